@@ -0,0 +1,51 @@
+use std::fmt::{self, Display};
+
+use reqwest::{Error as ReqwestError, StatusCode};
+
+#[derive(Debug)]
+pub enum Error {
+	Http(ReqwestError),
+
+	Api {
+		status:  StatusCode,
+		message: String,
+	},
+}
+
+impl Error {
+	pub fn status(&self) -> Option<StatusCode> {
+		match self {
+			Error::Http(err) => err.status(),
+			Error::Api {
+				status,
+				..
+			} => Some(*status),
+		}
+	}
+	/// Whether the server asked for the request to be retried later, such as
+	/// while it generates a challenge.
+	pub fn is_retryable(&self) -> bool {
+		self.status() == Some(StatusCode::SERVICE_UNAVAILABLE)
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Http(err) => write!(f, "{err}"),
+
+			Error::Api {
+				status,
+				message,
+			} => write!(f, "{}: {message}", status.as_u16()),
+		}
+	}
+}
+
+impl From<ReqwestError> for Error {
+	fn from(err: ReqwestError) -> Self {
+		Error::Http(err)
+	}
+}
@@ -0,0 +1,1196 @@
+mod error;
+
+use ctc_api::{
+	admin::RegeneratedChallenge,
+	challenge::{
+		ChallengeKind,
+		Difficulty,
+		DifficultyQuery,
+		Hint,
+		affine::{
+			AffineAttackPublic,
+			AffineAttackSubmission,
+			AffineDecryptPublic,
+			AffineDecryptSubmission,
+			AffineEncryptPublic,
+			AffineEncryptSubmission,
+		},
+		caesar::{
+			CaesarAttackPublic,
+			CaesarAttackSubmission,
+			CaesarCiphertextAttackPublic,
+			CaesarCiphertextAttackSubmission,
+			CaesarDecryptPublic,
+			CaesarDecryptSubmission,
+			CaesarEncryptPublic,
+			CaesarEncryptSubmission,
+		},
+		columnar::{
+			ColumnarDecryptPublic,
+			ColumnarDecryptSubmission,
+			ColumnarDoubleAttackPublic,
+			ColumnarDoubleAttackSubmission,
+			ColumnarEncryptPublic,
+			ColumnarEncryptSubmission,
+		},
+		diffie_hellman_exchange::{DiffieHellmanExchangePublic, DiffieHellmanExchangeSubmission},
+		dss::{DssSignPublic, DssSignSubmission, DssVerifyPublic, DssVerifySubmission},
+		enigma::{
+			EnigmaAttackPublic,
+			EnigmaAttackSubmission,
+			EnigmaDecryptPublic,
+			EnigmaDecryptSubmission,
+			EnigmaEncryptPublic,
+			EnigmaEncryptSubmission,
+		},
+		hill::{
+			HillAttackPublic,
+			HillAttackSubmission,
+			HillDecryptPublic,
+			HillDecryptSubmission,
+			HillEncryptPublic,
+			HillEncryptSubmission,
+		},
+		playfair::{
+			PlayfairDecryptPublic,
+			PlayfairDecryptSubmission,
+			PlayfairEncryptPublic,
+			PlayfairEncryptSubmission,
+		},
+		rail_fence::{
+			RailFenceDecryptPublic,
+			RailFenceDecryptSubmission,
+			RailFenceEncryptPublic,
+			RailFenceEncryptSubmission,
+		},
+		rsa::{RsaDecryptPublic, RsaDecryptSubmission, RsaEncryptPublic, RsaEncryptSubmission},
+		substitution::{SubstitutionAttackPublic, SubstitutionAttackSubmission},
+		vigenere::{
+			VigenereAttackPublic,
+			VigenereAttackSubmission,
+			VigenereDecryptPublic,
+			VigenereDecryptSubmission,
+			VigenereEncryptPublic,
+			VigenereEncryptSubmission,
+		},
+	},
+	history::{HistoryPage, HistoryQuery},
+	leaderboard::LeaderboardResult,
+	stats::ChallengeStats,
+	user::UserCredentials,
+};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::{Serialize, de::DeserializeOwned};
+
+pub use crate::error::Error;
+
+const CAESAR_ENCRYPT_PATH: &str = "/caesar/encrypt";
+const CAESAR_DECRYPT_PATH: &str = "/caesar/decrypt";
+const CAESAR_ATTACK_PATH: &str = "/caesar/attack";
+const DIFFIE_HELLMAN_EXCHANGE_PATH: &str = "/diffie-hellman/exchange";
+const RSA_ENCRYPT_PATH: &str = "/rsa/encrypt";
+const RSA_DECRYPT_PATH: &str = "/rsa/decrypt";
+const DSS_SIGN_PATH: &str = "/dss/sign";
+const DSS_VERIFY_PATH: &str = "/dss/verify";
+const VIGENERE_ENCRYPT_PATH: &str = "/vigenere/encrypt";
+const VIGENERE_DECRYPT_PATH: &str = "/vigenere/decrypt";
+const VIGENERE_ATTACK_PATH: &str = "/vigenere/attack";
+const AFFINE_ENCRYPT_PATH: &str = "/affine/encrypt";
+const AFFINE_DECRYPT_PATH: &str = "/affine/decrypt";
+const AFFINE_ATTACK_PATH: &str = "/affine/attack";
+const SUBSTITUTION_ATTACK_PATH: &str = "/substitution/attack";
+const CAESAR_CIPHERTEXT_ATTACK_PATH: &str = "/caesar/ciphertext-attack";
+const PLAYFAIR_ENCRYPT_PATH: &str = "/playfair/encrypt";
+const PLAYFAIR_DECRYPT_PATH: &str = "/playfair/decrypt";
+const HILL_ENCRYPT_PATH: &str = "/hill/encrypt";
+const HILL_DECRYPT_PATH: &str = "/hill/decrypt";
+const HILL_ATTACK_PATH: &str = "/hill/attack";
+const RAIL_FENCE_ENCRYPT_PATH: &str = "/rail-fence/encrypt";
+const RAIL_FENCE_DECRYPT_PATH: &str = "/rail-fence/decrypt";
+const COLUMNAR_ENCRYPT_PATH: &str = "/columnar/encrypt";
+const COLUMNAR_DECRYPT_PATH: &str = "/columnar/decrypt";
+const COLUMNAR_DOUBLE_ATTACK_PATH: &str = "/columnar/double-attack";
+const ENIGMA_ENCRYPT_PATH: &str = "/enigma/encrypt";
+const ENIGMA_DECRYPT_PATH: &str = "/enigma/decrypt";
+const ENIGMA_ATTACK_PATH: &str = "/enigma/attack";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Login {
+	Registered,
+	Existing,
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+	http:     reqwest::Client,
+	base_url: String,
+
+	bearer_token: Option<String>,
+}
+
+impl Client {
+	pub fn new(base_url: impl Into<String>) -> Self {
+		let base_url = base_url.into().trim_end_matches('/').to_owned();
+
+		Client {
+			http: reqwest::Client::new(),
+			base_url,
+
+			bearer_token: None,
+		}
+	}
+
+	pub fn with_bearer_token(mut self, bearer_token: impl Into<String>) -> Self {
+		self.bearer_token = Some(bearer_token.into());
+		self
+	}
+
+	pub fn bearer_token(&self) -> Option<&str> {
+		self.bearer_token.as_deref()
+	}
+
+	pub async fn health(&self) -> Result<(), Error> {
+		self.send(self.request(Method::GET, "/health"))
+			.await?;
+		Ok(())
+	}
+
+	/// Registers a new user, or logs in if the username already exists. The
+	/// returned bearer token is stored and sent with every subsequent request.
+	pub async fn login(&mut self, credentials: &UserCredentials) -> Result<Login, Error> {
+		let request = self
+			.request(Method::POST, "/user")
+			.json(credentials);
+		let response = self.send(request).await?;
+
+		let login = match response.status() {
+			StatusCode::CREATED => Login::Registered,
+			_ => Login::Existing,
+		};
+
+		self.bearer_token = Some(response.text().await?);
+
+		Ok(login)
+	}
+
+	/// Fetches the user's stats at one difficulty, or at every difficulty
+	/// when `difficulty` is `None`.
+	pub async fn stats(
+		&self,
+		difficulty: Option<Difficulty>,
+	) -> Result<Vec<ChallengeStats>, Error> {
+		let query = DifficultyQuery {
+			difficulty,
+		};
+
+		let request = self
+			.request(Method::GET, "/user/me/stats")
+			.query(&query);
+
+		Ok(self.send(request).await?.json().await?)
+	}
+
+	pub async fn history(&self, query: &HistoryQuery) -> Result<HistoryPage, Error> {
+		let request = self
+			.request(Method::GET, "/user/me/history")
+			.query(query);
+
+		Ok(self.send(request).await?.json().await?)
+	}
+
+	pub async fn caesar_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<CaesarEncryptPublic, Error> {
+		self.get_with_difficulty(CAESAR_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_caesar_encrypt(
+		&self,
+		submission: &CaesarEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(CAESAR_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn caesar_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{CAESAR_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_caesar_encrypt(&self) -> Result<(), Error> {
+		self.delete(CAESAR_ENCRYPT_PATH).await
+	}
+
+	pub async fn caesar_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{CAESAR_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn caesar_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<CaesarDecryptPublic, Error> {
+		self.get_with_difficulty(CAESAR_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_caesar_decrypt(
+		&self,
+		submission: &CaesarDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(CAESAR_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn caesar_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{CAESAR_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_caesar_decrypt(&self) -> Result<(), Error> {
+		self.delete(CAESAR_DECRYPT_PATH).await
+	}
+
+	pub async fn caesar_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{CAESAR_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn caesar_attack(&self, difficulty: Difficulty) -> Result<CaesarAttackPublic, Error> {
+		self.get_with_difficulty(CAESAR_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_caesar_attack(
+		&self,
+		submission: &CaesarAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(CAESAR_ATTACK_PATH, submission).await
+	}
+
+	pub async fn caesar_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{CAESAR_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_caesar_attack(&self) -> Result<(), Error> {
+		self.delete(CAESAR_ATTACK_PATH).await
+	}
+
+	pub async fn caesar_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{CAESAR_ATTACK_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn diffie_hellman_exchange(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<DiffieHellmanExchangePublic, Error> {
+		self.get_with_difficulty(DIFFIE_HELLMAN_EXCHANGE_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_diffie_hellman_exchange(
+		&self,
+		submission: &DiffieHellmanExchangeSubmission,
+	) -> Result<String, Error> {
+		self.post(DIFFIE_HELLMAN_EXCHANGE_PATH, submission)
+			.await
+	}
+
+	pub async fn diffie_hellman_exchange_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{DIFFIE_HELLMAN_EXCHANGE_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_diffie_hellman_exchange(&self) -> Result<(), Error> {
+		self.delete(DIFFIE_HELLMAN_EXCHANGE_PATH).await
+	}
+
+	pub async fn diffie_hellman_exchange_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{DIFFIE_HELLMAN_EXCHANGE_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn rsa_encrypt(&self, difficulty: Difficulty) -> Result<RsaEncryptPublic, Error> {
+		self.get_with_difficulty(RSA_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_rsa_encrypt(
+		&self,
+		submission: &RsaEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(RSA_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn rsa_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{RSA_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_rsa_encrypt(&self) -> Result<(), Error> {
+		self.delete(RSA_ENCRYPT_PATH).await
+	}
+
+	pub async fn rsa_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{RSA_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn rsa_decrypt(&self, difficulty: Difficulty) -> Result<RsaDecryptPublic, Error> {
+		self.get_with_difficulty(RSA_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_rsa_decrypt(
+		&self,
+		submission: &RsaDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(RSA_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn rsa_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{RSA_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_rsa_decrypt(&self) -> Result<(), Error> {
+		self.delete(RSA_DECRYPT_PATH).await
+	}
+
+	pub async fn rsa_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{RSA_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn dss_sign(&self, difficulty: Difficulty) -> Result<DssSignPublic, Error> {
+		self.get_with_difficulty(DSS_SIGN_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_dss_sign(&self, submission: &DssSignSubmission) -> Result<String, Error> {
+		self.post(DSS_SIGN_PATH, submission).await
+	}
+
+	pub async fn dss_sign_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{DSS_SIGN_PATH}/hint")).await
+	}
+
+	pub async fn abandon_dss_sign(&self) -> Result<(), Error> {
+		self.delete(DSS_SIGN_PATH).await
+	}
+
+	pub async fn dss_sign_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{DSS_SIGN_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn dss_verify(&self, difficulty: Difficulty) -> Result<DssVerifyPublic, Error> {
+		self.get_with_difficulty(DSS_VERIFY_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_dss_verify(
+		&self,
+		submission: &DssVerifySubmission,
+	) -> Result<String, Error> {
+		self.post(DSS_VERIFY_PATH, submission).await
+	}
+
+	pub async fn dss_verify_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{DSS_VERIFY_PATH}/hint")).await
+	}
+
+	pub async fn abandon_dss_verify(&self) -> Result<(), Error> {
+		self.delete(DSS_VERIFY_PATH).await
+	}
+
+	pub async fn dss_verify_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{DSS_VERIFY_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn vigenere_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<VigenereEncryptPublic, Error> {
+		self.get_with_difficulty(VIGENERE_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_vigenere_encrypt(
+		&self,
+		submission: &VigenereEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(VIGENERE_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn vigenere_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{VIGENERE_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_vigenere_encrypt(&self) -> Result<(), Error> {
+		self.delete(VIGENERE_ENCRYPT_PATH).await
+	}
+
+	pub async fn vigenere_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{VIGENERE_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn vigenere_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<VigenereDecryptPublic, Error> {
+		self.get_with_difficulty(VIGENERE_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_vigenere_decrypt(
+		&self,
+		submission: &VigenereDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(VIGENERE_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn vigenere_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{VIGENERE_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_vigenere_decrypt(&self) -> Result<(), Error> {
+		self.delete(VIGENERE_DECRYPT_PATH).await
+	}
+
+	pub async fn vigenere_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{VIGENERE_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn vigenere_attack(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<VigenereAttackPublic, Error> {
+		self.get_with_difficulty(VIGENERE_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_vigenere_attack(
+		&self,
+		submission: &VigenereAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(VIGENERE_ATTACK_PATH, submission).await
+	}
+
+	pub async fn vigenere_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{VIGENERE_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_vigenere_attack(&self) -> Result<(), Error> {
+		self.delete(VIGENERE_ATTACK_PATH).await
+	}
+
+	pub async fn vigenere_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{VIGENERE_ATTACK_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn affine_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<AffineEncryptPublic, Error> {
+		self.get_with_difficulty(AFFINE_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_affine_encrypt(
+		&self,
+		submission: &AffineEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(AFFINE_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn affine_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{AFFINE_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_affine_encrypt(&self) -> Result<(), Error> {
+		self.delete(AFFINE_ENCRYPT_PATH).await
+	}
+
+	pub async fn affine_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{AFFINE_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn affine_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<AffineDecryptPublic, Error> {
+		self.get_with_difficulty(AFFINE_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_affine_decrypt(
+		&self,
+		submission: &AffineDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(AFFINE_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn affine_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{AFFINE_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_affine_decrypt(&self) -> Result<(), Error> {
+		self.delete(AFFINE_DECRYPT_PATH).await
+	}
+
+	pub async fn affine_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{AFFINE_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn affine_attack(&self, difficulty: Difficulty) -> Result<AffineAttackPublic, Error> {
+		self.get_with_difficulty(AFFINE_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_affine_attack(
+		&self,
+		submission: &AffineAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(AFFINE_ATTACK_PATH, submission).await
+	}
+
+	pub async fn affine_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{AFFINE_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_affine_attack(&self) -> Result<(), Error> {
+		self.delete(AFFINE_ATTACK_PATH).await
+	}
+
+	pub async fn affine_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{AFFINE_ATTACK_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn substitution_attack(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<SubstitutionAttackPublic, Error> {
+		self.get_with_difficulty(SUBSTITUTION_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_substitution_attack(
+		&self,
+		submission: &SubstitutionAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(SUBSTITUTION_ATTACK_PATH, submission)
+			.await
+	}
+
+	pub async fn substitution_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{SUBSTITUTION_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_substitution_attack(&self) -> Result<(), Error> {
+		self.delete(SUBSTITUTION_ATTACK_PATH).await
+	}
+
+	pub async fn substitution_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{SUBSTITUTION_ATTACK_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn caesar_ciphertext_attack(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<CaesarCiphertextAttackPublic, Error> {
+		self.get_with_difficulty(CAESAR_CIPHERTEXT_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_caesar_ciphertext_attack(
+		&self,
+		submission: &CaesarCiphertextAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(CAESAR_CIPHERTEXT_ATTACK_PATH, submission)
+			.await
+	}
+
+	pub async fn caesar_ciphertext_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{CAESAR_CIPHERTEXT_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_caesar_ciphertext_attack(&self) -> Result<(), Error> {
+		self.delete(CAESAR_CIPHERTEXT_ATTACK_PATH).await
+	}
+
+	pub async fn caesar_ciphertext_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{CAESAR_CIPHERTEXT_ATTACK_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn playfair_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<PlayfairEncryptPublic, Error> {
+		self.get_with_difficulty(PLAYFAIR_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_playfair_encrypt(
+		&self,
+		submission: &PlayfairEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(PLAYFAIR_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn playfair_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{PLAYFAIR_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_playfair_encrypt(&self) -> Result<(), Error> {
+		self.delete(PLAYFAIR_ENCRYPT_PATH).await
+	}
+
+	pub async fn playfair_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{PLAYFAIR_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn playfair_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<PlayfairDecryptPublic, Error> {
+		self.get_with_difficulty(PLAYFAIR_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_playfair_decrypt(
+		&self,
+		submission: &PlayfairDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(PLAYFAIR_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn playfair_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{PLAYFAIR_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_playfair_decrypt(&self) -> Result<(), Error> {
+		self.delete(PLAYFAIR_DECRYPT_PATH).await
+	}
+
+	pub async fn playfair_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{PLAYFAIR_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn hill_encrypt(&self, difficulty: Difficulty) -> Result<HillEncryptPublic, Error> {
+		self.get_with_difficulty(HILL_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_hill_encrypt(
+		&self,
+		submission: &HillEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(HILL_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn hill_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{HILL_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_hill_encrypt(&self) -> Result<(), Error> {
+		self.delete(HILL_ENCRYPT_PATH).await
+	}
+
+	pub async fn hill_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{HILL_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn hill_decrypt(&self, difficulty: Difficulty) -> Result<HillDecryptPublic, Error> {
+		self.get_with_difficulty(HILL_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_hill_decrypt(
+		&self,
+		submission: &HillDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(HILL_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn hill_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{HILL_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_hill_decrypt(&self) -> Result<(), Error> {
+		self.delete(HILL_DECRYPT_PATH).await
+	}
+
+	pub async fn hill_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{HILL_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn hill_attack(&self, difficulty: Difficulty) -> Result<HillAttackPublic, Error> {
+		self.get_with_difficulty(HILL_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_hill_attack(
+		&self,
+		submission: &HillAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(HILL_ATTACK_PATH, submission).await
+	}
+
+	pub async fn hill_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{HILL_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_hill_attack(&self) -> Result<(), Error> {
+		self.delete(HILL_ATTACK_PATH).await
+	}
+
+	pub async fn hill_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{HILL_ATTACK_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn rail_fence_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<RailFenceEncryptPublic, Error> {
+		self.get_with_difficulty(RAIL_FENCE_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_rail_fence_encrypt(
+		&self,
+		submission: &RailFenceEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(RAIL_FENCE_ENCRYPT_PATH, submission)
+			.await
+	}
+
+	pub async fn rail_fence_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{RAIL_FENCE_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_rail_fence_encrypt(&self) -> Result<(), Error> {
+		self.delete(RAIL_FENCE_ENCRYPT_PATH).await
+	}
+
+	pub async fn rail_fence_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{RAIL_FENCE_ENCRYPT_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn rail_fence_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<RailFenceDecryptPublic, Error> {
+		self.get_with_difficulty(RAIL_FENCE_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_rail_fence_decrypt(
+		&self,
+		submission: &RailFenceDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(RAIL_FENCE_DECRYPT_PATH, submission)
+			.await
+	}
+
+	pub async fn rail_fence_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{RAIL_FENCE_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_rail_fence_decrypt(&self) -> Result<(), Error> {
+		self.delete(RAIL_FENCE_DECRYPT_PATH).await
+	}
+
+	pub async fn rail_fence_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{RAIL_FENCE_DECRYPT_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn columnar_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<ColumnarEncryptPublic, Error> {
+		self.get_with_difficulty(COLUMNAR_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_columnar_encrypt(
+		&self,
+		submission: &ColumnarEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(COLUMNAR_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn columnar_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{COLUMNAR_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_columnar_encrypt(&self) -> Result<(), Error> {
+		self.delete(COLUMNAR_ENCRYPT_PATH).await
+	}
+
+	pub async fn columnar_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{COLUMNAR_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn columnar_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<ColumnarDecryptPublic, Error> {
+		self.get_with_difficulty(COLUMNAR_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_columnar_decrypt(
+		&self,
+		submission: &ColumnarDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(COLUMNAR_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn columnar_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{COLUMNAR_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_columnar_decrypt(&self) -> Result<(), Error> {
+		self.delete(COLUMNAR_DECRYPT_PATH).await
+	}
+
+	pub async fn columnar_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{COLUMNAR_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn columnar_double_attack(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<ColumnarDoubleAttackPublic, Error> {
+		self.get_with_difficulty(COLUMNAR_DOUBLE_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_columnar_double_attack(
+		&self,
+		submission: &ColumnarDoubleAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(COLUMNAR_DOUBLE_ATTACK_PATH, submission)
+			.await
+	}
+
+	pub async fn columnar_double_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{COLUMNAR_DOUBLE_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_columnar_double_attack(&self) -> Result<(), Error> {
+		self.delete(COLUMNAR_DOUBLE_ATTACK_PATH).await
+	}
+
+	pub async fn columnar_double_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(
+			&format!("{COLUMNAR_DOUBLE_ATTACK_PATH}/leaderboard"),
+			difficulty,
+		)
+		.await
+	}
+
+	pub async fn enigma_encrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<EnigmaEncryptPublic, Error> {
+		self.get_with_difficulty(ENIGMA_ENCRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_enigma_encrypt(
+		&self,
+		submission: &EnigmaEncryptSubmission,
+	) -> Result<String, Error> {
+		self.post(ENIGMA_ENCRYPT_PATH, submission).await
+	}
+
+	pub async fn enigma_encrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{ENIGMA_ENCRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_enigma_encrypt(&self) -> Result<(), Error> {
+		self.delete(ENIGMA_ENCRYPT_PATH).await
+	}
+
+	pub async fn enigma_encrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{ENIGMA_ENCRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn enigma_decrypt(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<EnigmaDecryptPublic, Error> {
+		self.get_with_difficulty(ENIGMA_DECRYPT_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_enigma_decrypt(
+		&self,
+		submission: &EnigmaDecryptSubmission,
+	) -> Result<String, Error> {
+		self.post(ENIGMA_DECRYPT_PATH, submission).await
+	}
+
+	pub async fn enigma_decrypt_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{ENIGMA_DECRYPT_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_enigma_decrypt(&self) -> Result<(), Error> {
+		self.delete(ENIGMA_DECRYPT_PATH).await
+	}
+
+	pub async fn enigma_decrypt_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{ENIGMA_DECRYPT_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	pub async fn enigma_attack(&self, difficulty: Difficulty) -> Result<EnigmaAttackPublic, Error> {
+		self.get_with_difficulty(ENIGMA_ATTACK_PATH, difficulty)
+			.await
+	}
+
+	pub async fn submit_enigma_attack(
+		&self,
+		submission: &EnigmaAttackSubmission,
+	) -> Result<String, Error> {
+		self.post(ENIGMA_ATTACK_PATH, submission).await
+	}
+
+	pub async fn enigma_attack_hint(&self) -> Result<Hint, Error> {
+		self.get(&format!("{ENIGMA_ATTACK_PATH}/hint"))
+			.await
+	}
+
+	pub async fn abandon_enigma_attack(&self) -> Result<(), Error> {
+		self.delete(ENIGMA_ATTACK_PATH).await
+	}
+
+	pub async fn enigma_attack_leaderboard(
+		&self,
+		difficulty: Difficulty,
+	) -> Result<Vec<LeaderboardResult>, Error> {
+		self.get_with_difficulty(&format!("{ENIGMA_ATTACK_PATH}/leaderboard"), difficulty)
+			.await
+	}
+
+	/// Regenerates a stored challenge from its seed. Requires an admin account.
+	pub async fn regenerate(
+		&self,
+		challenge: ChallengeKind,
+		id: i32,
+	) -> Result<RegeneratedChallenge, Error> {
+		let path = format!("/admin/{challenge}/{id}/regenerate");
+		let response = self
+			.send(self.request(Method::POST, &path))
+			.await?;
+
+		Ok(response.json::<RegeneratedChallenge>().await?)
+	}
+
+	async fn get<T>(&self, path: &str) -> Result<T, Error>
+	where
+		T: DeserializeOwned,
+	{
+		let response = self.send(self.request(Method::GET, path)).await?;
+		Ok(response.json::<T>().await?)
+	}
+
+	async fn post<B>(&self, path: &str, body: &B) -> Result<String, Error>
+	where
+		B: Serialize,
+	{
+		let request = self.request(Method::POST, path).json(body);
+		let response = self.send(request).await?;
+
+		Ok(response.text().await?)
+	}
+
+	async fn delete(&self, path: &str) -> Result<(), Error> {
+		self.send(self.request(Method::DELETE, path))
+			.await?;
+		Ok(())
+	}
+
+	async fn get_with_difficulty<T>(&self, path: &str, difficulty: Difficulty) -> Result<T, Error>
+	where
+		T: DeserializeOwned,
+	{
+		let query = DifficultyQuery {
+			difficulty: Some(difficulty),
+		};
+
+		let request = self.request(Method::GET, path).query(&query);
+		let response = self.send(request).await?;
+
+		Ok(response.json::<T>().await?)
+	}
+
+	fn request(&self, method: Method, path: &str) -> RequestBuilder {
+		let request = self
+			.http
+			.request(method, format!("{}{path}", self.base_url));
+
+		match &self.bearer_token {
+			Some(bearer_token) => request.bearer_auth(bearer_token),
+			None => request,
+		}
+	}
+
+	async fn send(&self, request: RequestBuilder) -> Result<Response, Error> {
+		let response = request.send().await?;
+		let status = response.status();
+
+		if !status.is_success() {
+			let error = Error::Api {
+				status,
+				message: response.text().await?,
+			};
+
+			return Err(error);
+		}
+
+		Ok(response)
+	}
+}
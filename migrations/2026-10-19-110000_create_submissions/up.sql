@@ -0,0 +1,14 @@
+CREATE TABLE submissions (
+	id SERIAL PRIMARY KEY,
+	user_id INTEGER NOT NULL
+		REFERENCES users(id)
+		ON UPDATE CASCADE
+		ON DELETE CASCADE,
+	challenge TEXT NOT NULL,
+	challenge_id INTEGER NOT NULL,
+	payload JSONB NOT NULL,
+	correct BOOLEAN NOT NULL,
+	created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX submissions_user_id_index ON submissions (user_id);
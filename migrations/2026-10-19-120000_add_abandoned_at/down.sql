@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+DROP COLUMN abandoned_at;
+
+ALTER TABLE caesar_decrypts
+DROP COLUMN abandoned_at;
+
+ALTER TABLE caesar_encrypts
+DROP COLUMN abandoned_at;
+
+ALTER TABLE diffie_hellman_exchanges
+DROP COLUMN abandoned_at;
+
+ALTER TABLE dss_signs
+DROP COLUMN abandoned_at;
+
+ALTER TABLE dss_verifies
+DROP COLUMN abandoned_at;
+
+ALTER TABLE rsa_decrypts
+DROP COLUMN abandoned_at;
+
+ALTER TABLE rsa_encrypts
+DROP COLUMN abandoned_at;
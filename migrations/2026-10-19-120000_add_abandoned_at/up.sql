@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE caesar_decrypts
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE caesar_encrypts
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE diffie_hellman_exchanges
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE dss_signs
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE dss_verifies
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE rsa_decrypts
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE rsa_encrypts
+ADD COLUMN abandoned_at TIMESTAMP WITH TIME ZONE;
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE caesar_decrypts
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE caesar_encrypts
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE diffie_hellman_exchanges
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE dss_signs
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE dss_verifies
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE rsa_decrypts
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE rsa_encrypts
+ADD COLUMN expired_at TIMESTAMP WITH TIME ZONE;
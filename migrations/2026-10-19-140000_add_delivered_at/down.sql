@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+DROP COLUMN delivered_at;
+
+ALTER TABLE caesar_decrypts
+DROP COLUMN delivered_at;
+
+ALTER TABLE caesar_encrypts
+DROP COLUMN delivered_at;
+
+ALTER TABLE diffie_hellman_exchanges
+DROP COLUMN delivered_at;
+
+ALTER TABLE dss_signs
+DROP COLUMN delivered_at;
+
+ALTER TABLE dss_verifies
+DROP COLUMN delivered_at;
+
+ALTER TABLE rsa_decrypts
+DROP COLUMN delivered_at;
+
+ALTER TABLE rsa_encrypts
+DROP COLUMN delivered_at;
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+DROP COLUMN hints_used;
+
+ALTER TABLE caesar_decrypts
+DROP COLUMN hints_used;
+
+ALTER TABLE caesar_encrypts
+DROP COLUMN hints_used;
+
+ALTER TABLE diffie_hellman_exchanges
+DROP COLUMN hints_used;
+
+ALTER TABLE dss_signs
+DROP COLUMN hints_used;
+
+ALTER TABLE dss_verifies
+DROP COLUMN hints_used;
+
+ALTER TABLE rsa_decrypts
+DROP COLUMN hints_used;
+
+ALTER TABLE rsa_encrypts
+DROP COLUMN hints_used;
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE caesar_decrypts
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE caesar_encrypts
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE diffie_hellman_exchanges
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE dss_signs
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE dss_verifies
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE rsa_decrypts
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE rsa_encrypts
+ADD COLUMN hints_used INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+DROP COLUMN difficulty;
+
+ALTER TABLE caesar_decrypts
+DROP COLUMN difficulty;
+
+ALTER TABLE caesar_encrypts
+DROP COLUMN difficulty;
+
+ALTER TABLE diffie_hellman_exchanges
+DROP COLUMN difficulty;
+
+ALTER TABLE dss_signs
+DROP COLUMN difficulty;
+
+ALTER TABLE dss_verifies
+DROP COLUMN difficulty;
+
+ALTER TABLE rsa_decrypts
+DROP COLUMN difficulty;
+
+ALTER TABLE rsa_encrypts
+DROP COLUMN difficulty;
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE caesar_decrypts
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE caesar_encrypts
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE diffie_hellman_exchanges
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE dss_signs
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE dss_verifies
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE rsa_decrypts
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
+
+ALTER TABLE rsa_encrypts
+ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'medium';
@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+DROP COLUMN seed;
+
+ALTER TABLE caesar_decrypts
+DROP COLUMN seed;
+
+ALTER TABLE caesar_encrypts
+DROP COLUMN seed;
+
+ALTER TABLE diffie_hellman_exchanges
+DROP COLUMN seed;
+
+ALTER TABLE dss_signs
+DROP COLUMN seed;
+
+ALTER TABLE dss_verifies
+DROP COLUMN seed;
+
+ALTER TABLE rsa_decrypts
+DROP COLUMN seed;
+
+ALTER TABLE rsa_encrypts
+DROP COLUMN seed;
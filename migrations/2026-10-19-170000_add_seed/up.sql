@@ -0,0 +1,23 @@
+ALTER TABLE caesar_attacks
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE caesar_decrypts
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE caesar_encrypts
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE diffie_hellman_exchanges
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE dss_signs
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE dss_verifies
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE rsa_decrypts
+ADD COLUMN seed BIGINT;
+
+ALTER TABLE rsa_encrypts
+ADD COLUMN seed BIGINT;
@@ -0,0 +1,48 @@
+DELETE FROM diffie_hellman_exchanges
+WHERE difficulty IN ('advanced', 'expert');
+
+ALTER TABLE diffie_hellman_exchanges
+ALTER COLUMN g TYPE BIGINT USING g::BIGINT,
+ALTER COLUMN n TYPE BIGINT USING n::BIGINT,
+ALTER COLUMN sk_server TYPE BIGINT USING sk_server::BIGINT;
+
+DELETE FROM dss_signs
+WHERE difficulty IN ('advanced', 'expert');
+
+ALTER TABLE dss_signs
+ALTER COLUMN n_p TYPE BIGINT USING n_p::BIGINT,
+ALTER COLUMN n_q TYPE BIGINT USING n_q::BIGINT,
+ALTER COLUMN g TYPE BIGINT USING g::BIGINT,
+ALTER COLUMN m TYPE BIGINT USING m::BIGINT;
+
+DELETE FROM dss_verifies
+WHERE difficulty IN ('advanced', 'expert');
+
+ALTER TABLE dss_verifies
+ALTER COLUMN n_p TYPE BIGINT USING n_p::BIGINT,
+ALTER COLUMN n_q TYPE BIGINT USING n_q::BIGINT,
+ALTER COLUMN g TYPE BIGINT USING g::BIGINT,
+ALTER COLUMN pk TYPE BIGINT USING pk::BIGINT,
+ALTER COLUMN m TYPE BIGINT USING m::BIGINT,
+ALTER COLUMN r TYPE BIGINT USING r::BIGINT,
+ALTER COLUMN s TYPE BIGINT USING s::BIGINT;
+
+DELETE FROM rsa_decrypts
+WHERE difficulty IN ('advanced', 'expert');
+
+ALTER TABLE rsa_decrypts
+ALTER COLUMN n_p TYPE BIGINT USING n_p::BIGINT,
+ALTER COLUMN n_q TYPE BIGINT USING n_q::BIGINT,
+ALTER COLUMN e TYPE BIGINT USING e::BIGINT,
+ALTER COLUMN d TYPE BIGINT USING d::BIGINT,
+ALTER COLUMN c TYPE BIGINT USING c::BIGINT;
+
+DELETE FROM rsa_encrypts
+WHERE difficulty IN ('advanced', 'expert');
+
+ALTER TABLE rsa_encrypts
+ALTER COLUMN n_p TYPE BIGINT USING n_p::BIGINT,
+ALTER COLUMN n_q TYPE BIGINT USING n_q::BIGINT,
+ALTER COLUMN e TYPE BIGINT USING e::BIGINT,
+ALTER COLUMN d TYPE BIGINT USING d::BIGINT,
+ALTER COLUMN m TYPE BIGINT USING m::BIGINT;
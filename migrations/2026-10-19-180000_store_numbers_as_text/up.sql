@@ -0,0 +1,33 @@
+ALTER TABLE diffie_hellman_exchanges
+ALTER COLUMN g TYPE TEXT USING g::TEXT,
+ALTER COLUMN n TYPE TEXT USING n::TEXT,
+ALTER COLUMN sk_server TYPE TEXT USING sk_server::TEXT;
+
+ALTER TABLE dss_signs
+ALTER COLUMN n_p TYPE TEXT USING n_p::TEXT,
+ALTER COLUMN n_q TYPE TEXT USING n_q::TEXT,
+ALTER COLUMN g TYPE TEXT USING g::TEXT,
+ALTER COLUMN m TYPE TEXT USING m::TEXT;
+
+ALTER TABLE dss_verifies
+ALTER COLUMN n_p TYPE TEXT USING n_p::TEXT,
+ALTER COLUMN n_q TYPE TEXT USING n_q::TEXT,
+ALTER COLUMN g TYPE TEXT USING g::TEXT,
+ALTER COLUMN pk TYPE TEXT USING pk::TEXT,
+ALTER COLUMN m TYPE TEXT USING m::TEXT,
+ALTER COLUMN r TYPE TEXT USING r::TEXT,
+ALTER COLUMN s TYPE TEXT USING s::TEXT;
+
+ALTER TABLE rsa_decrypts
+ALTER COLUMN n_p TYPE TEXT USING n_p::TEXT,
+ALTER COLUMN n_q TYPE TEXT USING n_q::TEXT,
+ALTER COLUMN e TYPE TEXT USING e::TEXT,
+ALTER COLUMN d TYPE TEXT USING d::TEXT,
+ALTER COLUMN c TYPE TEXT USING c::TEXT;
+
+ALTER TABLE rsa_encrypts
+ALTER COLUMN n_p TYPE TEXT USING n_p::TEXT,
+ALTER COLUMN n_q TYPE TEXT USING n_q::TEXT,
+ALTER COLUMN e TYPE TEXT USING e::TEXT,
+ALTER COLUMN d TYPE TEXT USING d::TEXT,
+ALTER COLUMN m TYPE TEXT USING m::TEXT;
@@ -0,0 +1,3 @@
+DROP TABLE vigenere_encrypts;
+DROP TABLE vigenere_decrypts;
+DROP TABLE vigenere_attacks;
@@ -0,0 +1,3 @@
+DROP TABLE affine_encrypts;
+DROP TABLE affine_decrypts;
+DROP TABLE affine_attacks;
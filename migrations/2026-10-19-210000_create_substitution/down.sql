@@ -0,0 +1 @@
+DROP TABLE substitution_attacks;
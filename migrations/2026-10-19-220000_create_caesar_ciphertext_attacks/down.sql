@@ -0,0 +1 @@
+DROP TABLE caesar_ciphertext_attacks;
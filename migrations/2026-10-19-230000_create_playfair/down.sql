@@ -0,0 +1,2 @@
+DROP TABLE playfair_encrypts;
+DROP TABLE playfair_decrypts;
@@ -0,0 +1,35 @@
+CREATE TABLE playfair_encrypts (
+	id SERIAL PRIMARY KEY,
+	user_id INTEGER NOT NULL
+		REFERENCES users(id)
+		ON UPDATE CASCADE
+		ON DELETE CASCADE,
+	keyword TEXT NOT NULL,
+	message TEXT NOT NULL,
+	created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+	completed_at TIMESTAMP WITH TIME ZONE,
+	abandoned_at TIMESTAMP WITH TIME ZONE,
+	expired_at TIMESTAMP WITH TIME ZONE,
+	delivered_at TIMESTAMP WITH TIME ZONE,
+	hints_used INTEGER NOT NULL DEFAULT 0,
+	difficulty TEXT NOT NULL DEFAULT 'medium',
+	seed BIGINT
+);
+
+CREATE TABLE playfair_decrypts (
+	id SERIAL PRIMARY KEY,
+	user_id INTEGER NOT NULL
+		REFERENCES users(id)
+		ON UPDATE CASCADE
+		ON DELETE CASCADE,
+	keyword TEXT NOT NULL,
+	cipher TEXT NOT NULL,
+	created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+	completed_at TIMESTAMP WITH TIME ZONE,
+	abandoned_at TIMESTAMP WITH TIME ZONE,
+	expired_at TIMESTAMP WITH TIME ZONE,
+	delivered_at TIMESTAMP WITH TIME ZONE,
+	hints_used INTEGER NOT NULL DEFAULT 0,
+	difficulty TEXT NOT NULL DEFAULT 'medium',
+	seed BIGINT
+);
@@ -0,0 +1,3 @@
+DROP TABLE hill_encrypts;
+DROP TABLE hill_decrypts;
+DROP TABLE hill_attacks;
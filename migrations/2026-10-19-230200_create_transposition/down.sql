@@ -0,0 +1,5 @@
+DROP TABLE rail_fence_encrypts;
+DROP TABLE rail_fence_decrypts;
+DROP TABLE columnar_encrypts;
+DROP TABLE columnar_decrypts;
+DROP TABLE columnar_double_attacks;
@@ -0,0 +1,3 @@
+DROP TABLE enigma_encrypts;
+DROP TABLE enigma_decrypts;
+DROP TABLE enigma_attacks;
@@ -0,0 +1,471 @@
+mod solve;
+
+use std::{
+	fmt::Display,
+	process::ExitCode,
+	time::{Duration, Instant},
+};
+
+use clap::Parser;
+use ctc_api::{challenge::Difficulty, user::UserCredentials};
+use ctc_client::{Client, Error};
+
+/// Attempts at a challenge the server is still generating, such as the
+/// number-theory challenges at the key-sized difficulties.
+const ATTEMPTS: usize = 12;
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Parser)]
+#[command(
+	name = "ctc-solver",
+	about = "Solves every challenge against a deployment"
+)]
+struct Args {
+	#[arg(long, env = "CTC_URL", default_value = "http://localhost:3000")]
+	url: String,
+
+	#[arg(long, env = "CTC_USERNAME")]
+	username: String,
+
+	#[arg(long, env = "CTC_PASSWORD")]
+	password: String,
+
+	/// Difficulty of the generated challenges: easy, medium, hard or 1-3
+	#[arg(long, env = "CTC_DIFFICULTY", default_value_t = Difficulty::Medium)]
+	difficulty: Difficulty,
+}
+
+#[derive(Default)]
+struct Report {
+	passed: usize,
+	failed: usize,
+}
+
+#[derive(Debug)]
+enum SolveError {
+	Client(Error),
+	Unsolvable,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+	let args = Args::parse();
+	let difficulty = args.difficulty;
+
+	let credentials = UserCredentials {
+		username: args.username,
+		password: args.password,
+	};
+
+	let mut client = Client::new(args.url);
+
+	if let Err(err) = client.login(&credentials).await {
+		eprintln!("could not log in: {err}");
+		return ExitCode::FAILURE;
+	}
+
+	let mut report = Report::default();
+
+	report
+		.run("caesar encrypt", caesar_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("caesar decrypt", caesar_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("caesar attack", caesar_attack(&client, difficulty))
+		.await;
+	report
+		.run(
+			"diffie-hellman exchange",
+			diffie_hellman_exchange(&client, difficulty),
+		)
+		.await;
+	report
+		.run("rsa encrypt", rsa_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("rsa decrypt", rsa_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("dss sign", dss_sign(&client, difficulty))
+		.await;
+	report
+		.run("dss verify", dss_verify(&client, difficulty))
+		.await;
+	report
+		.run("vigenere encrypt", vigenere_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("vigenere decrypt", vigenere_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("vigenere attack", vigenere_attack(&client, difficulty))
+		.await;
+	report
+		.run("affine encrypt", affine_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("affine decrypt", affine_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("affine attack", affine_attack(&client, difficulty))
+		.await;
+	report
+		.run(
+			"substitution attack",
+			substitution_attack(&client, difficulty),
+		)
+		.await;
+	report
+		.run(
+			"caesar ciphertext attack",
+			caesar_ciphertext_attack(&client, difficulty),
+		)
+		.await;
+	report
+		.run("playfair encrypt", playfair_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("playfair decrypt", playfair_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("hill encrypt", hill_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("hill decrypt", hill_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("hill attack", hill_attack(&client, difficulty))
+		.await;
+	report
+		.run(
+			"rail fence encrypt",
+			rail_fence_encrypt(&client, difficulty),
+		)
+		.await;
+	report
+		.run(
+			"rail fence decrypt",
+			rail_fence_decrypt(&client, difficulty),
+		)
+		.await;
+	report
+		.run("columnar encrypt", columnar_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("columnar decrypt", columnar_decrypt(&client, difficulty))
+		.await;
+	report
+		.run(
+			"columnar double attack",
+			columnar_double_attack(&client, difficulty),
+		)
+		.await;
+	report
+		.run("enigma encrypt", enigma_encrypt(&client, difficulty))
+		.await;
+	report
+		.run("enigma decrypt", enigma_decrypt(&client, difficulty))
+		.await;
+	report
+		.run("enigma attack", enigma_attack(&client, difficulty))
+		.await;
+
+	println!("{} passed, {} failed", report.passed, report.failed);
+
+	match report.failed {
+		0 => ExitCode::SUCCESS,
+		_ => ExitCode::FAILURE,
+	}
+}
+
+impl Report {
+	async fn run(&mut self, name: &str, solve: impl Future<Output = Result<String, SolveError>>) {
+		let start = Instant::now();
+		let result = solve.await;
+		let elapsed = start.elapsed();
+
+		match result {
+			Ok(message) => {
+				self.passed += 1;
+				println!("PASS {name} ({elapsed:.2?}) {message}");
+			},
+
+			Err(err) => {
+				self.failed += 1;
+				println!("FAIL {name} ({elapsed:.2?}) {err}");
+			},
+		}
+	}
+}
+
+impl Display for SolveError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SolveError::Client(err) => write!(f, "{err}"),
+			SolveError::Unsolvable => write!(f, "could not compute an answer"),
+		}
+	}
+}
+
+impl From<Error> for SolveError {
+	fn from(err: Error) -> Self {
+		SolveError::Client(err)
+	}
+}
+
+async fn retry<T, F>(request: impl Fn() -> F) -> Result<T, Error>
+where
+	F: Future<Output = Result<T, Error>>,
+{
+	for _ in 1..ATTEMPTS {
+		match request().await {
+			Err(err) if err.is_retryable() => tokio::time::sleep(RETRY_DELAY).await,
+			result => return result,
+		}
+	}
+
+	request().await
+}
+
+async fn caesar_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.caesar_encrypt(difficulty).await?;
+	let submission = solve::caesar_encrypt(&public);
+
+	Ok(client.submit_caesar_encrypt(&submission).await?)
+}
+
+async fn caesar_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.caesar_decrypt(difficulty).await?;
+	let submission = solve::caesar_decrypt(&public);
+
+	Ok(client.submit_caesar_decrypt(&submission).await?)
+}
+
+async fn caesar_attack(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.caesar_attack(difficulty).await?;
+	let submission = solve::caesar_attack(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_caesar_attack(&submission).await?)
+}
+
+async fn diffie_hellman_exchange(
+	client: &Client,
+	difficulty: Difficulty,
+) -> Result<String, SolveError> {
+	let public = retry(|| client.diffie_hellman_exchange(difficulty)).await?;
+	let submission = solve::diffie_hellman_exchange(&mut rand::rng(), &public);
+
+	Ok(client
+		.submit_diffie_hellman_exchange(&submission)
+		.await?)
+}
+
+async fn rsa_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = retry(|| client.rsa_encrypt(difficulty)).await?;
+	let submission = solve::rsa_encrypt(&public);
+
+	Ok(client.submit_rsa_encrypt(&submission).await?)
+}
+
+async fn rsa_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = retry(|| client.rsa_decrypt(difficulty)).await?;
+	let submission = solve::rsa_decrypt(&public);
+
+	Ok(client.submit_rsa_decrypt(&submission).await?)
+}
+
+async fn dss_sign(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = retry(|| client.dss_sign(difficulty)).await?;
+	let submission = solve::dss_sign(&mut rand::rng(), &public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_dss_sign(&submission).await?)
+}
+
+async fn dss_verify(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = retry(|| client.dss_verify(difficulty)).await?;
+	let submission = solve::dss_verify(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_dss_verify(&submission).await?)
+}
+
+async fn vigenere_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.vigenere_encrypt(difficulty).await?;
+	let submission = solve::vigenere_encrypt(&public);
+
+	Ok(client
+		.submit_vigenere_encrypt(&submission)
+		.await?)
+}
+
+async fn vigenere_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.vigenere_decrypt(difficulty).await?;
+	let submission = solve::vigenere_decrypt(&public);
+
+	Ok(client
+		.submit_vigenere_decrypt(&submission)
+		.await?)
+}
+
+async fn vigenere_attack(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.vigenere_attack(difficulty).await?;
+	let submission = solve::vigenere_attack(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_vigenere_attack(&submission).await?)
+}
+
+async fn affine_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.affine_encrypt(difficulty).await?;
+	let submission = solve::affine_encrypt(&public);
+
+	Ok(client.submit_affine_encrypt(&submission).await?)
+}
+
+async fn affine_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.affine_decrypt(difficulty).await?;
+	let submission = solve::affine_decrypt(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_affine_decrypt(&submission).await?)
+}
+
+async fn affine_attack(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.affine_attack(difficulty).await?;
+	let submission = solve::affine_attack(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_affine_attack(&submission).await?)
+}
+
+async fn substitution_attack(
+	client: &Client,
+	difficulty: Difficulty,
+) -> Result<String, SolveError> {
+	let public = client.substitution_attack(difficulty).await?;
+	let submission = solve::substitution_attack(&mut rand::rng(), &public);
+
+	Ok(client
+		.submit_substitution_attack(&submission)
+		.await?)
+}
+
+async fn caesar_ciphertext_attack(
+	client: &Client,
+	difficulty: Difficulty,
+) -> Result<String, SolveError> {
+	let public = client
+		.caesar_ciphertext_attack(difficulty)
+		.await?;
+	let submission = solve::caesar_ciphertext_attack(&public);
+
+	Ok(client
+		.submit_caesar_ciphertext_attack(&submission)
+		.await?)
+}
+
+async fn playfair_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.playfair_encrypt(difficulty).await?;
+	let submission = solve::playfair_encrypt(&public);
+
+	Ok(client
+		.submit_playfair_encrypt(&submission)
+		.await?)
+}
+
+async fn playfair_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.playfair_decrypt(difficulty).await?;
+	let submission = solve::playfair_decrypt(&public);
+
+	Ok(client
+		.submit_playfair_decrypt(&submission)
+		.await?)
+}
+
+async fn hill_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.hill_encrypt(difficulty).await?;
+	let submission = solve::hill_encrypt(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_hill_encrypt(&submission).await?)
+}
+
+async fn hill_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.hill_decrypt(difficulty).await?;
+	let submission = solve::hill_decrypt(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_hill_decrypt(&submission).await?)
+}
+
+async fn hill_attack(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.hill_attack(difficulty).await?;
+	let submission = solve::hill_attack(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_hill_attack(&submission).await?)
+}
+
+async fn rail_fence_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.rail_fence_encrypt(difficulty).await?;
+	let submission = solve::rail_fence_encrypt(&public);
+
+	Ok(client
+		.submit_rail_fence_encrypt(&submission)
+		.await?)
+}
+
+async fn rail_fence_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.rail_fence_decrypt(difficulty).await?;
+	let submission = solve::rail_fence_decrypt(&public);
+
+	Ok(client
+		.submit_rail_fence_decrypt(&submission)
+		.await?)
+}
+
+async fn columnar_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.columnar_encrypt(difficulty).await?;
+	let submission = solve::columnar_encrypt(&public);
+
+	Ok(client
+		.submit_columnar_encrypt(&submission)
+		.await?)
+}
+
+async fn columnar_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.columnar_decrypt(difficulty).await?;
+	let submission = solve::columnar_decrypt(&public);
+
+	Ok(client
+		.submit_columnar_decrypt(&submission)
+		.await?)
+}
+
+async fn columnar_double_attack(
+	client: &Client,
+	difficulty: Difficulty,
+) -> Result<String, SolveError> {
+	let public = client.columnar_double_attack(difficulty).await?;
+	let submission = solve::columnar_double_attack(&public);
+
+	Ok(client
+		.submit_columnar_double_attack(&submission)
+		.await?)
+}
+
+async fn enigma_encrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.enigma_encrypt(difficulty).await?;
+	let submission = solve::enigma_encrypt(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_enigma_encrypt(&submission).await?)
+}
+
+async fn enigma_decrypt(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.enigma_decrypt(difficulty).await?;
+	let submission = solve::enigma_decrypt(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_enigma_decrypt(&submission).await?)
+}
+
+async fn enigma_attack(client: &Client, difficulty: Difficulty) -> Result<String, SolveError> {
+	let public = client.enigma_attack(difficulty).await?;
+	let submission = solve::enigma_attack(&public).ok_or(SolveError::Unsolvable)?;
+
+	Ok(client.submit_enigma_attack(&submission).await?)
+}
@@ -0,0 +1,839 @@
+use std::sync::LazyLock;
+
+use ctc_api::{
+	challenge::{
+		affine::{
+			self,
+			AffineAttackPublic,
+			AffineAttackSubmission,
+			AffineDecryptPublic,
+			AffineDecryptSubmission,
+			AffineEncryptPublic,
+			AffineEncryptSubmission,
+		},
+		caesar::{
+			self,
+			CaesarAttackPublic,
+			CaesarAttackSubmission,
+			CaesarCiphertextAttackPublic,
+			CaesarCiphertextAttackSubmission,
+			CaesarDecryptPublic,
+			CaesarDecryptSubmission,
+			CaesarEncryptPublic,
+			CaesarEncryptSubmission,
+		},
+		columnar::{
+			self,
+			ColumnarDecryptPublic,
+			ColumnarDecryptSubmission,
+			ColumnarDoubleAttackPublic,
+			ColumnarDoubleAttackSubmission,
+			ColumnarEncryptPublic,
+			ColumnarEncryptSubmission,
+		},
+		diffie_hellman_exchange::{DiffieHellmanExchangePublic, DiffieHellmanExchangeSubmission},
+		dss::{
+			self,
+			DssGroup,
+			DssSignPublic,
+			DssSignSubmission,
+			DssVerifyPublic,
+			DssVerifySubmission,
+		},
+		english,
+		enigma::{
+			self,
+			EnigmaAttackPublic,
+			EnigmaAttackSubmission,
+			EnigmaDecryptPublic,
+			EnigmaDecryptSubmission,
+			EnigmaEncryptPublic,
+			EnigmaEncryptSubmission,
+			Settings,
+		},
+		hill::{
+			self,
+			HillAttackPublic,
+			HillAttackSubmission,
+			HillDecryptPublic,
+			HillDecryptSubmission,
+			HillEncryptPublic,
+			HillEncryptSubmission,
+		},
+		playfair::{
+			self,
+			PlayfairDecryptPublic,
+			PlayfairDecryptSubmission,
+			PlayfairEncryptPublic,
+			PlayfairEncryptSubmission,
+		},
+		rail_fence::{
+			self,
+			RailFenceDecryptPublic,
+			RailFenceDecryptSubmission,
+			RailFenceEncryptPublic,
+			RailFenceEncryptSubmission,
+		},
+		rsa::{RsaDecryptPublic, RsaDecryptSubmission, RsaEncryptPublic, RsaEncryptSubmission},
+		substitution::{SubstitutionAttackPublic, SubstitutionAttackSubmission},
+		vigenere::{
+			self,
+			VigenereAttackPublic,
+			VigenereAttackSubmission,
+			VigenereDecryptPublic,
+			VigenereDecryptSubmission,
+			VigenereEncryptPublic,
+			VigenereEncryptSubmission,
+		},
+	},
+	math::{
+		self,
+		big::{self, BigUint},
+	},
+};
+use rand::{Rng, seq::SliceRandom};
+
+/// Fewest letters per column the Vigenère attack tries to break.
+const VIGENERE_COLUMN_SIZE: usize = 20;
+
+/// Random restarts of the substitution hill climb.
+const SUBSTITUTION_RESTARTS: usize = 20;
+
+/// Letters and the space between words.
+const SYMBOLS: usize = 27;
+
+/// Log frequencies of every run of four symbols in the bundled English
+/// corpus, indexed by [`quadgram_index`]. Unseen runs are charged well below
+/// the rarest seen one.
+static QUADGRAMS: LazyLock<Vec<f64>> = LazyLock::new(|| {
+	let text = symbols(&english::PASSAGES.join(" "));
+	let mut counts = vec![0usize; SYMBOLS.pow(4)];
+
+	for window in text.windows(4) {
+		counts[quadgram_index(window)] += 1;
+	}
+
+	let total = counts.iter().sum::<usize>() as f64;
+
+	counts
+		.into_iter()
+		.map(|count| match count {
+			0 => -8.0,
+			_ => (count as f64 / total).log10(),
+		})
+		.collect()
+});
+
+pub fn caesar_encrypt(public: &CaesarEncryptPublic) -> CaesarEncryptSubmission {
+	CaesarEncryptSubmission {
+		cipher: caesar::encrypt(&public.message, public.key),
+	}
+}
+
+pub fn caesar_decrypt(public: &CaesarDecryptPublic) -> CaesarDecryptSubmission {
+	CaesarDecryptSubmission {
+		message: caesar::decrypt(&public.cipher, public.key),
+	}
+}
+
+pub fn caesar_attack(public: &CaesarAttackPublic) -> Option<CaesarAttackSubmission> {
+	let message = public.message.bytes().next()?;
+	let cipher = public.cipher.bytes().next()?;
+
+	let submission = CaesarAttackSubmission {
+		key: math::safe_mod(cipher as i64 - message as i64, 26) as i32,
+	};
+
+	Some(submission)
+}
+
+/// Tries every key and keeps the one whose plaintext is best covered by
+/// common English words, falling back on letter frequencies to break ties.
+pub fn caesar_ciphertext_attack(
+	public: &CaesarCiphertextAttackPublic,
+) -> CaesarCiphertextAttackSubmission {
+	let key = (0..26).max_by(|a, b| {
+		let a = caesar::decrypt(&public.cipher, *a);
+		let b = caesar::decrypt(&public.cipher, *b);
+
+		word_coverage(&a)
+			.cmp(&word_coverage(&b))
+			.then(english::chi_squared(&b).total_cmp(&english::chi_squared(&a)))
+	});
+
+	CaesarCiphertextAttackSubmission {
+		key,
+		message: None,
+	}
+}
+
+pub fn diffie_hellman_exchange<R: Rng + ?Sized>(
+	rng: &mut R,
+	public: &DiffieHellmanExchangePublic,
+) -> DiffieHellmanExchangeSubmission {
+	let sk_user = big::random_range(rng, &BigUint::from(2u32), &(&public.n - 1u32));
+
+	DiffieHellmanExchangeSubmission {
+		pk_user: public.g.modpow(&sk_user, &public.n),
+		k:       public.pk_server.modpow(&sk_user, &public.n),
+	}
+}
+
+pub fn rsa_encrypt(public: &RsaEncryptPublic) -> RsaEncryptSubmission {
+	RsaEncryptSubmission {
+		c: public
+			.m
+			.modpow(&public.e, &(&public.p * &public.q)),
+	}
+}
+
+pub fn rsa_decrypt(public: &RsaDecryptPublic) -> RsaDecryptSubmission {
+	RsaDecryptSubmission {
+		m: public
+			.c
+			.modpow(&public.d, &(&public.p * &public.q)),
+	}
+}
+
+pub fn dss_sign<R: Rng + ?Sized>(rng: &mut R, public: &DssSignPublic) -> Option<DssSignSubmission> {
+	let group = DssGroup {
+		p: public.p.clone(),
+		q: public.q.clone(),
+		g: public.g.clone(),
+	};
+
+	let digest = dss::h_digest(&public.h, &public.m, &public.q)?;
+
+	let sk = group.random_exponent(rng);
+	let pk = public.g.modpow(&sk, &public.p);
+
+	let (r, s) = group.sign(rng, &sk, &digest)?;
+
+	Some(DssSignSubmission {
+		pk,
+		r,
+		s,
+	})
+}
+
+pub fn dss_verify(public: &DssVerifyPublic) -> Option<DssVerifySubmission> {
+	let digest = dss::h_digest(&public.h, &public.m, &public.q)?;
+	let s_inv_q = big::inverse_mod(&public.s, &public.q)?;
+
+	let u = digest * &s_inv_q % &public.q;
+	let v = big::sub_mod(&BigUint::ZERO, &public.r, &public.q) * &s_inv_q % &public.q;
+
+	let w = public.g.modpow(&u, &public.p) * public.pk.modpow(&v, &public.p) % &public.p;
+
+	let submission = DssVerifySubmission {
+		u,
+		v,
+		w: w % &public.q,
+	};
+
+	Some(submission)
+}
+
+pub fn vigenere_encrypt(public: &VigenereEncryptPublic) -> VigenereEncryptSubmission {
+	VigenereEncryptSubmission {
+		cipher: vigenere::encrypt(&public.message, &public.key),
+	}
+}
+
+pub fn vigenere_decrypt(public: &VigenereDecryptPublic) -> VigenereDecryptSubmission {
+	VigenereDecryptSubmission {
+		message: vigenere::decrypt(&public.cipher, &public.key),
+	}
+}
+
+/// Finds the key length by index of coincidence, then breaks each column as
+/// a Caesar shift by comparing letter frequencies with English.
+pub fn vigenere_attack(public: &VigenereAttackPublic) -> Option<VigenereAttackSubmission> {
+	let key_length =
+		vigenere::key_length(&public.cipher, public.cipher.len() / VIGENERE_COLUMN_SIZE)?;
+
+	let submission = VigenereAttackSubmission {
+		key: vigenere::recover_key(&public.cipher, key_length),
+	};
+
+	Some(submission)
+}
+
+pub fn affine_encrypt(public: &AffineEncryptPublic) -> AffineEncryptSubmission {
+	AffineEncryptSubmission {
+		cipher: affine::encrypt(&public.message, public.a, public.b),
+	}
+}
+
+pub fn affine_decrypt(public: &AffineDecryptPublic) -> Option<AffineDecryptSubmission> {
+	let submission = AffineDecryptSubmission {
+		message: affine::decrypt(&public.cipher, public.a, public.b)?,
+	};
+
+	Some(submission)
+}
+
+/// Solves the two linear equations the known pairs give for `a` and `b`.
+pub fn affine_attack(public: &AffineAttackPublic) -> Option<AffineAttackSubmission> {
+	let (a, b) = affine::recover_key(&public.message, &public.cipher)?;
+
+	Some(AffineAttackSubmission {
+		a,
+		b,
+	})
+}
+
+/// Hill-climbs over keys by swapping pairs of letters, scoring each candidate
+/// plaintext by how English its quadgrams look. Starts from the key that
+/// matches letter frequencies, then from random keys to escape local maxima.
+pub fn substitution_attack<R: Rng + ?Sized>(
+	rng: &mut R,
+	public: &SubstitutionAttackPublic,
+) -> SubstitutionAttackSubmission {
+	let cipher = symbols(&public.cipher);
+	let counts = english::letter_counts(&public.cipher);
+
+	let mut by_count = (0..26).collect::<Vec<usize>>();
+	by_count.sort_by_key(|letter| std::cmp::Reverse(counts[*letter]));
+
+	let mut by_frequency = (0..26).collect::<Vec<usize>>();
+	by_frequency.sort_by(|a, b| english::FREQUENCIES[*b].total_cmp(&english::FREQUENCIES[*a]));
+
+	// maps each cipher letter to the plaintext letter it stands for
+	let mut best = [0u8; 26];
+
+	for (cipher_letter, plain_letter) in by_count.iter().zip(&by_frequency) {
+		best[*cipher_letter] = *plain_letter as u8;
+	}
+
+	let mut best_fitness = quadgram_fitness(&cipher, &best);
+
+	for restart in 0..SUBSTITUTION_RESTARTS {
+		let mut key = best;
+
+		if restart > 0 {
+			key.shuffle(rng);
+		}
+
+		let mut fitness = quadgram_fitness(&cipher, &key);
+		let mut improved = true;
+
+		while improved {
+			improved = false;
+
+			for i in 0..26 {
+				for j in i + 1..26 {
+					key.swap(i, j);
+					let candidate = quadgram_fitness(&cipher, &key);
+
+					if candidate > fitness {
+						fitness = candidate;
+						improved = true;
+					} else {
+						key.swap(i, j);
+					}
+				}
+			}
+		}
+
+		if fitness > best_fitness {
+			best = key;
+			best_fitness = fitness;
+		}
+	}
+
+	let mut key = [b'A'; 26];
+
+	for (cipher_letter, plain_letter) in best.iter().enumerate() {
+		key[*plain_letter as usize] = b'A' + cipher_letter as u8;
+	}
+
+	SubstitutionAttackSubmission {
+		key:     String::from_utf8(key.to_vec()).ok(),
+		message: None,
+	}
+}
+
+/// Counts the most letters of `text` that can be split into words from
+/// [`english::WORDS`], leaving the rest uncovered.
+pub fn playfair_encrypt(public: &PlayfairEncryptPublic) -> PlayfairEncryptSubmission {
+	PlayfairEncryptSubmission {
+		cipher: playfair::encrypt(&public.message, &public.keyword),
+	}
+}
+
+pub fn playfair_decrypt(public: &PlayfairDecryptPublic) -> PlayfairDecryptSubmission {
+	PlayfairDecryptSubmission {
+		message: playfair::decrypt(&public.cipher, &public.keyword),
+	}
+}
+
+pub fn hill_encrypt(public: &HillEncryptPublic) -> Option<HillEncryptSubmission> {
+	let submission = HillEncryptSubmission {
+		cipher: hill::encrypt(&public.message, &public.key)?,
+	};
+
+	Some(submission)
+}
+
+pub fn hill_decrypt(public: &HillDecryptPublic) -> Option<HillDecryptSubmission> {
+	let submission = HillDecryptSubmission {
+		message: hill::decrypt(&public.cipher, &public.key)?,
+	};
+
+	Some(submission)
+}
+
+/// The known plaintext holds exactly as many blocks as the key has rows, so
+/// its length is the square of the key size.
+pub fn hill_attack(public: &HillAttackPublic) -> Option<HillAttackSubmission> {
+	let size = public.message.len().isqrt();
+
+	let submission = HillAttackSubmission {
+		key: hill::recover_key(&public.message, &public.cipher, size)?,
+	};
+
+	Some(submission)
+}
+
+pub fn rail_fence_encrypt(public: &RailFenceEncryptPublic) -> RailFenceEncryptSubmission {
+	RailFenceEncryptSubmission {
+		cipher: rail_fence::encrypt(&public.message, public.rails),
+	}
+}
+
+pub fn rail_fence_decrypt(public: &RailFenceDecryptPublic) -> RailFenceDecryptSubmission {
+	RailFenceDecryptSubmission {
+		message: rail_fence::decrypt(&public.cipher, public.rails),
+	}
+}
+
+pub fn columnar_encrypt(public: &ColumnarEncryptPublic) -> ColumnarEncryptSubmission {
+	ColumnarEncryptSubmission {
+		cipher: columnar::encrypt(&public.message, &public.key),
+	}
+}
+
+pub fn columnar_decrypt(public: &ColumnarDecryptPublic) -> ColumnarDecryptSubmission {
+	ColumnarDecryptSubmission {
+		message: columnar::decrypt(&public.cipher, &public.key),
+	}
+}
+
+/// Tries every pair of column orders and keeps the decryption best covered by
+/// common English words. Undoing the second transposition once per order
+/// keeps the search at `n!` first decryptions and `n!²` second ones.
+pub fn columnar_double_attack(
+	public: &ColumnarDoubleAttackPublic,
+) -> ColumnarDoubleAttackSubmission {
+	let keys = column_keys(public.key_size);
+
+	let message = keys
+		.iter()
+		.flat_map(|second_key| {
+			let inner = columnar::decrypt(&public.cipher, second_key);
+
+			keys.iter()
+				.map(move |first_key| columnar::decrypt(&inner, first_key))
+		})
+		.max_by_key(|message| word_coverage(message))
+		.unwrap_or_default();
+
+	ColumnarDoubleAttackSubmission {
+		message,
+	}
+}
+
+pub fn enigma_encrypt(public: &EnigmaEncryptPublic) -> Option<EnigmaEncryptSubmission> {
+	let settings = Settings::parse(
+		&public.rotors,
+		&public.rings,
+		&public.positions,
+		&public.plugboard,
+	)?;
+
+	let submission = EnigmaEncryptSubmission {
+		cipher: enigma::encrypt(&public.message, &settings),
+	};
+
+	Some(submission)
+}
+
+pub fn enigma_decrypt(public: &EnigmaDecryptPublic) -> Option<EnigmaDecryptSubmission> {
+	let settings = Settings::parse(
+		&public.rotors,
+		&public.rings,
+		&public.positions,
+		&public.plugboard,
+	)?;
+
+	let submission = EnigmaDecryptSubmission {
+		message: enigma::decrypt(&public.cipher, &settings),
+	};
+
+	Some(submission)
+}
+
+/// Tries all 26³ start positions and keeps the first under which the crib
+/// encrypts to the start of the cipher.
+pub fn enigma_attack(public: &EnigmaAttackPublic) -> Option<EnigmaAttackSubmission> {
+	let mut settings = Settings::parse(&public.rotors, &public.rings, "AAA", &public.plugboard)?;
+	let prefix = public.cipher.get(..public.crib.len())?;
+
+	let positions = (0..26 * 26 * 26).find_map(|index: usize| {
+		settings.positions = [
+			(index / 676) as u8,
+			(index / 26 % 26) as u8,
+			(index % 26) as u8,
+		];
+
+		(enigma::encrypt(&public.crib, &settings) == prefix)
+			.then(|| enigma::letter_string(&settings.positions))
+	})?;
+
+	let submission = EnigmaAttackSubmission {
+		positions,
+	};
+
+	Some(submission)
+}
+
+/// One key per column order of `size` columns, naming the column read `i`th
+/// with the `i`th letter of the alphabet.
+fn column_keys(size: usize) -> Vec<String> {
+	let mut orders = vec![Vec::new()];
+
+	for column in 0..size {
+		orders = orders
+			.into_iter()
+			.flat_map(|order: Vec<usize>| {
+				(0..=order.len()).map(move |index| {
+					let mut order = order.clone();
+					order.insert(index, column);
+					order
+				})
+			})
+			.collect();
+	}
+
+	orders
+		.into_iter()
+		.map(|order| {
+			let mut key = vec![b'A'; size];
+
+			for (rank, column) in order.into_iter().enumerate() {
+				key[column] = b'A' + rank as u8;
+			}
+
+			String::from_utf8_lossy(&key).into_owned()
+		})
+		.collect()
+}
+
+fn word_coverage(text: &str) -> usize {
+	let mut covered = vec![0; text.len() + 1];
+
+	for end in 1..=text.len() {
+		covered[end] = english::WORDS
+			.iter()
+			.filter(|word| text[..end].ends_with(*word))
+			.map(|word| covered[end - word.len()] + word.len())
+			.fold(covered[end - 1], usize::max);
+	}
+
+	covered[text.len()]
+}
+
+/// Turns the letters of `text` into 0 to 25 and each run of whitespace into a
+/// single 26, dropping everything else.
+fn symbols(text: &str) -> Vec<u8> {
+	text.split_whitespace()
+		.map(|word| {
+			word.bytes()
+				.filter(u8::is_ascii_alphabetic)
+				.map(|byte| byte.to_ascii_uppercase() - b'A')
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>()
+		.join(&(SYMBOLS as u8 - 1))
+}
+
+fn quadgram_index(window: &[u8]) -> usize {
+	window
+		.iter()
+		.fold(0, |index, symbol| index * SYMBOLS + *symbol as usize)
+}
+
+/// Sums the quadgram log frequencies of `cipher` decrypted with `key`.
+fn quadgram_fitness(cipher: &[u8], key: &[u8; 26]) -> f64 {
+	let decrypt = |symbol: u8| match symbol {
+		26 => 26,
+		_ => key[symbol as usize],
+	};
+
+	cipher
+		.windows(4)
+		.map(|window| {
+			let index = window.iter().fold(0, |index, symbol| {
+				index * SYMBOLS + decrypt(*symbol) as usize
+			});
+
+			QUADGRAMS[index]
+		})
+		.sum()
+}
+
+#[cfg(test)]
+mod tests {
+	use ctc_api::challenge::{
+		KeySize,
+		affine::{AffineAttackChallenge, AffineDecryptChallenge, AffineEncryptChallenge},
+		caesar::{
+			CaesarAttackChallenge,
+			CaesarCiphertextAttackChallenge,
+			CaesarDecryptChallenge,
+			CaesarEncryptChallenge,
+		},
+		columnar::{
+			ColumnarDecryptChallenge,
+			ColumnarDoubleAttackChallenge,
+			ColumnarEncryptChallenge,
+		},
+		diffie_hellman_exchange::DiffieHellmanExchangeChallenge,
+		dss::{DssSignChallenge, DssVerifyChallenge},
+		enigma::{EnigmaAttackChallenge, EnigmaDecryptChallenge, EnigmaEncryptChallenge},
+		hill::{HillAttackChallenge, HillDecryptChallenge, HillEncryptChallenge},
+		playfair::{PlayfairDecryptChallenge, PlayfairEncryptChallenge},
+		rail_fence::{RailFenceDecryptChallenge, RailFenceEncryptChallenge},
+		rsa::{RsaDecryptChallenge, RsaEncryptChallenge},
+		substitution::SubstitutionAttackChallenge,
+		vigenere::{VigenereAttackChallenge, VigenereDecryptChallenge, VigenereEncryptChallenge},
+	};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use crate::solve::*;
+
+	const ATTEMPTS: usize = 10;
+	const RANGE: std::ops::RangeInclusive<usize> = 1_000..=10_000;
+	const BITS: KeySize = KeySize::Bits(256);
+
+	#[test]
+	fn it_solves_caesar_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = CaesarEncryptChallenge::generate(&mut rng, 6);
+			let submission = caesar_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = CaesarDecryptChallenge::generate(&mut rng, 6);
+			let submission = caesar_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.message));
+
+			let attack = CaesarAttackChallenge::generate(&mut rng, 6);
+			let submission = caesar_attack(&attack.clone().into()).unwrap();
+			assert!(attack.verify(submission.key));
+
+			let attack = CaesarCiphertextAttackChallenge::generate(&mut rng, 30);
+			let submission = caesar_ciphertext_attack(&attack.clone().into());
+			assert!(attack.verify(&submission));
+		}
+	}
+
+	#[test]
+	fn it_solves_vigenere_challenges() {
+		// the attack is statistical and fails on the odd unlucky message, so
+		// the test draws from a fixed seed
+		let mut rng = StdRng::seed_from_u64(1);
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = VigenereEncryptChallenge::generate(&mut rng, 5, 12);
+			let submission = vigenere_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = VigenereDecryptChallenge::generate(&mut rng, 5, 12);
+			let submission = vigenere_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.message));
+
+			let attack = VigenereAttackChallenge::generate(&mut rng, 5, 300);
+			let submission = vigenere_attack(&attack.clone().into()).unwrap();
+			assert!(attack.verify(&submission.key));
+		}
+	}
+
+	#[test]
+	fn it_solves_affine_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = AffineEncryptChallenge::generate(&mut rng, 6);
+			let submission = affine_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = AffineDecryptChallenge::generate(&mut rng, 6);
+			let submission = affine_decrypt(&decrypt.clone().into()).unwrap();
+			assert!(decrypt.verify(&submission.message));
+
+			let attack = AffineAttackChallenge::generate(&mut rng);
+			let submission = affine_attack(&attack.clone().into()).unwrap();
+			assert!(attack.verify(submission.a, submission.b));
+		}
+	}
+
+	#[test]
+	fn it_solves_substitution_challenges() {
+		// the hill climb is randomised and statistical, so the test draws from
+		// a fixed seed
+		let mut rng = StdRng::seed_from_u64(1);
+
+		for _ in 0..ATTEMPTS {
+			let attack = SubstitutionAttackChallenge::generate(&mut rng, 300);
+			let submission = substitution_attack(&mut rng, &attack.clone().into());
+			assert!(attack.verify(&submission, 0.9));
+		}
+	}
+
+	#[test]
+	fn it_solves_playfair_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = PlayfairEncryptChallenge::generate(&mut rng, 10);
+			let submission = playfair_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = PlayfairDecryptChallenge::generate(&mut rng, 10);
+			let submission = playfair_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.message));
+		}
+	}
+
+	#[test]
+	fn it_solves_hill_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			for size in [2, 3] {
+				let encrypt = HillEncryptChallenge::generate(&mut rng, size, 6);
+				let submission = hill_encrypt(&encrypt.clone().into()).unwrap();
+				assert!(encrypt.verify(&submission.cipher));
+
+				let decrypt = HillDecryptChallenge::generate(&mut rng, size, 6);
+				let submission = hill_decrypt(&decrypt.clone().into()).unwrap();
+				assert!(decrypt.verify(&submission.message));
+
+				let attack = HillAttackChallenge::generate(&mut rng, size);
+				let submission = hill_attack(&attack.clone().into()).unwrap();
+				assert!(attack.verify(&submission.key));
+			}
+		}
+	}
+
+	#[test]
+	fn it_solves_transposition_challenges() {
+		// the attack picks the decryption that reads best as English, which
+		// can tie on an unlucky message, so the test draws from a fixed seed
+		let mut rng = StdRng::seed_from_u64(1);
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = RailFenceEncryptChallenge::generate(&mut rng, 12);
+			let submission = rail_fence_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = RailFenceDecryptChallenge::generate(&mut rng, 12);
+			let submission = rail_fence_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.message));
+
+			let encrypt = ColumnarEncryptChallenge::generate(&mut rng, 4, 12);
+			let submission = columnar_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = ColumnarDecryptChallenge::generate(&mut rng, 4, 12);
+			let submission = columnar_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.message));
+
+			let attack = ColumnarDoubleAttackChallenge::generate(&mut rng, 4, 40);
+			let submission = columnar_double_attack(&attack.clone().into());
+			assert!(attack.verify(&submission.message));
+		}
+	}
+
+	#[test]
+	fn it_solves_enigma_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = EnigmaEncryptChallenge::generate(&mut rng, 10, 12);
+			let submission = enigma_encrypt(&encrypt.clone().into()).unwrap();
+			assert!(encrypt.verify(&submission.cipher));
+
+			let decrypt = EnigmaDecryptChallenge::generate(&mut rng, 10, 12);
+			let submission = enigma_decrypt(&decrypt.clone().into()).unwrap();
+			assert!(decrypt.verify(&submission.message));
+
+			let attack = EnigmaAttackChallenge::generate(&mut rng, 10, 10, 30);
+			let submission = enigma_attack(&attack.clone().into()).unwrap();
+			assert!(attack.verify(&submission.positions));
+		}
+	}
+
+	#[test]
+	fn it_solves_diffie_hellman_exchanges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let exchange = DiffieHellmanExchangeChallenge::generate(&mut rng, RANGE).unwrap();
+			let submission = diffie_hellman_exchange(&mut rng, &exchange.clone().into());
+			assert!(exchange.verify(&submission.pk_user, &submission.k));
+		}
+	}
+
+	#[test]
+	fn it_solves_rsa_challenges() {
+		let mut rng = rand::rng();
+
+		for _ in 0..ATTEMPTS {
+			let encrypt = RsaEncryptChallenge::generate(&mut rng, RANGE).unwrap();
+			let submission = rsa_encrypt(&encrypt.clone().into());
+			assert!(encrypt.verify(&submission.c));
+
+			let decrypt = RsaDecryptChallenge::generate(&mut rng, RANGE).unwrap();
+			let submission = rsa_decrypt(&decrypt.clone().into());
+			assert!(decrypt.verify(&submission.m));
+		}
+	}
+
+	#[test]
+	fn it_solves_dss_challenges() {
+		let mut rng = StdRng::seed_from_u64(1);
+
+		for _ in 0..ATTEMPTS {
+			let sign = DssSignChallenge::generate(&mut rng, RANGE).unwrap();
+			let submission = dss_sign(&mut rng, &sign.clone().into()).unwrap();
+			assert!(sign.verify(&submission.pk, &submission.r, &submission.s));
+
+			let verify = DssVerifyChallenge::generate(&mut rng, RANGE).unwrap();
+			let submission = dss_verify(&verify.clone().into()).unwrap();
+			assert!(verify.verify(&submission.u, &submission.v, &submission.w));
+		}
+	}
+
+	#[test]
+	fn it_solves_large_parameters() {
+		let mut rng = rand::rng();
+
+		let exchange = DiffieHellmanExchangeChallenge::generate(&mut rng, BITS).unwrap();
+		let submission = diffie_hellman_exchange(&mut rng, &exchange.clone().into());
+		assert!(exchange.verify(&submission.pk_user, &submission.k));
+
+		let decrypt = RsaDecryptChallenge::generate(&mut rng, BITS).unwrap();
+		let submission = rsa_decrypt(&decrypt.clone().into());
+		assert!(decrypt.verify(&submission.m));
+		assert!(decrypt.p.bits() >= 128);
+
+		let verify = DssVerifyChallenge::generate(&mut rng, BITS).unwrap();
+		let submission = dss_verify(&verify.clone().into()).unwrap();
+		assert!(verify.verify(&submission.u, &submission.v, &submission.w));
+		assert_eq!(256, verify.p.bits());
+	}
+}
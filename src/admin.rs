@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::challenge::{ChallengeKind, Difficulty};
+
+/// A stored challenge generated again from its seed. `matches` is false when
+/// the configured parameter ranges have changed since it was created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegeneratedChallenge {
+	pub challenge:  ChallengeKind,
+	pub id:         i32,
+	pub seed:       u64,
+	pub difficulty: Difficulty,
+	pub parameters: Value,
+	pub matches:    bool,
+}
@@ -0,0 +1,315 @@
+use std::env;
+
+use anyhow::anyhow;
+use clap::{Parser, Subcommand, ValueEnum};
+use ctc_api::{
+	challenge::{ChallengeKind, Difficulty},
+	leaderboard::LeaderboardResult,
+	models::{
+		challenge_model::ChallengeModel,
+		user::{NewUser, User},
+	},
+	state::{self, AppState},
+	sweeper,
+	user::UserCredentials,
+	with_challenge_model,
+};
+use validator::Validate;
+
+#[derive(Parser)]
+#[command(name = "ctc-admin", about = "Administers a ctc-api deployment")]
+struct Args {
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Runs or reverts database migrations
+	#[command(subcommand)]
+	Migrate(MigrateCommand),
+
+	/// Manages users
+	#[command(subcommand)]
+	User(UserCommand),
+
+	/// Manages completed challenges
+	#[command(subcommand)]
+	Completions(CompletionsCommand),
+
+	/// Exports leaderboards
+	#[command(subcommand)]
+	Leaderboard(LeaderboardCommand),
+
+	/// Expires stale in-progress challenges
+	Sweep,
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+	/// Runs all pending migrations
+	Run,
+
+	/// Reverts the most recently applied migrations
+	Revert {
+		#[arg(long, default_value_t = 1)]
+		steps: usize,
+	},
+}
+
+#[derive(Subcommand)]
+enum UserCommand {
+	/// Creates a new user
+	Create {
+		username: String,
+		password: String,
+
+		#[arg(long)]
+		admin: bool,
+	},
+
+	/// Grants a user admin rights
+	Promote { username: String },
+
+	/// Revokes a user's admin rights
+	Demote { username: String },
+
+	/// Bans a user and ends their session
+	Ban { username: String },
+
+	/// Lifts a user's ban
+	Unban { username: String },
+
+	/// Sets a new password for a user and ends their session
+	ResetPassword { username: String, password: String },
+
+	/// Removes every cache entry belonging to a user
+	PurgeCache { username: String },
+}
+
+#[derive(Subcommand)]
+enum CompletionsCommand {
+	/// Deletes a user's completions of a challenge
+	Delete {
+		challenge: ChallengeKind,
+		username:  String,
+
+		/// Only delete the completion with this id
+		#[arg(long)]
+		id: Option<i32>,
+	},
+}
+
+#[derive(Subcommand)]
+enum LeaderboardCommand {
+	/// Prints a challenge's leaderboard
+	Export {
+		challenge: ChallengeKind,
+
+		#[arg(long, default_value_t = Difficulty::Medium)]
+		difficulty: Difficulty,
+
+		#[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+		format: ExportFormat,
+	},
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+	Csv,
+	Json,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = dotenvy::dotenv();
+
+	let subscriber = tracing_subscriber::FmtSubscriber::new();
+	tracing::subscriber::set_global_default(subscriber)?;
+
+	match Args::parse().command {
+		Command::Migrate(command) => migrate(command),
+		Command::User(command) => user(&AppState::connect().await?, command).await,
+		Command::Completions(command) => completions(&AppState::connect().await?, command).await,
+		Command::Leaderboard(command) => leaderboard(&AppState::connect().await?, command).await,
+		Command::Sweep => {
+			let expired = sweeper::sweep(&AppState::connect().await?).await?;
+			tracing::info!("expired {expired} stale challenges");
+
+			Ok(())
+		},
+	}
+}
+
+fn migrate(command: MigrateCommand) -> anyhow::Result<()> {
+	let db_addr = env::var("DATABASE_URL")?;
+
+	match command {
+		MigrateCommand::Run => state::run_db_migrations(&db_addr),
+		MigrateCommand::Revert {
+			steps,
+		} => state::revert_db_migrations(&db_addr, steps),
+	}
+}
+
+async fn user(state: &AppState, command: UserCommand) -> anyhow::Result<()> {
+	match command {
+		UserCommand::Create {
+			username,
+			password,
+			admin,
+		} => {
+			let credentials = UserCredentials {
+				username,
+				password,
+			};
+
+			credentials.validate()?;
+
+			if User::find_by_username(state, &credentials.username)
+				.await?
+				.is_some()
+			{
+				return Err(anyhow!("user {} already exists", credentials.username));
+			}
+
+			let new_user = NewUser {
+				username:      credentials.username,
+				password_hash: User::hash_password(&credentials.password)?,
+			};
+
+			let user = User::create(state, new_user).await?;
+
+			if admin {
+				user.set_admin(state, true).await?;
+			}
+		},
+
+		UserCommand::Promote {
+			username,
+		} => {
+			find_user(state, &username)
+				.await?
+				.set_admin(state, true)
+				.await?;
+		},
+
+		UserCommand::Demote {
+			username,
+		} => {
+			find_user(state, &username)
+				.await?
+				.set_admin(state, false)
+				.await?;
+		},
+
+		UserCommand::Ban {
+			username,
+		} => {
+			find_user(state, &username)
+				.await?
+				.set_banned(state, true)
+				.await?;
+		},
+
+		UserCommand::Unban {
+			username,
+		} => {
+			find_user(state, &username)
+				.await?
+				.set_banned(state, false)
+				.await?;
+		},
+
+		UserCommand::ResetPassword {
+			username,
+			password,
+		} => {
+			let credentials = UserCredentials {
+				username,
+				password,
+			};
+
+			credentials.validate()?;
+
+			find_user(state, &credentials.username)
+				.await?
+				.set_password(state, &credentials.password)
+				.await?;
+		},
+
+		UserCommand::PurgeCache {
+			username,
+		} => {
+			find_user(state, &username)
+				.await?
+				.purge_cached(state)?;
+		},
+	}
+
+	Ok(())
+}
+
+async fn completions(state: &AppState, command: CompletionsCommand) -> anyhow::Result<()> {
+	match command {
+		CompletionsCommand::Delete {
+			challenge,
+			username,
+			id,
+		} => {
+			let user = find_user(state, &username).await?;
+
+			let deleted = with_challenge_model!(challenge, M => {
+				M::delete_completed(state, user.id, id).await?
+			});
+
+			tracing::info!("deleted {deleted} {challenge} completions of {username}");
+		},
+	}
+
+	Ok(())
+}
+
+async fn leaderboard(state: &AppState, command: LeaderboardCommand) -> anyhow::Result<()> {
+	match command {
+		LeaderboardCommand::Export {
+			challenge,
+			difficulty,
+			format,
+		} => {
+			let results =
+				with_challenge_model!(challenge, M => M::leaderboard(state, difficulty).await?);
+
+			print_leaderboard(&results, format)?;
+		},
+	}
+
+	Ok(())
+}
+
+async fn find_user(state: &AppState, username: &str) -> anyhow::Result<User> {
+	User::find_by_username(state, username)
+		.await?
+		.ok_or_else(|| anyhow!("user {username} not found"))
+}
+
+fn print_leaderboard(results: &[LeaderboardResult], format: ExportFormat) -> anyhow::Result<()> {
+	match format {
+		ExportFormat::Csv => {
+			println!("rank,username,duration_ms");
+
+			for (index, result) in results.iter().enumerate() {
+				println!(
+					"{},{},{}",
+					index + 1,
+					result.username,
+					result.duration.as_secs_f64() * 1_000.0,
+				);
+			}
+		},
+
+		ExportFormat::Json => println!("{}", serde_json::to_string_pretty(results)?),
+	}
+
+	Ok(())
+}
@@ -0,0 +1,86 @@
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{challenge::affine, math};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AffineAttackChallenge {
+	pub a:       i32,
+	pub b:       i32,
+	pub message: String,
+	pub cipher:  String,
+}
+
+/// Two known plaintext letters and the cipher letters they became.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffineAttackPublic {
+	pub message: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AffineAttackSubmission {
+	#[validate(
+		range(min = 1, max = 25, message = "a must be in the range [1, 25]."),
+		custom(function = "affine::validate_multiplier")
+	)]
+	pub a: i32,
+
+	#[validate(range(min = 0, max = 25, message = "b must be in the range [0, 25]."))]
+	pub b: i32,
+}
+
+impl AffineAttackChallenge {
+	/// Picks two plaintext letters whose difference is invertible mod 26, so
+	/// that exactly one key maps them to their cipher letters.
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+		let (a, b) = affine::random_key(rng);
+
+		let first = rng.random_range(0..26u8);
+		let difference = affine::random_multiplier(rng);
+		let second = math::safe_mod(first as i32 + difference, 26) as u8;
+
+		let message = [first + b'A', second + b'A']
+			.iter()
+			.map(|byte| *byte as char)
+			.collect::<String>();
+
+		let cipher = affine::encrypt(&message, a, b);
+
+		AffineAttackChallenge {
+			a,
+			b,
+			message,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, a: i32, b: i32) -> bool {
+		affine::is_key(a) && affine::encrypt(&self.message, a, b) == self.cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let message = self.message.as_bytes();
+		let difference = math::safe_mod(message[0] as i32 - message[1] as i32, 26);
+
+		vec![
+			"Each pair gives an equation y = a·x + b mod 26; subtract the two to eliminate b."
+				.to_owned(),
+			format!(
+				"The plaintext letters differ by {difference}, whose inverse mod 26 is {}.",
+				math::inverse_mod(difference, 26).unwrap_or_default()
+			),
+			format!("a is {}.", self.a),
+		]
+	}
+}
+
+impl From<AffineAttackChallenge> for AffineAttackPublic {
+	fn from(challenge: AffineAttackChallenge) -> Self {
+		AffineAttackPublic {
+			message: challenge.message,
+			cipher:  challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,76 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{challenge::affine, math};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AffineDecryptChallenge {
+	pub a:      i32,
+	pub b:      i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffineDecryptPublic {
+	pub a:      i32,
+	pub b:      i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AffineDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl AffineDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, cipher_size: usize) -> Self {
+		let (a, b) = affine::random_key(rng);
+
+		let cipher = Alphabetic
+			.sample_string(rng, cipher_size.max(1))
+			.to_uppercase();
+
+		AffineDecryptChallenge {
+			a,
+			b,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		affine::decrypt(&self.cipher, self.a, self.b).is_some_and(|decrypted| decrypted == message)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.cipher[..1];
+
+		vec![
+			"Undo the encryption by replacing each letter y with a⁻¹·(y - b) mod 26, where a⁻¹ is the inverse of a mod 26."
+				.to_owned(),
+			format!(
+				"The inverse of {} mod 26 is {}.",
+				self.a,
+				math::inverse_mod(self.a, 26).unwrap_or_default()
+			),
+			format!(
+				"The first letter, {first}, becomes {}.",
+				affine::decrypt(first, self.a, self.b).unwrap_or_default()
+			),
+		]
+	}
+}
+
+impl From<AffineDecryptChallenge> for AffineDecryptPublic {
+	fn from(challenge: AffineDecryptChallenge) -> Self {
+		AffineDecryptPublic {
+			a:      challenge.a,
+			b:      challenge.b,
+			cipher: challenge.cipher,
+		}
+	}
+}
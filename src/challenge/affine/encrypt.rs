@@ -0,0 +1,78 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::affine;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AffineEncryptChallenge {
+	pub a:       i32,
+	pub b:       i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffineEncryptPublic {
+	pub a:       i32,
+	pub b:       i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AffineEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl AffineEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let (a, b) = affine::random_key(rng);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		AffineEncryptChallenge {
+			a,
+			b,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		affine::encrypt(&self.message, self.a, self.b) == cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.message[..1];
+
+		vec![
+			"Number the letters from A = 0 and replace each letter x with (a·x + b) mod 26."
+				.to_owned(),
+			format!(
+				"With a = {} and b = {}, A becomes {} and B becomes {}.",
+				self.a,
+				self.b,
+				affine::encrypt("A", self.a, self.b),
+				affine::encrypt("B", self.a, self.b)
+			),
+			format!(
+				"The first letter, {first}, becomes {}.",
+				affine::encrypt(first, self.a, self.b)
+			),
+		]
+	}
+}
+
+impl From<AffineEncryptChallenge> for AffineEncryptPublic {
+	fn from(challenge: AffineEncryptChallenge) -> Self {
+		AffineEncryptPublic {
+			a:       challenge.a,
+			b:       challenge.b,
+			message: challenge.message,
+		}
+	}
+}
@@ -0,0 +1,159 @@
+mod encrypt;
+pub use crate::challenge::affine::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::affine::decrypt::*;
+
+mod attack;
+pub use crate::challenge::affine::attack::*;
+
+use rand::{Rng, RngExt, seq::IndexedRandom};
+use validator::ValidationError;
+
+use crate::math;
+
+pub fn encrypt(message: &str, a: i32, b: i32) -> String {
+	message
+		.bytes()
+		.map(|char| {
+			let x = (char - b'A') as i64;
+			(math::safe_mod(a as i64 * x + b as i64, 26) as u8 + b'A') as char
+		})
+		.collect::<String>()
+}
+
+/// Returns `None` when `a` has no inverse mod 26.
+pub fn decrypt(cipher: &str, a: i32, b: i32) -> Option<String> {
+	let a_inverse = math::inverse_mod(math::safe_mod(a, 26), 26)? as i64;
+
+	let message = cipher
+		.bytes()
+		.map(|char| {
+			let y = (char - b'A') as i64;
+			(math::safe_mod(a_inverse * (y - b as i64), 26) as u8 + b'A') as char
+		})
+		.collect::<String>();
+
+	Some(message)
+}
+
+/// Valid multipliers have an inverse mod 26.
+pub fn is_key(a: i32) -> bool {
+	math::gcd(math::safe_mod(a, 26), 26) == 1
+}
+
+/// The multipliers in [1, 25] with an inverse mod 26.
+pub fn multipliers() -> Vec<i32> {
+	(1..26)
+		.filter(|a| math::gcd(*a, 26) == 1)
+		.collect()
+}
+
+pub fn random_multiplier<R: Rng + ?Sized>(rng: &mut R) -> i32 {
+	multipliers().choose(rng).copied().unwrap_or(1)
+}
+
+/// Rejects submitted multipliers that have no inverse mod 26.
+pub fn validate_multiplier(a: i32) -> Result<(), ValidationError> {
+	if !is_key(a) {
+		let error =
+			ValidationError::new("multiplier").with_message("a must be coprime to 26.".into());
+
+		return Err(error);
+	}
+
+	Ok(())
+}
+
+pub fn random_key<R: Rng + ?Sized>(rng: &mut R) -> (i32, i32) {
+	let a = random_multiplier(rng);
+	let b = rng.random_range(0..26);
+
+	(a, b)
+}
+
+/// Solves `y = a·x + b (mod 26)` for the first two letters of a known
+/// plaintext and its cipher. Subtracting the equations leaves
+/// `y₁ - y₂ = a·(x₁ - x₂)`, which has a unique solution when `x₁ - x₂` is
+/// invertible mod 26.
+pub fn recover_key(message: &str, cipher: &str) -> Option<(i32, i32)> {
+	let mut pairs = message
+		.bytes()
+		.zip(cipher.bytes())
+		.map(|(x, y)| ((x - b'A') as i64, (y - b'A') as i64));
+
+	let (x1, y1) = pairs.next()?;
+	let (x2, y2) = pairs.next()?;
+
+	let difference_inverse = math::inverse_mod(math::safe_mod(x1 - x2, 26), 26)? as i64;
+
+	let a = math::safe_mod((y1 - y2) * difference_inverse, 26) as i64;
+	let b = math::safe_mod(y1 - a * x1, 26) as i64;
+
+	Some((a as i32, b as i32))
+}
+
+#[cfg(test)]
+mod tests {
+	use validator::Validate;
+
+	use crate::challenge::affine::*;
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		assert_eq!("IHHWVCSWFRCP", encrypt("AFFINECIPHER", 5, 8));
+		assert_eq!(
+			Some("AFFINECIPHER".to_owned()),
+			decrypt("IHHWVCSWFRCP", 5, 8)
+		);
+		assert_eq!(None, decrypt("IHHWVCSWFRCP", 13, 8));
+	}
+
+	#[test]
+	fn it_checks_multipliers() {
+		assert_eq!(12, multipliers().len());
+		assert!(multipliers().into_iter().all(is_key));
+		assert!(!is_key(2));
+		assert!(!is_key(13));
+		assert!(is_key(-1));
+	}
+
+	#[test]
+	fn it_validates_submitted_multipliers() {
+		let submission = |a| AffineAttackSubmission {
+			a,
+			b: 0,
+		};
+
+		assert!(submission(5).validate().is_ok());
+		assert!(submission(13).validate().is_err());
+		assert!(submission(0).validate().is_err());
+	}
+
+	#[test]
+	fn it_recovers_keys_from_two_pairs() {
+		let cipher = encrypt("AFFINECIPHER", 5, 8);
+
+		assert_eq!(Some((5, 8)), recover_key("AFFINECIPHER", &cipher));
+		assert_eq!(None, recover_key("AC", &encrypt("AC", 5, 8)));
+		assert_eq!(None, recover_key("A", "I"));
+	}
+
+	#[test]
+	fn it_hints_at_empty_sizes() {
+		let mut rng = rand::rng();
+
+		assert_eq!(
+			3,
+			AffineEncryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			AffineDecryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+	}
+}
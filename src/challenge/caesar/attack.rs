@@ -0,0 +1,70 @@
+use rand::{
+	Rng,
+	RngExt,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::caesar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaesarAttackChallenge {
+	pub message: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaesarAttackPublic {
+	pub message: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CaesarAttackSubmission {
+	#[validate(range(min = 0, max = 25, message = "Key must be in the range [0, 25]."))]
+	pub key: i32,
+}
+
+impl CaesarAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let key = rng.random_range(8..=18);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		let cipher = caesar::encrypt(&message, key);
+
+		CaesarAttackChallenge {
+			message,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, key: i32) -> bool {
+		caesar::encrypt(&self.message, key) == self.cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			"Every letter is shifted by the same key, so a single pair of letters is enough."
+				.to_owned(),
+			"The key is (cipher letter - message letter) mod 26, counting A as 0.".to_owned(),
+			format!(
+				"The first letter of the message, {}, became {} in the cipher.",
+				&self.message[..1],
+				&self.cipher[..1]
+			),
+		]
+	}
+}
+
+impl From<CaesarAttackChallenge> for CaesarAttackPublic {
+	fn from(challenge: CaesarAttackChallenge) -> Self {
+		CaesarAttackPublic {
+			message: challenge.message,
+			cipher:  challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,83 @@
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::{caesar, english};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaesarCiphertextAttackChallenge {
+	pub key:     i32,
+	pub message: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaesarCiphertextAttackPublic {
+	pub cipher: String,
+}
+
+/// Either the recovered key or the recovered message.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct CaesarCiphertextAttackSubmission {
+	#[validate(range(min = 0, max = 25, message = "Key must be in the range [0, 25]."))]
+	pub key: Option<i32>,
+
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: Option<String>,
+}
+
+impl CaesarCiphertextAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let key = rng.random_range(1..=25);
+		let message = english::words(rng, message_size);
+		let cipher = caesar::encrypt(&message, key);
+
+		CaesarCiphertextAttackChallenge {
+			key,
+			message,
+			cipher,
+		}
+	}
+
+	/// Accepts a key that decrypts the cipher to the message, or the message
+	/// itself in any case.
+	pub fn verify(&self, submission: &CaesarCiphertextAttackSubmission) -> bool {
+		let key_matches = submission
+			.key
+			.is_some_and(|key| caesar::decrypt(&self.cipher, key) == self.message);
+
+		let message_matches = submission
+			.message
+			.as_ref()
+			.is_some_and(|message| message.to_uppercase() == self.message);
+
+		key_matches || message_matches
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let common = english::letter_counts(&self.cipher)
+			.iter()
+			.enumerate()
+			.max_by_key(|(_, count)| **count)
+			.map(|(letter, _)| (b'A' + letter as u8) as char)
+			.unwrap_or('A');
+
+		vec![
+			"There are only 26 keys: try them all and look for English, or line up the most common cipher letter with E."
+				.to_owned(),
+			format!("The most common letter in the cipher is {common}."),
+			format!(
+				"The message starts with {}.",
+				&self.message[..self.message.len().min(3)]
+			),
+		]
+	}
+}
+
+impl From<CaesarCiphertextAttackChallenge> for CaesarCiphertextAttackPublic {
+	fn from(challenge: CaesarCiphertextAttackChallenge) -> Self {
+		CaesarCiphertextAttackPublic {
+			cipher: challenge.cipher,
+		}
+	}
+}
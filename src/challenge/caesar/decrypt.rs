@@ -0,0 +1,73 @@
+use rand::{
+	Rng,
+	RngExt,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::caesar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaesarDecryptChallenge {
+	pub key:    i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaesarDecryptPublic {
+	pub key:    i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CaesarDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl CaesarDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, cipher_size: usize) -> Self {
+		let key = rng.random_range(8..=18);
+
+		let cipher = Alphabetic
+			.sample_string(rng, cipher_size.max(1))
+			.to_uppercase();
+
+		CaesarDecryptChallenge {
+			key,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		caesar::decrypt(&self.cipher, self.key) == message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.cipher[..1];
+
+		vec![
+			"Shift every letter of the cipher backward by the key, wrapping around from A to Z."
+				.to_owned(),
+			format!(
+				"Shifting back by {}, {} becomes A.",
+				self.key,
+				caesar::encrypt("A", self.key)
+			),
+			format!(
+				"The first letter, {first}, becomes {}.",
+				caesar::decrypt(first, self.key)
+			),
+		]
+	}
+}
+
+impl From<CaesarDecryptChallenge> for CaesarDecryptPublic {
+	fn from(challenge: CaesarDecryptChallenge) -> Self {
+		CaesarDecryptPublic {
+			key:    challenge.key,
+			cipher: challenge.cipher,
+		}
+	}
+}
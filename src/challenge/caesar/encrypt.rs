@@ -0,0 +1,73 @@
+use rand::{
+	Rng,
+	RngExt,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::caesar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaesarEncryptChallenge {
+	pub key:     i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaesarEncryptPublic {
+	pub key:     i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CaesarEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl CaesarEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let key = rng.random_range(8..=18);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		CaesarEncryptChallenge {
+			key,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		caesar::encrypt(&self.message, self.key) == cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.message[..1];
+
+		vec![
+			"Shift every letter of the message forward by the key, wrapping around from Z to A."
+				.to_owned(),
+			format!(
+				"Shifting by {}, A becomes {}.",
+				self.key,
+				caesar::encrypt("A", self.key)
+			),
+			format!(
+				"The first letter, {first}, becomes {}.",
+				caesar::encrypt(first, self.key)
+			),
+		]
+	}
+}
+
+impl From<CaesarEncryptChallenge> for CaesarEncryptPublic {
+	fn from(challenge: CaesarEncryptChallenge) -> Self {
+		CaesarEncryptPublic {
+			key:     challenge.key,
+			message: challenge.message,
+		}
+	}
+}
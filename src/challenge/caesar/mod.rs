@@ -0,0 +1,132 @@
+mod encrypt;
+pub use crate::challenge::caesar::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::caesar::decrypt::*;
+
+mod attack;
+pub use crate::challenge::caesar::attack::*;
+
+mod ciphertext_attack;
+pub use crate::challenge::caesar::ciphertext_attack::*;
+
+pub fn encrypt(message: &str, key: i32) -> String {
+	message
+		.chars()
+		.map(|char| {
+			let old_ascii_index = char as u8 - 65;
+			let new_ascii_index = (old_ascii_index + key as u8) % 26;
+
+			(new_ascii_index + 65) as char
+		})
+		.collect::<String>()
+}
+
+pub fn decrypt(cipher: &str, key: i32) -> String {
+	cipher
+		.chars()
+		.map(|char| {
+			let old_ascii_index = char as u8 - 65;
+
+			let new_ascii_index = if old_ascii_index >= key as u8 {
+				old_ascii_index - key as u8
+			} else {
+				old_ascii_index + 26 - key as u8
+			} % 26;
+
+			(new_ascii_index + 65) as char
+		})
+		.collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use crate::challenge::{caesar::*, english};
+
+	/// Whether `text` splits into English words, allowing the last one to be
+	/// cut short.
+	fn is_words(text: &str) -> bool {
+		text.is_empty()
+			|| english::WORDS
+				.iter()
+				.any(|word| text.strip_prefix(word).is_some_and(is_words) || word.starts_with(text))
+	}
+
+	fn ciphertext_attack() -> CaesarCiphertextAttackChallenge {
+		CaesarCiphertextAttackChallenge::generate(&mut StdRng::seed_from_u64(7), 60)
+	}
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		assert_eq!("KHOOR", encrypt("HELLO", 3));
+		assert_eq!("HELLO", decrypt("KHOOR", 3));
+	}
+
+	#[test]
+	fn it_hints_at_empty_sizes() {
+		let mut rng = StdRng::seed_from_u64(7);
+
+		assert_eq!(
+			3,
+			CaesarEncryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			CaesarDecryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			CaesarAttackChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+	}
+
+	#[test]
+	fn it_generates_english_ciphertext_attacks() {
+		let challenge = ciphertext_attack();
+
+		assert_eq!(60, challenge.message.len());
+		assert!((1..=25).contains(&challenge.key));
+		assert_eq!(challenge.message, decrypt(&challenge.cipher, challenge.key));
+		assert!(is_words(&challenge.message));
+	}
+
+	#[test]
+	fn it_verifies_ciphertext_attacks() {
+		let challenge = ciphertext_attack();
+
+		let key = |key| CaesarCiphertextAttackSubmission {
+			key: Some(key),
+			..Default::default()
+		};
+		let message = |message: String| CaesarCiphertextAttackSubmission {
+			message: Some(message),
+			..Default::default()
+		};
+
+		assert!(challenge.verify(&key(challenge.key)));
+		assert!(!challenge.verify(&key((challenge.key + 1) % 26)));
+		assert!(challenge.verify(&message(challenge.message.clone())));
+		assert!(challenge.verify(&message(challenge.message.to_lowercase())));
+		assert!(!challenge.verify(&message(challenge.cipher.clone())));
+		assert!(!challenge.verify(&Default::default()));
+	}
+
+	#[test]
+	fn it_hints_at_ciphertext_attacks_in_order() {
+		let challenge = ciphertext_attack();
+		let hints = challenge.hints();
+
+		assert_eq!(3, hints.len());
+		assert!(!hints[0].contains(&challenge.message[..3]));
+		assert!(hints[1].starts_with("The most common letter in the cipher is"));
+		assert!(hints[2].contains(&challenge.message[..3]));
+	}
+}
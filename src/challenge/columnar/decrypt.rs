@@ -0,0 +1,73 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::columnar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnarDecryptChallenge {
+	pub key:    String,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnarDecryptPublic {
+	pub key:    String,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ColumnarDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl ColumnarDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, cipher_size: usize) -> Self {
+		let key = columnar::random_key(rng, key_size);
+
+		let message = Alphabetic
+			.sample_string(rng, cipher_size)
+			.to_uppercase();
+
+		let cipher = columnar::encrypt(&message, &key);
+
+		ColumnarDecryptChallenge {
+			key,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		columnar::decrypt(&self.cipher, &self.key) == message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let width = self.key.len();
+		let message = columnar::decrypt(&self.cipher, &self.key);
+
+		vec![
+			"Cut the cipher into columns in the alphabetical order of the key's letters, then read the rows from left to right."
+				.to_owned(),
+			format!(
+				"The first {} columns hold {} letters and the rest {}.",
+				self.cipher.len() % width,
+				self.cipher.len() / width + 1,
+				self.cipher.len() / width
+			),
+			format!("The message starts with {}.", &message[..width.min(message.len())]),
+		]
+	}
+}
+
+impl From<ColumnarDecryptChallenge> for ColumnarDecryptPublic {
+	fn from(challenge: ColumnarDecryptChallenge) -> Self {
+		ColumnarDecryptPublic {
+			key:    challenge.key,
+			cipher: challenge.cipher,
+		}
+	}
+}
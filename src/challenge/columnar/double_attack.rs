@@ -0,0 +1,69 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::{columnar, english};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnarDoubleAttackChallenge {
+	pub first_key:  String,
+	pub second_key: String,
+	pub message:    String,
+	pub cipher:     String,
+}
+
+/// Only the cipher and the length shared by both keys are public.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnarDoubleAttackPublic {
+	pub key_size: usize,
+	pub cipher:   String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ColumnarDoubleAttackSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl ColumnarDoubleAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, message_size: usize) -> Self {
+		let first_key = columnar::random_key(rng, key_size);
+		let second_key = columnar::random_key(rng, key_size);
+
+		let message = english::words(rng, message_size);
+		let cipher = columnar::double_encrypt(&message, &first_key, &second_key);
+
+		ColumnarDoubleAttackChallenge {
+			first_key,
+			second_key,
+			message,
+			cipher,
+		}
+	}
+
+	/// Accepts the message in any case.
+	pub fn verify(&self, message: &str) -> bool {
+		message.to_uppercase() == self.message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			"Transposition keeps the letters, only moving them. With short keys, every pair of column orders can be tried, keeping the one that reads as English words."
+				.to_owned(),
+			format!(
+				"The second transposition read column {} first.",
+				columnar::column_order(&self.second_key)[0] + 1
+			),
+			format!("The first key is {}.", self.first_key),
+		]
+	}
+}
+
+impl From<ColumnarDoubleAttackChallenge> for ColumnarDoubleAttackPublic {
+	fn from(challenge: ColumnarDoubleAttackChallenge) -> Self {
+		ColumnarDoubleAttackPublic {
+			key_size: challenge.first_key.len(),
+			cipher:   challenge.cipher,
+		}
+	}
+}
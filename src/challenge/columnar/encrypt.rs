@@ -0,0 +1,75 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::columnar;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnarEncryptChallenge {
+	pub key:     String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnarEncryptPublic {
+	pub key:     String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ColumnarEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl ColumnarEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, message_size: usize) -> Self {
+		let key = columnar::random_key(rng, key_size);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size)
+			.to_uppercase();
+
+		ColumnarEncryptChallenge {
+			key,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		columnar::encrypt(&self.message, &self.key) == cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = columnar::column_order(&self.key)[0];
+		let column = self
+			.message
+			.chars()
+			.skip(first)
+			.step_by(self.key.len())
+			.collect::<String>();
+
+		vec![
+			"Write the message in rows as wide as the key, then read the columns from top to bottom in the alphabetical order of the key's letters."
+				.to_owned(),
+			format!(
+				"The first column read is column {}, under the letter {}.",
+				first + 1,
+				&self.key[first..=first]
+			),
+			format!("The cipher starts with {column}."),
+		]
+	}
+}
+
+impl From<ColumnarEncryptChallenge> for ColumnarEncryptPublic {
+	fn from(challenge: ColumnarEncryptChallenge) -> Self {
+		ColumnarEncryptPublic {
+			key:     challenge.key,
+			message: challenge.message,
+		}
+	}
+}
@@ -0,0 +1,111 @@
+mod encrypt;
+pub use crate::challenge::columnar::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::columnar::decrypt::*;
+
+mod double_attack;
+pub use crate::challenge::columnar::double_attack::*;
+
+use rand::{Rng, seq::SliceRandom};
+
+/// Reading order of the columns: by the alphabetical order of the key's
+/// letters, with repeated letters taken from left to right.
+pub fn column_order(key: &str) -> Vec<usize> {
+	let mut columns = (0..key.len()).collect::<Vec<_>>();
+	columns.sort_by_key(|column| key.as_bytes()[*column]);
+	columns
+}
+
+/// Writes the message in rows as wide as the key and reads the columns off in
+/// key order. The last row is left short rather than padded.
+pub fn encrypt(message: &str, key: &str) -> String {
+	let letters = message.as_bytes();
+	let width = key.len().max(1);
+
+	column_order(key)
+		.into_iter()
+		.flat_map(|column| letters.iter().skip(column).step_by(width))
+		.map(|letter| *letter as char)
+		.collect()
+}
+
+/// Cuts the cipher into columns, the first `len % width` of which are one
+/// letter longer, and reads the rows back off.
+pub fn decrypt(cipher: &str, key: &str) -> String {
+	let width = key.len().max(1);
+	let (rows, long_columns) = (cipher.len() / width, cipher.len() % width);
+
+	let mut letters = vec![0; cipher.len()];
+	let mut cipher = cipher.bytes();
+
+	for column in column_order(key) {
+		let height = rows + usize::from(column < long_columns);
+
+		for (row, letter) in cipher.by_ref().take(height).enumerate() {
+			letters[row * width + column] = letter;
+		}
+	}
+
+	String::from_utf8_lossy(&letters).into_owned()
+}
+
+/// Transposes twice, first with `first_key` and then with `second_key`.
+pub fn double_encrypt(message: &str, first_key: &str, second_key: &str) -> String {
+	encrypt(&encrypt(message, first_key), second_key)
+}
+
+pub fn double_decrypt(cipher: &str, first_key: &str, second_key: &str) -> String {
+	decrypt(&decrypt(cipher, second_key), first_key)
+}
+
+/// Keys are `size` distinct letters, so that the column order is unambiguous.
+pub fn random_key<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+	let mut letters = (b'A'..=b'Z').collect::<Vec<_>>();
+	letters.shuffle(rng);
+
+	letters
+		.into_iter()
+		.take(size.min(26))
+		.map(char::from)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::challenge::columnar::*;
+
+	#[test]
+	fn it_orders_columns() {
+		assert_eq!(vec![4, 2, 1, 3, 5, 0], column_order("ZEBRAS"));
+		assert_eq!(vec![1, 0, 2], column_order("BAB"));
+	}
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		let message = "WEAREDISCOVEREDFLEEATONCE";
+		let cipher = encrypt(message, "ZEBRAS");
+
+		assert_eq!("EVLNACDTESEAROFODEECWIREE", cipher);
+		assert_eq!(message, decrypt(&cipher, "ZEBRAS"));
+	}
+
+	#[test]
+	fn it_transposes_twice() {
+		let message = "WEAREDISCOVEREDFLEEATONCE";
+		let cipher = double_encrypt(message, "ZEBRAS", "STRIPE");
+
+		assert_eq!(encrypt(&encrypt(message, "ZEBRAS"), "STRIPE"), cipher);
+		assert_eq!(message, double_decrypt(&cipher, "ZEBRAS", "STRIPE"));
+	}
+
+	#[test]
+	fn it_draws_distinct_letters() {
+		let key = random_key(&mut rand::rng(), 7);
+		let mut letters = key.bytes().collect::<Vec<_>>();
+		letters.sort();
+		letters.dedup();
+
+		assert_eq!(7, letters.len());
+	}
+}
@@ -0,0 +1,124 @@
+use std::ops::RangeInclusive;
+
+use num_traits::One;
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+	challenge::{self, KeySize},
+	math::{
+		self,
+		big::{self, BigUint},
+	},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffieHellmanExchangeChallenge {
+	#[serde(with = "math::big")]
+	pub g:         BigUint,
+	#[serde(with = "math::big")]
+	pub n:         BigUint,
+	#[serde(with = "math::big")]
+	pub sk_server: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffieHellmanExchangePublic {
+	#[serde(with = "math::big")]
+	pub g:         BigUint,
+	#[serde(with = "math::big")]
+	pub n:         BigUint,
+	#[serde(with = "math::big")]
+	pub pk_server: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DiffieHellmanExchangeSubmission {
+	#[serde(with = "math::big")]
+	pub pk_user: BigUint,
+	#[serde(with = "math::big")]
+	pub k:       BigUint,
+}
+
+impl DiffieHellmanExchangeChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		match size.into() {
+			KeySize::Range(n_range) => {
+				DiffieHellmanExchangeChallenge::generate_in_range(rng, n_range)
+			},
+
+			KeySize::Bits(bits) => DiffieHellmanExchangeChallenge::generate_with_bits(rng, bits),
+		}
+	}
+
+	pub fn pk_server(&self) -> BigUint {
+		self.g.modpow(&self.sk_server, &self.n)
+	}
+
+	pub fn verify(&self, pk_user: &BigUint, k: &BigUint) -> bool {
+		k == &pk_user.modpow(&self.sk_server, &self.n)
+	}
+
+	fn generate_in_range<R: Rng + ?Sized>(
+		rng: &mut R,
+		n_range: RangeInclusive<usize>,
+	) -> Option<Self> {
+		let n = challenge::random_prime(rng, &n_range)?;
+		let g = math::primitive(n)?;
+
+		let sk_server_min = n / 2;
+		let sk_server_max = n - 1;
+		let sk_server = rng.random_range(sk_server_min..=sk_server_max);
+
+		let challenge = DiffieHellmanExchangeChallenge {
+			g:         g.into(),
+			n:         n.into(),
+			sk_server: sk_server.into(),
+		};
+
+		Some(challenge)
+	}
+
+	/// Finding a primitive root needs the factors of n - 1, which are out of
+	/// reach at these sizes, so g generates a large prime-order subgroup
+	/// instead.
+	fn generate_with_bits<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> Option<Self> {
+		let (n, q, g) = big::prime_order_group(rng, bits, big::subgroup_bits(bits))?;
+		let sk_server = big::random_range(rng, &BigUint::one(), &q);
+
+		let challenge = DiffieHellmanExchangeChallenge {
+			g,
+			n,
+			sk_server,
+		};
+
+		Some(challenge)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let pk_server = self.pk_server();
+
+		vec![
+			"Pick any secret sk_user and send pk_user = g^sk_user mod n.".to_owned(),
+			format!("The shared key is k = {pk_server}^sk_user mod {}.", self.n),
+			format!(
+				"For example, with sk_user = 2, pk_user = {} and k = {}.",
+				self.g.modpow(&BigUint::from(2u32), &self.n),
+				pk_server.modpow(&BigUint::from(2u32), &self.n)
+			),
+		]
+	}
+}
+
+impl From<DiffieHellmanExchangeChallenge> for DiffieHellmanExchangePublic {
+	fn from(challenge: DiffieHellmanExchangeChallenge) -> Self {
+		let pk_server = challenge.pk_server();
+
+		DiffieHellmanExchangePublic {
+			g: challenge.g,
+			n: challenge.n,
+			pk_server,
+		}
+	}
+}
@@ -0,0 +1,194 @@
+mod sign;
+pub use crate::challenge::dss::sign::*;
+
+mod verify;
+use std::ops::RangeInclusive;
+
+use num_traits::{One, Zero};
+use rand::{Rng, seq::IndexedRandom};
+
+pub use crate::challenge::dss::verify::*;
+use crate::{
+	challenge::{self, KeySize},
+	math::{
+		self,
+		big::{self, BigUint},
+	},
+};
+
+const H_FUNCTIONS: [&str; 3] = ["m mod q", "2m mod q", "3m mod q"];
+
+/// Primes drawn before giving up on a range without a usable group.
+const GROUP_ATTEMPTS: usize = 100;
+
+/// Values of k tried before giving up on signing a digest.
+const SIGNATURE_ATTEMPTS: usize = 100;
+
+#[derive(Debug, Clone)]
+pub struct DssGroup {
+	pub p: BigUint,
+	pub q: BigUint,
+	pub g: BigUint,
+}
+
+impl DssGroup {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		match size.into() {
+			KeySize::Range(p_range) => DssGroup::generate_in_range(rng, p_range),
+			KeySize::Bits(bits) => {
+				let (p, q, g) = big::prime_order_group(rng, bits, big::subgroup_bits(bits))?;
+
+				Some(DssGroup {
+					p,
+					q,
+					g,
+				})
+			},
+		}
+	}
+
+	/// Returns a random value in `[1, q - 1]`.
+	pub fn random_exponent<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUint {
+		big::random_range(rng, &BigUint::one(), &self.q)
+	}
+
+	/// Draws primes until the largest prime factor q of p - 1 exceeds √p. With
+	/// a smaller q, most of the subgroup's elements can be multiples of q, and
+	/// for q = 3 the smallest generator makes r = (g^k mod p) mod q zero for
+	/// every k, so no signature could ever be made.
+	fn generate_in_range<R: Rng + ?Sized>(
+		rng: &mut R,
+		p_range: RangeInclusive<usize>,
+	) -> Option<Self> {
+		for _ in 0..GROUP_ATTEMPTS {
+			let p = challenge::random_prime(rng, &p_range)?;
+
+			let Some(q) = math::prime_factors(p - 1).into_iter().last() else {
+				continue;
+			};
+
+			if q <= p / q {
+				continue;
+			}
+
+			let Some(g) = (2..p).find(|g| math::order(*g, p) == Some(q)) else {
+				continue;
+			};
+
+			let group = DssGroup {
+				p: p.into(),
+				q: q.into(),
+				g: g.into(),
+			};
+
+			return Some(group);
+		}
+
+		None
+	}
+
+	/// Signs `digest` with a fresh k, retrying while r or s comes out as zero,
+	/// since s has no inverse mod q then and the signature could not be
+	/// verified. Returns `None` if every attempt fails.
+	pub fn sign<R: Rng + ?Sized>(
+		&self,
+		rng: &mut R,
+		sk: &BigUint,
+		digest: &BigUint,
+	) -> Option<(BigUint, BigUint)> {
+		for _ in 0..SIGNATURE_ATTEMPTS {
+			let k = self.random_exponent(rng);
+			let r = self.g.modpow(&k, &self.p) % &self.q;
+
+			if r.is_zero() {
+				continue;
+			}
+
+			let s = signature(self, sk, digest, &k, &r)?;
+
+			if !s.is_zero() {
+				return Some((r, s));
+			}
+		}
+
+		None
+	}
+
+	fn random_message<R: Rng + ?Sized>(&self, rng: &mut R, h: &str) -> Option<BigUint> {
+		let m_min = &self.p / 2u32;
+
+		loop {
+			let m = big::random_range(rng, &m_min, &self.p);
+
+			if !h_digest(h, &m, &self.q)?.is_zero() {
+				return Some(m);
+			}
+		}
+	}
+}
+
+pub fn random_h<R: Rng + ?Sized>(rng: &mut R) -> Option<String> {
+	H_FUNCTIONS
+		.choose(rng)
+		.map(|value| value.to_string())
+}
+
+pub fn h_digest(h: &str, m: &BigUint, q: &BigUint) -> Option<BigUint> {
+	match h {
+		"m mod q" => Some(m % q),
+		"2m mod q" => Some(m * 2u32 % q),
+		"3m mod q" => Some(m * 3u32 % q),
+
+		_ => None,
+	}
+}
+
+/// Computes `s = k⁻¹(H(m) - sk·r) mod q`.
+pub fn signature(
+	group: &DssGroup,
+	sk: &BigUint,
+	digest: &BigUint,
+	k: &BigUint,
+	r: &BigUint,
+) -> Option<BigUint> {
+	let k_inv_q = big::inverse_mod(k, &group.q)?;
+	let difference = big::sub_mod(digest, &(sk * r), &group.q);
+
+	Some(difference * k_inv_q % &group.q)
+}
+
+fn verification_values(
+	group: &DssGroup,
+	pk: &BigUint,
+	digest: &BigUint,
+	r: &BigUint,
+	s: &BigUint,
+) -> Option<(BigUint, BigUint, BigUint)> {
+	let s_inv_q = big::inverse_mod(s, &group.q)?;
+
+	let u = digest * &s_inv_q % &group.q;
+	let v = big::sub_mod(&BigUint::zero(), r, &group.q) * &s_inv_q % &group.q;
+
+	let w = group.g.modpow(&u, &group.p) * pk.modpow(&v, &group.p) % &group.p % &group.q;
+
+	Some((u, v, w))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::challenge::{dss::*, seeded_rng};
+
+	#[test]
+	fn it_rejects_groups_with_a_small_subgroup() {
+		// 2593 - 1 = 2⁵·3⁴, so q would be 3
+		assert!(DssGroup::generate(&mut seeded_rng(1), 2593..=2593).is_none());
+		assert!(DssGroup::generate(&mut seeded_rng(1), 3889..=3889).is_none());
+		assert!(DssVerifyChallenge::generate(&mut seeded_rng(1), 2593..=2593).is_none());
+
+		let group = DssGroup::generate(&mut seeded_rng(1), 2579..=2579).unwrap();
+
+		assert_eq!(BigUint::from(2579u32), group.p);
+		assert!(&group.q * &group.q > group.p);
+		assert_eq!(BigUint::one(), group.g.modpow(&group.q, &group.p));
+	}
+}
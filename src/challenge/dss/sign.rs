@@ -0,0 +1,108 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+	challenge::{
+		KeySize,
+		dss::{self, DssGroup},
+	},
+	math::{self, big::BigUint},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DssSignChallenge {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub g: BigUint,
+	pub h: String,
+	#[serde(with = "math::big")]
+	pub m: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DssSignPublic {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub g: BigUint,
+	pub h: String,
+	#[serde(with = "math::big")]
+	pub m: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DssSignSubmission {
+	#[serde(with = "math::big")]
+	pub pk: BigUint,
+	#[serde(with = "math::big")]
+	pub r:  BigUint,
+	#[serde(with = "math::big")]
+	pub s:  BigUint,
+}
+
+impl DssSignChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		let group = DssGroup::generate(rng, size)?;
+		let h = dss::random_h(rng)?;
+		let m = group.random_message(rng, &h)?;
+
+		let challenge = DssSignChallenge {
+			p: group.p,
+			q: group.q,
+			g: group.g,
+			h,
+			m,
+		};
+
+		Some(challenge)
+	}
+
+	pub fn group(&self) -> DssGroup {
+		DssGroup {
+			p: self.p.clone(),
+			q: self.q.clone(),
+			g: self.g.clone(),
+		}
+	}
+
+	pub fn verify(&self, pk: &BigUint, r: &BigUint, s: &BigUint) -> bool {
+		if pk >= &self.p || r >= &self.q || s >= &self.q {
+			return false;
+		}
+
+		let Some(digest) = dss::h_digest(&self.h, &self.m, &self.q) else {
+			return false;
+		};
+
+		dss::verification_values(&self.group(), pk, &digest, r, s).is_some_and(|(_, _, w)| &w == r)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let digest = dss::h_digest(&self.h, &self.m, &self.q).unwrap_or_default();
+
+		vec![
+			"Pick a secret key sk in [1, q-1] and publish pk = g^sk mod p.".to_owned(),
+			format!("The digest is H(m) = {} = {digest}.", self.h),
+			"Pick k in [1, q-1], then r = (g^k mod p) mod q.".to_owned(),
+			"s = k⁻¹(H(m) - sk·r) mod q. If r or s comes out as 0, pick a different k.".to_owned(),
+		]
+	}
+}
+
+impl From<DssSignChallenge> for DssSignPublic {
+	fn from(challenge: DssSignChallenge) -> Self {
+		DssSignPublic {
+			p: challenge.p,
+			q: challenge.q,
+			g: challenge.g,
+			h: challenge.h,
+			m: challenge.m,
+		}
+	}
+}
@@ -0,0 +1,143 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+	challenge::{
+		KeySize,
+		dss::{self, DssGroup},
+	},
+	math::{
+		self,
+		big::{self, BigUint},
+	},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DssVerifyChallenge {
+	#[serde(with = "math::big")]
+	pub p:  BigUint,
+	#[serde(with = "math::big")]
+	pub q:  BigUint,
+	#[serde(with = "math::big")]
+	pub g:  BigUint,
+	pub h:  String,
+	#[serde(with = "math::big")]
+	pub pk: BigUint,
+	#[serde(with = "math::big")]
+	pub m:  BigUint,
+	#[serde(with = "math::big")]
+	pub r:  BigUint,
+	#[serde(with = "math::big")]
+	pub s:  BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DssVerifyPublic {
+	#[serde(with = "math::big")]
+	pub p:  BigUint,
+	#[serde(with = "math::big")]
+	pub q:  BigUint,
+	#[serde(with = "math::big")]
+	pub g:  BigUint,
+	pub h:  String,
+	#[serde(with = "math::big")]
+	pub pk: BigUint,
+	#[serde(with = "math::big")]
+	pub m:  BigUint,
+	#[serde(with = "math::big")]
+	pub r:  BigUint,
+	#[serde(with = "math::big")]
+	pub s:  BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DssVerifySubmission {
+	#[serde(with = "math::big")]
+	pub u: BigUint,
+	#[serde(with = "math::big")]
+	pub v: BigUint,
+	#[serde(with = "math::big")]
+	pub w: BigUint,
+}
+
+impl DssVerifyChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		let group = DssGroup::generate(rng, size)?;
+		let h = dss::random_h(rng)?;
+
+		let sk = group.random_exponent(rng);
+		let pk = group.g.modpow(&sk, &group.p);
+
+		let m = group.random_message(rng, &h)?;
+		let digest = dss::h_digest(&h, &m, &group.q)?;
+
+		let (r, s) = group.sign(rng, &sk, &digest)?;
+
+		let challenge = DssVerifyChallenge {
+			p: group.p,
+			q: group.q,
+			g: group.g,
+			h,
+			pk,
+			m,
+			r,
+			s,
+		};
+
+		Some(challenge)
+	}
+
+	pub fn group(&self) -> DssGroup {
+		DssGroup {
+			p: self.p.clone(),
+			q: self.q.clone(),
+			g: self.g.clone(),
+		}
+	}
+
+	pub fn verify(&self, u: &BigUint, v: &BigUint, w: &BigUint) -> bool {
+		if self.pk >= self.p || self.r >= self.q || self.s >= self.q {
+			return false;
+		}
+
+		let Some(digest) = dss::h_digest(&self.h, &self.m, &self.q) else {
+			return false;
+		};
+
+		let Some((correct_u, correct_v, correct_w)) =
+			dss::verification_values(&self.group(), &self.pk, &digest, &self.r, &self.s)
+		else {
+			return false;
+		};
+
+		u == &correct_u && v == &correct_v && w == &correct_w && correct_w == self.r
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let digest = dss::h_digest(&self.h, &self.m, &self.q).unwrap_or_default();
+		let s_inv_q = big::inverse_mod(&self.s, &self.q).unwrap_or_default();
+
+		vec![
+			format!("The digest is H(m) = {} = {digest}.", self.h),
+			format!("s⁻¹ mod q is {s_inv_q}."),
+			"u = H(m)·s⁻¹ mod q and v = -r·s⁻¹ mod q.".to_owned(),
+			"w = (g^u · pk^v mod p) mod q, which equals r for a valid signature.".to_owned(),
+		]
+	}
+}
+
+impl From<DssVerifyChallenge> for DssVerifyPublic {
+	fn from(challenge: DssVerifyChallenge) -> Self {
+		DssVerifyPublic {
+			p:  challenge.p,
+			q:  challenge.q,
+			g:  challenge.g,
+			h:  challenge.h,
+			pk: challenge.pk,
+			m:  challenge.m,
+			r:  challenge.r,
+			s:  challenge.s,
+		}
+	}
+}
@@ -0,0 +1,191 @@
+use std::sync::LazyLock;
+
+use rand::{Rng, RngExt};
+
+/// Relative frequency of each letter A to Z in English text.
+pub const FREQUENCIES: [f64; 26] = [
+	0.0817, 0.0149, 0.0278, 0.0425, 0.1270, 0.0223, 0.0202, 0.0609, 0.0697, 0.0015, 0.0077, 0.0403,
+	0.0241, 0.0675, 0.0751, 0.0193, 0.0010, 0.0599, 0.0633, 0.0906, 0.0276, 0.0098, 0.0236, 0.0015,
+	0.0197, 0.0007,
+];
+
+/// Index of coincidence of English text. Uniformly random letters sit near
+/// 1/26, or about 0.038.
+pub const INDEX_OF_COINCIDENCE: f64 = 0.0667;
+
+/// Common English words that plaintexts are drawn from, so that frequency
+/// analysis works on them the way it would on real text.
+pub const WORDS: [&str; 213] = [
+	"THE", "OF", "AND", "TO", "IN", "IS", "YOU", "THAT", "IT", "HE", "WAS", "FOR", "ON", "ARE",
+	"AS", "WITH", "HIS", "THEY", "AT", "BE", "THIS", "HAVE", "FROM", "OR", "ONE", "HAD", "BY",
+	"WORD", "BUT", "NOT", "WHAT", "ALL", "WERE", "WE", "WHEN", "YOUR", "CAN", "SAID", "THERE",
+	"USE", "AN", "EACH", "WHICH", "SHE", "DO", "HOW", "THEIR", "IF", "WILL", "UP", "OTHER",
+	"ABOUT", "OUT", "MANY", "THEN", "THEM", "THESE", "SO", "SOME", "HER", "WOULD", "MAKE", "LIKE",
+	"HIM", "INTO", "TIME", "HAS", "LOOK", "TWO", "MORE", "WRITE", "GO", "SEE", "NUMBER", "NO",
+	"WAY", "COULD", "PEOPLE", "MY", "THAN", "FIRST", "WATER", "BEEN", "CALL", "WHO", "OIL", "ITS",
+	"NOW", "FIND", "LONG", "DOWN", "DAY", "DID", "GET", "COME", "MADE", "MAY", "PART", "OVER",
+	"NEW", "SOUND", "TAKE", "ONLY", "LITTLE", "WORK", "KNOW", "PLACE", "YEAR", "LIVE", "ME",
+	"BACK", "GIVE", "MOST", "VERY", "AFTER", "THING", "OUR", "JUST", "NAME", "GOOD", "SENTENCE",
+	"MAN", "THINK", "SAY", "GREAT", "WHERE", "HELP", "THROUGH", "MUCH", "BEFORE", "LINE", "RIGHT",
+	"TOO", "MEAN", "OLD", "ANY", "SAME", "TELL", "BOY", "FOLLOW", "CAME", "WANT", "SHOW", "ALSO",
+	"AROUND", "FORM", "THREE", "SMALL", "SET", "PUT", "END", "DOES", "ANOTHER", "WELL", "LARGE",
+	"MUST", "BIG", "EVEN", "SUCH", "BECAUSE", "TURN", "HERE", "WHY", "ASK", "WENT", "MEN", "READ",
+	"NEED", "LAND", "DIFFER", "HOME", "US", "MOVE", "TRY", "KIND", "HAND", "PICTURE", "AGAIN",
+	"CHANGE", "OFF", "PLAY", "SPELL", "AIR", "AWAY", "ANIMAL", "HOUSE", "POINT", "PAGE", "LETTER",
+	"MOTHER", "ANSWER", "FOUND", "STUDY", "STILL", "LEARN", "SHOULD", "AMERICA", "WORLD", "HIGH",
+	"EVERY", "NEAR", "ADD", "FOOD", "BETWEEN", "OWN", "BELOW", "COUNTRY", "PLANT", "LAST", "LIGHT",
+	"THOUGHT", "HEAD", "UNDER",
+];
+
+/// Passages of ordinary English prose, one per paragraph of the bundled
+/// corpus.
+pub static PASSAGES: LazyLock<Vec<&str>> = LazyLock::new(|| {
+	include_str!("passages.txt")
+		.split("\n\n")
+		.map(str::trim)
+		.filter(|passage| !passage.is_empty())
+		.collect()
+});
+
+/// Returns `size` uppercase letters of random English words run together,
+/// with the last word cut short if it does not fit.
+pub fn words<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+	let mut text = String::with_capacity(size + 16);
+
+	while text.len() < size {
+		text.push_str(WORDS[rng.random_range(0..WORDS.len())]);
+	}
+
+	text.truncate(size);
+	text
+}
+
+/// Returns uppercase English prose with at least `size` letters, keeping its
+/// spaces and punctuation. Starts at a random passage, runs on into the
+/// following ones as needed and stops at the end of the word holding the
+/// `size`th letter.
+pub fn passage<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+	let start = rng.random_range(0..PASSAGES.len());
+	let mut text = String::new();
+	let mut letters = 0;
+
+	for passage in PASSAGES.iter().cycle().skip(start) {
+		for word in passage.split_whitespace() {
+			if letters >= size {
+				return text;
+			}
+
+			if !text.is_empty() {
+				text.push(' ');
+			}
+
+			text.push_str(&word.to_uppercase());
+			letters += word
+				.bytes()
+				.filter(u8::is_ascii_alphabetic)
+				.count();
+		}
+	}
+
+	text
+}
+
+/// Counts each uppercase letter of `text`, ignoring everything else.
+pub fn letter_counts(text: &str) -> [usize; 26] {
+	let mut counts = [0; 26];
+
+	for byte in text.bytes().filter(u8::is_ascii_uppercase) {
+		counts[(byte - b'A') as usize] += 1;
+	}
+
+	counts
+}
+
+/// Probability that two letters drawn from `text` without replacement are
+/// the same.
+pub fn index_of_coincidence(text: &str) -> f64 {
+	let counts = letter_counts(text);
+	let total = counts.iter().sum::<usize>();
+
+	if total < 2 {
+		return 0.0;
+	}
+
+	let matches = counts
+		.iter()
+		.map(|count| count * count.saturating_sub(1))
+		.sum::<usize>();
+
+	matches as f64 / (total * (total - 1)) as f64
+}
+
+/// Chi-squared distance between the letter counts of `text` and English.
+/// Lower values look more like English.
+pub fn chi_squared(text: &str) -> f64 {
+	chi_squared_counts(&letter_counts(text))
+}
+
+/// Chi-squared distance between letter counts, as from [`letter_counts`],
+/// and English.
+pub fn chi_squared_counts(counts: &[usize; 26]) -> f64 {
+	let total = counts.iter().sum::<usize>() as f64;
+
+	counts
+		.iter()
+		.zip(FREQUENCIES)
+		.map(|(count, frequency)| {
+			let expected = total * frequency;
+			(*count as f64 - expected).powi(2) / expected
+		})
+		.sum()
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use crate::challenge::english::*;
+
+	#[test]
+	fn it_generates_english_letters() {
+		let text = words(&mut StdRng::seed_from_u64(1), 1_000);
+
+		assert_eq!(1_000, text.len());
+		assert!(text.bytes().all(|byte| byte.is_ascii_uppercase()));
+		assert!(index_of_coincidence(&text) > 0.06);
+	}
+
+	#[test]
+	fn it_takes_passages_of_english_prose() {
+		let mut rng = StdRng::seed_from_u64(3);
+
+		for size in [1, 100, 5_000] {
+			let text = passage(&mut rng, size);
+			let letters = letter_counts(&text).iter().sum::<usize>();
+
+			assert!(letters >= size);
+			assert!(!text.ends_with(' '));
+			assert!(!text.bytes().any(|byte| byte.is_ascii_lowercase()));
+		}
+
+		assert!(PASSAGES.len() > 10);
+	}
+
+	#[test]
+	fn it_measures_coincidence() {
+		assert_eq!(1.0, index_of_coincidence("AAAA"));
+		assert_eq!(0.0, index_of_coincidence("ABCD"));
+		assert_eq!(0.0, index_of_coincidence("A"));
+	}
+
+	#[test]
+	fn it_prefers_english_frequencies() {
+		let english = words(&mut StdRng::seed_from_u64(2), 200);
+		let shifted = english
+			.bytes()
+			.map(|byte| ((byte - b'A' + 13) % 26 + b'A') as char)
+			.collect::<String>();
+
+		assert!(chi_squared(&english) < chi_squared(&shifted));
+	}
+}
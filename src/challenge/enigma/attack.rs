@@ -0,0 +1,102 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::{
+	english,
+	enigma::{self, Settings},
+};
+
+/// Shortest crib handed out. An empty crib would accept any positions, and
+/// with four letters few positions besides the real ones match by chance.
+pub const MIN_CRIB_SIZE: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnigmaAttackChallenge {
+	pub rotors:    String,
+	pub rings:     String,
+	pub positions: String,
+	pub plugboard: String,
+	pub crib:      String,
+	pub cipher:    String,
+}
+
+/// Everything but the start positions, with a crib known to open the
+/// message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnigmaAttackPublic {
+	pub rotors:    String,
+	pub rings:     String,
+	pub plugboard: String,
+	pub crib:      String,
+	pub cipher:    String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct EnigmaAttackSubmission {
+	#[validate(length(equal = 3, message = "Positions must be three letters."))]
+	pub positions: String,
+}
+
+impl EnigmaAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		plugboard_pairs: usize,
+		crib_size: usize,
+		message_size: usize,
+	) -> Self {
+		let rotors = enigma::random_rotors(rng);
+		let rings = enigma::random_letters(rng, 3);
+		let positions = enigma::random_letters(rng, 3);
+		let plugboard = enigma::random_plugboard(rng, plugboard_pairs);
+
+		let crib_size = crib_size.max(MIN_CRIB_SIZE);
+		let message = english::words(rng, message_size.max(crib_size));
+		let crib = message[..crib_size].to_owned();
+
+		let cipher = Settings::parse(&rotors, &rings, &positions, &plugboard)
+			.map(|settings| enigma::encrypt(&message, &settings))
+			.unwrap_or_default();
+
+		EnigmaAttackChallenge {
+			rotors,
+			rings,
+			positions,
+			plugboard,
+			crib,
+			cipher,
+		}
+	}
+
+	/// Accepts any start positions, in either case, under which the crib
+	/// encrypts to the start of the cipher.
+	pub fn verify(&self, positions: &str) -> bool {
+		Settings::parse(&self.rotors, &self.rings, positions, &self.plugboard).is_some_and(
+			|settings| {
+				self.cipher
+					.starts_with(&enigma::encrypt(&self.crib, &settings))
+			},
+		)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			"With the rotors, rings and plugboard known there are only 26³ = 17,576 start positions. Try each and keep the one that turns the crib into the start of the cipher."
+				.to_owned(),
+			format!("The left rotor starts at {}.", &self.positions[..1]),
+			format!("The middle rotor starts at {}.", &self.positions[1..2]),
+		]
+	}
+}
+
+impl From<EnigmaAttackChallenge> for EnigmaAttackPublic {
+	fn from(challenge: EnigmaAttackChallenge) -> Self {
+		EnigmaAttackPublic {
+			rotors:    challenge.rotors,
+			rings:     challenge.rings,
+			plugboard: challenge.plugboard,
+			crib:      challenge.crib,
+			cipher:    challenge.cipher,
+		}
+	}
+}
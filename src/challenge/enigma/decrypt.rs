@@ -0,0 +1,100 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::enigma::{self, Settings};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnigmaDecryptChallenge {
+	pub rotors:    String,
+	pub rings:     String,
+	pub positions: String,
+	pub plugboard: String,
+	pub cipher:    String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnigmaDecryptPublic {
+	pub rotors:    String,
+	pub rings:     String,
+	pub positions: String,
+	pub plugboard: String,
+	pub cipher:    String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct EnigmaDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl EnigmaDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		plugboard_pairs: usize,
+		cipher_size: usize,
+	) -> Self {
+		let rotors = enigma::random_rotors(rng);
+		let rings = enigma::random_letters(rng, 3);
+		let positions = enigma::random_letters(rng, 3);
+		let plugboard = enigma::random_plugboard(rng, plugboard_pairs);
+
+		let message = Alphabetic
+			.sample_string(rng, cipher_size.max(1))
+			.to_uppercase();
+
+		let cipher = Settings::parse(&rotors, &rings, &positions, &plugboard)
+			.map(|settings| enigma::encrypt(&message, &settings))
+			.unwrap_or_default();
+
+		EnigmaDecryptChallenge {
+			rotors,
+			rings,
+			positions,
+			plugboard,
+			cipher,
+		}
+	}
+
+	pub fn settings(&self) -> Option<Settings> {
+		Settings::parse(&self.rotors, &self.rings, &self.positions, &self.plugboard)
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		self.settings()
+			.is_some_and(|settings| enigma::decrypt(&self.cipher, &settings) == message)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let message = self
+			.settings()
+			.map(|settings| enigma::decrypt(&self.cipher[..1], &settings))
+			.unwrap_or_default();
+
+		vec![
+			"The machine is its own inverse: set it up with the same key and type the cipher in to read the message out."
+				.to_owned(),
+			"The reflector means no letter ever becomes itself, so each message letter differs from the cipher letter above it."
+				.to_owned(),
+			format!(
+				"The first letter, {}, becomes {message}.",
+				&self.cipher[..1]
+			),
+		]
+	}
+}
+
+impl From<EnigmaDecryptChallenge> for EnigmaDecryptPublic {
+	fn from(challenge: EnigmaDecryptChallenge) -> Self {
+		EnigmaDecryptPublic {
+			rotors:    challenge.rotors,
+			rings:     challenge.rings,
+			positions: challenge.positions,
+			plugboard: challenge.plugboard,
+			cipher:    challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,106 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::enigma::{self, Settings};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnigmaEncryptChallenge {
+	pub rotors:    String,
+	pub rings:     String,
+	pub positions: String,
+	pub plugboard: String,
+	pub message:   String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnigmaEncryptPublic {
+	pub rotors:    String,
+	pub rings:     String,
+	pub positions: String,
+	pub plugboard: String,
+	pub message:   String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct EnigmaEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl EnigmaEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		plugboard_pairs: usize,
+		message_size: usize,
+	) -> Self {
+		let rotors = enigma::random_rotors(rng);
+		let rings = enigma::random_letters(rng, 3);
+		let positions = enigma::random_letters(rng, 3);
+		let plugboard = enigma::random_plugboard(rng, plugboard_pairs);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		EnigmaEncryptChallenge {
+			rotors,
+			rings,
+			positions,
+			plugboard,
+			message,
+		}
+	}
+
+	pub fn settings(&self) -> Option<Settings> {
+		Settings::parse(&self.rotors, &self.rings, &self.positions, &self.plugboard)
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		self.settings()
+			.is_some_and(|settings| enigma::encrypt(&self.message, &settings) == cipher)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let settings = self.settings();
+
+		let stepped = settings
+			.as_ref()
+			.map(|settings| {
+				let mut positions = settings.positions;
+				enigma::step(&mut positions, &settings.rotors);
+				enigma::letter_string(&positions)
+			})
+			.unwrap_or_default();
+
+		let cipher = settings
+			.as_ref()
+			.map(|settings| enigma::encrypt(&self.message[..1], settings))
+			.unwrap_or_default();
+
+		vec![
+			"Step the rotors before each letter, then send it through the plugboard, the rotors from right to left, the reflector, the rotors from left to right and the plugboard again."
+				.to_owned(),
+			format!("After the first key press the rotors show {stepped}."),
+			format!(
+				"The first letter, {}, becomes {cipher}.",
+				&self.message[..1]
+			),
+		]
+	}
+}
+
+impl From<EnigmaEncryptChallenge> for EnigmaEncryptPublic {
+	fn from(challenge: EnigmaEncryptChallenge) -> Self {
+		EnigmaEncryptPublic {
+			rotors:    challenge.rotors,
+			rings:     challenge.rings,
+			positions: challenge.positions,
+			plugboard: challenge.plugboard,
+			message:   challenge.message,
+		}
+	}
+}
@@ -0,0 +1,363 @@
+mod encrypt;
+pub use crate::challenge::enigma::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::enigma::decrypt::*;
+
+mod attack;
+pub use crate::challenge::enigma::attack::*;
+
+use rand::{Rng, RngExt, seq::SliceRandom};
+
+/// One of the Wehrmacht rotors, with its wiring from A to Z and the letter
+/// showing in the window when it carries the rotor on its left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotor {
+	pub name:   &'static str,
+	pub wiring: &'static [u8; 26],
+	pub notch:  u8,
+}
+
+pub const ROTORS: [Rotor; 5] = [
+	Rotor {
+		name:   "I",
+		wiring: b"EKMFLGDQVZNTOWYHXUSPAIBRCJ",
+		notch:  b'Q',
+	},
+	Rotor {
+		name:   "II",
+		wiring: b"AJDKSIRUXBLHWTMCQGZNPYFVOE",
+		notch:  b'E',
+	},
+	Rotor {
+		name:   "III",
+		wiring: b"BDFHJLCPRTXVZNYEIWGAKMUSQO",
+		notch:  b'V',
+	},
+	Rotor {
+		name:   "IV",
+		wiring: b"ESOVPZJAYQUIRHXLNFTGKDCMWB",
+		notch:  b'J',
+	},
+	Rotor {
+		name:   "V",
+		wiring: b"VZBRGITYUPSDNHLXAWMJQOFECK",
+		notch:  b'Z',
+	},
+];
+
+/// The UKW-B reflector used by most of the Wehrmacht's three-rotor machines.
+pub const REFLECTOR: &[u8; 26] = b"YRUHQSLDPXNGOKMIEBFZCWVJAT";
+
+/// Cables on the plugboard of a machine set up the way the army keyed them.
+pub const PLUGBOARD_PAIRS: usize = 10;
+
+/// A machine's key, with the rotors, ring settings and start positions from
+/// left to right, all as indices from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+	pub rotors:    [usize; 3],
+	pub rings:     [u8; 3],
+	pub positions: [u8; 3],
+	pub plugboard: [u8; 26],
+}
+
+impl Settings {
+	/// Reads settings the way a key sheet writes them: rotors as Roman
+	/// numerals such as `II IV V`, rings and positions as three letters such
+	/// as `BUL`, and the plugboard as pairs such as `AV BS CG`. Returns `None`
+	/// for a repeated rotor or a letter plugged twice.
+	pub fn parse(rotors: &str, rings: &str, positions: &str, plugboard: &str) -> Option<Self> {
+		let rotors = rotors
+			.split_whitespace()
+			.map(|name| ROTORS.iter().position(|rotor| rotor.name == name))
+			.collect::<Option<Vec<_>>>()?;
+
+		let rotors: [usize; 3] = rotors.try_into().ok()?;
+
+		if rotors[0] == rotors[1] || rotors[1] == rotors[2] || rotors[0] == rotors[2] {
+			return None;
+		}
+
+		let mut board = std::array::from_fn(|letter| letter as u8);
+
+		for pair in plugboard.split_whitespace() {
+			let &[first, second] = letters(pair)?.as_slice() else {
+				return None;
+			};
+
+			if first == second || board[first as usize] != first || board[second as usize] != second
+			{
+				return None;
+			}
+
+			board[first as usize] = second;
+			board[second as usize] = first;
+		}
+
+		let settings = Settings {
+			rotors,
+			rings: letters(rings)?.try_into().ok()?,
+			positions: letters(positions)?.try_into().ok()?,
+			plugboard: board,
+		};
+
+		Some(settings)
+	}
+}
+
+/// Encrypts each letter after stepping the rotors, passing other characters
+/// through without stepping. The machine is its own inverse, so the same
+/// settings also decrypt.
+pub fn encrypt(message: &str, settings: &Settings) -> String {
+	let mut positions = settings.positions;
+
+	message
+		.bytes()
+		.map(|byte| {
+			if !byte.is_ascii_uppercase() {
+				return byte as char;
+			}
+
+			step(&mut positions, &settings.rotors);
+			(press(byte - b'A', &positions, settings) + b'A') as char
+		})
+		.collect()
+}
+
+pub fn decrypt(cipher: &str, settings: &Settings) -> String {
+	encrypt(cipher, settings)
+}
+
+/// Moves the rotors as a key is pressed. The right rotor always steps, and a
+/// rotor at its notch steps itself and its left neighbour, which makes the
+/// middle rotor step twice in a row.
+pub fn step(positions: &mut [u8; 3], rotors: &[usize; 3]) {
+	let middle_at_notch = positions[1] == ROTORS[rotors[1]].notch - b'A';
+	let right_at_notch = positions[2] == ROTORS[rotors[2]].notch - b'A';
+
+	if middle_at_notch {
+		positions[0] = (positions[0] + 1) % 26;
+	}
+
+	if middle_at_notch || right_at_notch {
+		positions[1] = (positions[1] + 1) % 26;
+	}
+
+	positions[2] = (positions[2] + 1) % 26;
+}
+
+/// Names the rotors with their Roman numerals, left to right.
+pub fn rotor_names(rotors: &[usize; 3]) -> String {
+	rotors
+		.iter()
+		.map(|rotor| ROTORS[*rotor].name)
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Writes indices from 0 back as letters.
+pub fn letter_string(letters: &[u8]) -> String {
+	letters
+		.iter()
+		.map(|letter| (letter + b'A') as char)
+		.collect()
+}
+
+pub fn random_rotors<R: Rng + ?Sized>(rng: &mut R) -> String {
+	let mut rotors = [0, 1, 2, 3, 4];
+	rotors.shuffle(rng);
+
+	rotor_names(&[rotors[0], rotors[1], rotors[2]])
+}
+
+/// Draws each letter independently, as ring settings and start positions
+/// such as `AAA` may repeat letters.
+pub fn random_letters<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+	let letters = (0..size)
+		.map(|_| rng.random_range(0..26))
+		.collect::<Vec<u8>>();
+
+	letter_string(&letters)
+}
+
+/// Plugs `pairs` cables between distinct letters, written as pairs such as
+/// `AV BS CG`.
+pub fn random_plugboard<R: Rng + ?Sized>(rng: &mut R, pairs: usize) -> String {
+	let mut letters = (0..26).collect::<Vec<u8>>();
+	letters.shuffle(rng);
+
+	letters[..pairs.min(13) * 2]
+		.chunks(2)
+		.map(letter_string)
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Sends one letter through the plugboard, the rotors from right to left, the
+/// reflector and back.
+fn press(letter: u8, positions: &[u8; 3], settings: &Settings) -> u8 {
+	let mut letter = settings.plugboard[letter as usize];
+
+	for slot in (0..3).rev() {
+		letter = through(letter, slot, positions, settings, false);
+	}
+
+	letter = REFLECTOR[letter as usize] - b'A';
+
+	for slot in 0..3 {
+		letter = through(letter, slot, positions, settings, true);
+	}
+
+	settings.plugboard[letter as usize]
+}
+
+/// Passes a letter through the rotor in `slot`, offset by how far its wiring
+/// has turned relative to its ring.
+fn through(letter: u8, slot: usize, positions: &[u8; 3], settings: &Settings, back: bool) -> u8 {
+	let wiring = ROTORS[settings.rotors[slot]].wiring;
+	let shift = (positions[slot] + 26 - settings.rings[slot]) % 26;
+	let contact = (letter + shift) % 26;
+
+	let wired = match back {
+		false => wiring[contact as usize] - b'A',
+		true => wiring
+			.iter()
+			.position(|wired| *wired - b'A' == contact)
+			.unwrap_or_default() as u8,
+	};
+
+	(wired + 26 - shift) % 26
+}
+
+/// Turns uppercase or lowercase letters into indices from 0, returning `None`
+/// for anything else.
+fn letters(text: &str) -> Option<Vec<u8>> {
+	text.bytes()
+		.map(|byte| {
+			byte.is_ascii_alphabetic()
+				.then(|| byte.to_ascii_uppercase() - b'A')
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+
+	use crate::challenge::{enigma::*, seeded_rng};
+
+	#[test]
+	fn it_encrypts_with_the_default_key() {
+		let settings = Settings::parse("I II III", "AAA", "AAA", "").unwrap();
+
+		assert_eq!("BDZGO", encrypt("AAAAA", &settings));
+		assert_eq!("AAAAA", decrypt("BDZGO", &settings));
+	}
+
+	#[test]
+	fn it_double_steps_the_middle_rotor() {
+		let rotors = [0, 1, 2];
+		let mut positions = [0, 3, 20];
+		let mut seen = Vec::new();
+
+		for _ in 0..3 {
+			step(&mut positions, &rotors);
+			seen.push(letter_string(&positions));
+		}
+
+		assert_eq!(vec!["ADV", "AEW", "BFX"], seen);
+	}
+
+	#[test]
+	fn it_offsets_the_wiring_by_the_ring_settings() {
+		let settings = Settings::parse("I II III", "BBB", "AAA", "").unwrap();
+
+		assert_eq!("EWTYX", encrypt("AAAAA", &settings));
+	}
+
+	#[test]
+	fn it_round_trips_through_the_plugboard() {
+		let settings =
+			Settings::parse("II IV V", "BUL", "BLA", "AV BS CG DL FU HZ IN KM OW RX").unwrap();
+		let cipher = encrypt("ATTACK AT DAWN", &settings);
+
+		assert_eq!(14, cipher.len());
+		assert_eq!(Some(6), cipher.find(' '));
+		assert_eq!("ATTACK AT DAWN", decrypt(&cipher, &settings));
+	}
+
+	#[test]
+	fn it_decrypts_a_wartime_message() {
+		// the first part of a message sent during Operation Barbarossa in 1941,
+		// whose message key KCH was sent enciphered at WXC
+		let indicator =
+			Settings::parse("II IV V", "BUL", "WXC", "AV BS CG DL FU HZ IN KM OW RX").unwrap();
+		let settings =
+			Settings::parse("II IV V", "BUL", "BLA", "AV BS CG DL FU HZ IN KM OW RX").unwrap();
+
+		assert_eq!("BLA", decrypt("KCH", &indicator));
+		assert_eq!(
+			"AUFKLXABTEILUNGXVONXKURTINOWAXKURTINOWAXNORDWESTLXSEBEZXSEBEZXUAFFLIEGERSTRASZERIQTUNGXDUBROWKIXDUBROWKIXOPOTSCHKAXOPOTSCHKAXUMXEINSAQTDREINULLXUHRANGETRETENXANGRIFFXINFXRGTX",
+			decrypt(
+				"EDPUDNRGYSZRCXNUYTPOMRMBOFKTBZREZKMLXLVEFGUEYSIOZVEQMIKUBPMMYLKLTTDEISMDICAGYKUACTCDOMOHWXMUUIAUBSTSLRNBZSZWNRFXWFYSSXJZVIJHIDISHPRKLKAYUPADTXQSPINQMATLPIFSVKDASCTACDPBOPVHJK",
+				&settings
+			)
+		);
+	}
+
+	#[test]
+	fn it_rejects_malformed_settings() {
+		assert_eq!(None, Settings::parse("I I III", "AAA", "AAA", ""));
+		assert_eq!(None, Settings::parse("I II VI", "AAA", "AAA", ""));
+		assert_eq!(None, Settings::parse("I II III", "AA", "AAA", ""));
+		assert_eq!(None, Settings::parse("I II III", "AAA", "AAA", "AB AC"));
+		assert_eq!(None, Settings::parse("I II III", "AAA", "AAA", "AA"));
+	}
+
+	#[test]
+	fn it_generates_valid_settings() {
+		let mut rng = rand::rng();
+
+		let rotors = random_rotors(&mut rng);
+		let plugboard = random_plugboard(&mut rng, PLUGBOARD_PAIRS);
+
+		assert!(Settings::parse(&rotors, "AAA", "AAA", &plugboard).is_some());
+		assert_eq!(PLUGBOARD_PAIRS, plugboard.split_whitespace().count());
+	}
+
+	#[test]
+	fn it_generates_non_empty_challenges() {
+		let mut rng = rand::rng();
+
+		assert_eq!(
+			3,
+			EnigmaEncryptChallenge::generate(&mut rng, 0, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			EnigmaDecryptChallenge::generate(&mut rng, 0, 0)
+				.hints()
+				.len()
+		);
+
+		let attack = EnigmaAttackChallenge::generate(&mut rng, 0, 0, 0);
+
+		assert_eq!(MIN_CRIB_SIZE, attack.crib.len());
+		assert!(attack.verify(&attack.positions));
+	}
+
+	#[test]
+	fn it_repeats_letters_in_settings() {
+		let mut rng = seeded_rng(1);
+
+		let repeats = (0..100)
+			.map(|_| random_letters(&mut rng, 3))
+			.filter(|letters| letters.bytes().collect::<HashSet<_>>().len() < 3)
+			.count();
+
+		assert!(repeats > 0);
+	}
+}
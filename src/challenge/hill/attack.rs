@@ -0,0 +1,87 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{challenge::hill, math::matrix};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HillAttackChallenge {
+	pub key:     String,
+	pub message: String,
+	pub cipher:  String,
+}
+
+/// As many known plaintext blocks as the key has rows, and the cipher they
+/// became.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HillAttackPublic {
+	pub message: String,
+	pub cipher:  String,
+}
+
+/// The key is written as the letters of its matrix, row by row.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct HillAttackSubmission {
+	#[validate(length(min = 1, message = "Key cannot be empty."))]
+	pub key: String,
+}
+
+impl HillAttackChallenge {
+	/// Picks plaintext blocks that form an invertible matrix, so that exactly
+	/// one key maps them to their cipher.
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize) -> Self {
+		let key = hill::random_key(rng, key_size);
+
+		let message = loop {
+			let message = Alphabetic
+				.sample_string(rng, key_size * key_size)
+				.to_uppercase();
+
+			let invertible = hill::block_matrix(&message, key_size)
+				.is_some_and(|plain| matrix::is_invertible_mod(&plain, 26));
+
+			if invertible {
+				break message;
+			}
+		};
+
+		let cipher = hill::encrypt(&message, &key).unwrap_or_default();
+
+		HillAttackChallenge {
+			key,
+			message,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, key: &str) -> bool {
+		hill::is_key(key) && hill::encrypt(&self.message, key).as_deref() == Some(&self.cipher)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let size = self.key.len().isqrt();
+
+		let inverse = hill::block_matrix(&self.message, size)
+			.and_then(|plain| matrix::inverse_matrix_mod(&plain, 26))
+			.unwrap_or_default();
+
+		vec![
+			"Write the plaintext blocks as the columns of a matrix P and the cipher blocks as C. Then C = K·P mod 26, so K = C·P⁻¹."
+				.to_owned(),
+			format!("P⁻¹ mod 26 is {inverse:?}."),
+			format!("The key starts with {}.", &self.key[..size]),
+		]
+	}
+}
+
+impl From<HillAttackChallenge> for HillAttackPublic {
+	fn from(challenge: HillAttackChallenge) -> Self {
+		HillAttackPublic {
+			message: challenge.message,
+			cipher:  challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,77 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{challenge::hill, math::matrix};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HillDecryptChallenge {
+	pub key:    String,
+	pub cipher: String,
+}
+
+/// The key is written as the letters of its matrix, row by row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HillDecryptPublic {
+	pub key:    String,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct HillDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl HillDecryptChallenge {
+	/// Encrypts a message of whole blocks so that no filler is needed.
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, cipher_size: usize) -> Self {
+		let key = hill::random_key(rng, key_size);
+
+		let message = Alphabetic
+			.sample_string(rng, cipher_size.next_multiple_of(key_size))
+			.to_uppercase();
+
+		let cipher = hill::encrypt(&message, &key).unwrap_or_default();
+
+		HillDecryptChallenge {
+			key,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		hill::decrypt(&self.cipher, &self.key).as_deref() == Some(message)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let size = self.key.len().isqrt();
+		let first = &self.cipher[..size];
+
+		let inverse = hill::key_matrix(&self.key)
+			.and_then(|key| matrix::inverse_matrix_mod(&key, 26))
+			.unwrap_or_default();
+
+		vec![
+			"Invert the key matrix mod 26, as the inverse of its determinant times its adjugate, then multiply each block of the cipher by it."
+				.to_owned(),
+			format!("The inverse key matrix is {inverse:?}."),
+			format!(
+				"The first block, {first}, becomes {}.",
+				hill::decrypt(first, &self.key).unwrap_or_default()
+			),
+		]
+	}
+}
+
+impl From<HillDecryptChallenge> for HillDecryptPublic {
+	fn from(challenge: HillDecryptChallenge) -> Self {
+		HillDecryptPublic {
+			key:    challenge.key,
+			cipher: challenge.cipher,
+		}
+	}
+}
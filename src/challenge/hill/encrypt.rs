@@ -0,0 +1,74 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::hill;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HillEncryptChallenge {
+	pub key:     String,
+	pub message: String,
+}
+
+/// The key is written as the letters of its matrix, row by row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HillEncryptPublic {
+	pub key:     String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct HillEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl HillEncryptChallenge {
+	/// Rounds the message up to whole blocks so that no filler is needed.
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, message_size: usize) -> Self {
+		let key = hill::random_key(rng, key_size);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.next_multiple_of(key_size))
+			.to_uppercase();
+
+		HillEncryptChallenge {
+			key,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		hill::encrypt(&self.message, &self.key).as_deref() == Some(cipher)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let size = self.key.len().isqrt();
+		let first = &self.message[..size];
+
+		vec![
+			"Split the message into blocks the size of the key's rows and multiply each block, as a column of numbers with A as 0, by the key matrix mod 26."
+				.to_owned(),
+			format!(
+				"The key matrix is {:?}.",
+				hill::key_matrix(&self.key).unwrap_or_default()
+			),
+			format!(
+				"The first block, {first}, becomes {}.",
+				hill::encrypt(first, &self.key).unwrap_or_default()
+			),
+		]
+	}
+}
+
+impl From<HillEncryptChallenge> for HillEncryptPublic {
+	fn from(challenge: HillEncryptChallenge) -> Self {
+		HillEncryptPublic {
+			key:     challenge.key,
+			message: challenge.message,
+		}
+	}
+}
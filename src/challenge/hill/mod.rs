@@ -0,0 +1,188 @@
+mod encrypt;
+pub use crate::challenge::hill::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::hill::decrypt::*;
+
+mod attack;
+pub use crate::challenge::hill::attack::*;
+
+use rand::{Rng, RngExt};
+
+use crate::math::matrix::{self, Matrix};
+
+/// Pads the last block of a message that does not fill it.
+pub const FILLER: u8 = b'X';
+
+/// Parses a key written as the letters of its matrix row by row, so a 2×2 key
+/// has four letters and a 3×3 key nine. Returns `None` unless the key is made
+/// of letters and its length is a square.
+pub fn key_matrix(key: &str) -> Option<Matrix> {
+	let size = key.len().isqrt();
+
+	if size == 0 || size * size != key.len() || !key.bytes().all(|byte| byte.is_ascii_alphabetic())
+	{
+		return None;
+	}
+
+	let entries = key
+		.bytes()
+		.map(|byte| (byte.to_ascii_uppercase() - b'A') as u64)
+		.collect::<Vec<_>>();
+
+	Some(
+		entries
+			.chunks(size)
+			.map(<[u64]>::to_vec)
+			.collect(),
+	)
+}
+
+/// Writes a matrix of residues mod 26 back as key letters.
+pub fn key_string(matrix: &[Vec<u64>]) -> String {
+	matrix
+		.iter()
+		.flatten()
+		.map(|entry| (*entry as u8 + b'A') as char)
+		.collect()
+}
+
+/// Valid keys are square matrices with an inverse mod 26.
+pub fn is_key(key: &str) -> bool {
+	key_matrix(key).is_some_and(|matrix| matrix::is_invertible_mod(&matrix, 26))
+}
+
+/// Multiplies each block of letters, as a column vector, by the key matrix,
+/// padding the last block with the filler. Returns `None` for a malformed key.
+pub fn encrypt(message: &str, key: &str) -> Option<String> {
+	let key = key_matrix(key)?;
+	Some(transform(message, &key))
+}
+
+/// Returns `None` when the key has no inverse mod 26.
+pub fn decrypt(cipher: &str, key: &str) -> Option<String> {
+	let inverse = matrix::inverse_matrix_mod(&key_matrix(key)?, 26)?;
+	Some(transform(cipher, &inverse))
+}
+
+pub fn random_key<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+	loop {
+		let key = (0..size * size)
+			.map(|_| rng.random_range(b'A'..=b'Z') as char)
+			.collect::<String>();
+
+		if is_key(&key) {
+			return key;
+		}
+	}
+}
+
+/// Lays the first `size` blocks of `text` side by side as the columns of a
+/// square matrix. Returns `None` if there are not enough letters.
+pub fn block_matrix(text: &str, size: usize) -> Option<Matrix> {
+	let letters = text.as_bytes();
+
+	if size == 0 || letters.len() < size * size {
+		return None;
+	}
+
+	let columns = (0..size)
+		.map(|row| {
+			(0..size)
+				.map(|column| (letters[column * size + row] - b'A') as u64)
+				.collect()
+		})
+		.collect();
+
+	Some(columns)
+}
+
+/// Solves `C = K·P (mod 26)` for the key, where `P` and `C` hold the first
+/// `size` blocks of a known plaintext and its cipher as columns. The key is
+/// `C·P⁻¹`, so `P` has to be invertible mod 26.
+pub fn recover_key(message: &str, cipher: &str, size: usize) -> Option<String> {
+	let plain = block_matrix(message, size)?;
+	let cipher = block_matrix(cipher, size)?;
+
+	let inverse = matrix::inverse_matrix_mod(&plain, 26)?;
+
+	Some(key_string(&matrix::mul_matrix_mod(&cipher, &inverse, 26)))
+}
+
+fn transform(text: &str, key: &[Vec<u64>]) -> String {
+	let size = key.len();
+
+	let mut letters = text
+		.bytes()
+		.map(|byte| (byte - b'A') as u64)
+		.collect::<Vec<_>>();
+
+	letters.resize(letters.len().next_multiple_of(size), (FILLER - b'A') as u64);
+
+	letters
+		.chunks(size)
+		.flat_map(|block| {
+			let column = block
+				.iter()
+				.map(|letter| vec![*letter])
+				.collect::<Vec<_>>();
+
+			matrix::mul_matrix_mod(key, &column, 26)
+		})
+		.map(|row| (row[0] as u8 + b'A') as char)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::challenge::hill::*;
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		assert_eq!(Some("POH".to_owned()), encrypt("ACT", "GYBNQKURP"));
+		assert_eq!(Some("FIN".to_owned()), encrypt("CAT", "GYBNQKURP"));
+		assert_eq!(Some("ACT".to_owned()), decrypt("POH", "GYBNQKURP"));
+
+		assert_eq!(
+			Some("APADJTFTWLFJ".to_owned()),
+			encrypt("SHORTEXAMPLE", "HILL")
+		);
+		assert_eq!(
+			Some("SHORTEXAMPLE".to_owned()),
+			decrypt("APADJTFTWLFJ", "HILL")
+		);
+	}
+
+	#[test]
+	fn it_pads_the_last_block() {
+		let cipher = encrypt("ACTS", "GYBNQKURP").unwrap();
+
+		assert_eq!(6, cipher.len());
+		assert_eq!(Some("ACTSXX".to_owned()), decrypt(&cipher, "GYBNQKURP"));
+	}
+
+	#[test]
+	fn it_checks_keys() {
+		assert!(is_key("HILL"));
+		assert!(is_key("gybnqkurp"));
+		assert!(!is_key("ABCD"));
+		assert!(!is_key("HIL"));
+		assert!(!is_key("HI L"));
+		assert_eq!(None, decrypt("APAD", "ABCD"));
+	}
+
+	#[test]
+	fn it_recovers_keys() {
+		let cipher = encrypt("HELP", "HILL").unwrap();
+		assert_eq!(Some("HILL".to_owned()), recover_key("HELP", &cipher, 2));
+
+		let cipher = encrypt("ONETWOSIX", "GYBNQKURP").unwrap();
+		assert_eq!(
+			Some("GYBNQKURP".to_owned()),
+			recover_key("ONETWOSIX", &cipher, 3)
+		);
+
+		// SH and OR form a singular matrix mod 26
+		assert_eq!(None, recover_key("SHORTEXAMPLE", "APADJTFTWLFJ", 2));
+	}
+}
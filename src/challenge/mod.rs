@@ -0,0 +1,430 @@
+pub mod affine;
+pub mod caesar;
+pub mod columnar;
+pub mod diffie_hellman_exchange;
+pub mod dss;
+pub mod english;
+pub mod enigma;
+pub mod hill;
+pub mod playfair;
+pub mod rail_fence;
+pub mod rsa;
+pub mod substitution;
+pub mod vigenere;
+
+use std::{
+	fmt::{self, Display},
+	ops::RangeInclusive,
+	str::FromStr,
+};
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IteratorRandom};
+use serde::{Deserialize, Serialize};
+
+use crate::math;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChallengeKind {
+	CaesarEncrypt,
+	CaesarDecrypt,
+	CaesarAttack,
+	DiffieHellmanExchange,
+	RsaEncrypt,
+	RsaDecrypt,
+	DssSign,
+	DssVerify,
+	VigenereEncrypt,
+	VigenereDecrypt,
+	VigenereAttack,
+	AffineEncrypt,
+	AffineDecrypt,
+	AffineAttack,
+	SubstitutionAttack,
+	CaesarCiphertextAttack,
+	PlayfairEncrypt,
+	PlayfairDecrypt,
+	HillEncrypt,
+	HillDecrypt,
+	HillAttack,
+	RailFenceEncrypt,
+	RailFenceDecrypt,
+	ColumnarEncrypt,
+	ColumnarDecrypt,
+	ColumnarDoubleAttack,
+	EnigmaEncrypt,
+	EnigmaDecrypt,
+	EnigmaAttack,
+}
+
+impl ChallengeKind {
+	pub const ALL: [ChallengeKind; 29] = [
+		ChallengeKind::CaesarEncrypt,
+		ChallengeKind::CaesarDecrypt,
+		ChallengeKind::CaesarAttack,
+		ChallengeKind::DiffieHellmanExchange,
+		ChallengeKind::RsaEncrypt,
+		ChallengeKind::RsaDecrypt,
+		ChallengeKind::DssSign,
+		ChallengeKind::DssVerify,
+		ChallengeKind::VigenereEncrypt,
+		ChallengeKind::VigenereDecrypt,
+		ChallengeKind::VigenereAttack,
+		ChallengeKind::AffineEncrypt,
+		ChallengeKind::AffineDecrypt,
+		ChallengeKind::AffineAttack,
+		ChallengeKind::SubstitutionAttack,
+		ChallengeKind::CaesarCiphertextAttack,
+		ChallengeKind::PlayfairEncrypt,
+		ChallengeKind::PlayfairDecrypt,
+		ChallengeKind::HillEncrypt,
+		ChallengeKind::HillDecrypt,
+		ChallengeKind::HillAttack,
+		ChallengeKind::RailFenceEncrypt,
+		ChallengeKind::RailFenceDecrypt,
+		ChallengeKind::ColumnarEncrypt,
+		ChallengeKind::ColumnarDecrypt,
+		ChallengeKind::ColumnarDoubleAttack,
+		ChallengeKind::EnigmaEncrypt,
+		ChallengeKind::EnigmaDecrypt,
+		ChallengeKind::EnigmaAttack,
+	];
+
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ChallengeKind::CaesarEncrypt => "caesar-encrypt",
+			ChallengeKind::CaesarDecrypt => "caesar-decrypt",
+			ChallengeKind::CaesarAttack => "caesar-attack",
+			ChallengeKind::DiffieHellmanExchange => "diffie-hellman-exchange",
+			ChallengeKind::RsaEncrypt => "rsa-encrypt",
+			ChallengeKind::RsaDecrypt => "rsa-decrypt",
+			ChallengeKind::DssSign => "dss-sign",
+			ChallengeKind::DssVerify => "dss-verify",
+			ChallengeKind::VigenereEncrypt => "vigenere-encrypt",
+			ChallengeKind::VigenereDecrypt => "vigenere-decrypt",
+			ChallengeKind::VigenereAttack => "vigenere-attack",
+			ChallengeKind::AffineEncrypt => "affine-encrypt",
+			ChallengeKind::AffineDecrypt => "affine-decrypt",
+			ChallengeKind::AffineAttack => "affine-attack",
+			ChallengeKind::SubstitutionAttack => "substitution-attack",
+			ChallengeKind::CaesarCiphertextAttack => "caesar-ciphertext-attack",
+			ChallengeKind::PlayfairEncrypt => "playfair-encrypt",
+			ChallengeKind::PlayfairDecrypt => "playfair-decrypt",
+			ChallengeKind::HillEncrypt => "hill-encrypt",
+			ChallengeKind::HillDecrypt => "hill-decrypt",
+			ChallengeKind::HillAttack => "hill-attack",
+			ChallengeKind::RailFenceEncrypt => "rail-fence-encrypt",
+			ChallengeKind::RailFenceDecrypt => "rail-fence-decrypt",
+			ChallengeKind::ColumnarEncrypt => "columnar-encrypt",
+			ChallengeKind::ColumnarDecrypt => "columnar-decrypt",
+			ChallengeKind::ColumnarDoubleAttack => "columnar-double-attack",
+			ChallengeKind::EnigmaEncrypt => "enigma-encrypt",
+			ChallengeKind::EnigmaDecrypt => "enigma-decrypt",
+			ChallengeKind::EnigmaAttack => "enigma-attack",
+		}
+	}
+}
+
+impl Display for ChallengeKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl FromStr for ChallengeKind {
+	type Err = String;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		ChallengeKind::ALL
+			.into_iter()
+			.find(|kind| kind.as_str() == value)
+			.ok_or_else(|| format!("unknown challenge: {value}"))
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "&'static str")]
+pub enum Difficulty {
+	Easy,
+
+	#[default]
+	Medium,
+
+	Hard,
+
+	/// Number-theory challenges use realistically sized keys from here on.
+	Advanced,
+
+	Expert,
+}
+
+impl Difficulty {
+	pub const ALL: [Difficulty; 5] = [
+		Difficulty::Easy,
+		Difficulty::Medium,
+		Difficulty::Hard,
+		Difficulty::Advanced,
+		Difficulty::Expert,
+	];
+
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Difficulty::Easy => "easy",
+			Difficulty::Medium => "medium",
+			Difficulty::Hard => "hard",
+			Difficulty::Advanced => "advanced",
+			Difficulty::Expert => "expert",
+		}
+	}
+
+	pub fn tier(&self) -> usize {
+		match self {
+			Difficulty::Easy => 1,
+			Difficulty::Medium => 2,
+			Difficulty::Hard => 3,
+			Difficulty::Advanced => 4,
+			Difficulty::Expert => 5,
+		}
+	}
+
+	/// Default key size for tiers that use arbitrary-precision parameters.
+	/// Lower tiers draw their parameters from small configured ranges.
+	pub fn key_bits(&self) -> Option<u64> {
+		match self {
+			Difficulty::Easy | Difficulty::Medium | Difficulty::Hard => None,
+			Difficulty::Advanced => Some(512),
+			Difficulty::Expert => Some(2_048),
+		}
+	}
+
+	/// Scales a message length configured for medium difficulty.
+	pub fn scale_size(&self, size: usize) -> usize {
+		match self {
+			Difficulty::Easy => (size / 2).max(1),
+			Difficulty::Medium => size,
+			Difficulty::Hard => size * 2,
+			Difficulty::Advanced => size * 4,
+			Difficulty::Expert => size * 8,
+		}
+	}
+
+	/// Scales a parameter range configured for medium difficulty.
+	pub fn scale_range(&self, range: RangeInclusive<usize>) -> RangeInclusive<usize> {
+		let (start, end) = range.into_inner();
+
+		match self {
+			Difficulty::Easy => (start / 2)..=(end / 2),
+			Difficulty::Medium => start..=end,
+			Difficulty::Hard => (start * 2)..=(end * 2),
+			Difficulty::Advanced => (start * 4)..=(end * 4),
+			Difficulty::Expert => (start * 8)..=(end * 8),
+		}
+	}
+}
+
+impl Display for Difficulty {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl FromStr for Difficulty {
+	type Err = String;
+
+	/// Accepts either the name of a difficulty or its numeric tier.
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		Difficulty::ALL
+			.into_iter()
+			.find(|difficulty| {
+				difficulty.as_str() == value || difficulty.tier().to_string() == value
+			})
+			.ok_or_else(|| format!("unknown difficulty: {value}"))
+	}
+}
+
+impl TryFrom<String> for Difficulty {
+	type Error = String;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+
+impl From<Difficulty> for &'static str {
+	fn from(difficulty: Difficulty) -> Self {
+		difficulty.as_str()
+	}
+}
+
+/// How large the numbers in a number-theory challenge are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySize {
+	/// Primes drawn from a range of small values.
+	Range(RangeInclusive<usize>),
+
+	/// Primes of a given number of bits, for arbitrary-precision parameters.
+	Bits(u64),
+}
+
+impl From<RangeInclusive<usize>> for KeySize {
+	fn from(range: RangeInclusive<usize>) -> Self {
+		KeySize::Range(range)
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DifficultyQuery {
+	pub difficulty: Option<Difficulty>,
+}
+
+/// One of a challenge's progressive hints, numbered from 1. Each challenge's
+/// `hints()` start from the general method and work towards the answer, every
+/// hint giving away more than the one before.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hint {
+	pub number: usize,
+	pub total:  usize,
+	pub text:   String,
+}
+
+impl Hint {
+	/// Returns the `number`th hint, or the last one if `number` runs past the
+	/// end.
+	pub fn nth(hints: Vec<String>, number: usize) -> Option<Self> {
+		let total = hints.len();
+		let number = number.min(total).max(1);
+		let text = hints.into_iter().nth(number - 1)?;
+
+		let hint = Hint {
+			number,
+			total,
+			text,
+		};
+
+		Some(hint)
+	}
+}
+
+/// Returns the generator every challenge is drawn from, so a challenge can be
+/// reproduced from its seed. `StdRng` may change between `rand` releases, so
+/// seeds only reproduce a challenge on the same build.
+pub fn seeded_rng(seed: u64) -> StdRng {
+	StdRng::seed_from_u64(seed)
+}
+
+/// Picks a prime from the range uniformly, so that primes after a long gap
+/// are not drawn more often than the rest.
+fn random_prime<R: Rng + ?Sized>(rng: &mut R, range: &RangeInclusive<usize>) -> Option<u64> {
+	let start = *range.start() as u64;
+	let end = *range.end() as u64;
+
+	(start..=end)
+		.filter(|n| math::is_prime(*n))
+		.choose(rng)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{challenge::*, models};
+
+	fn hints() -> Vec<String> {
+		vec!["first".to_owned(), "second".to_owned()]
+	}
+
+	#[test]
+	fn it_reveals_hints_in_order() {
+		let hint = Hint::nth(hints(), 1).unwrap();
+		assert_eq!(1, hint.number);
+		assert_eq!(2, hint.total);
+		assert_eq!("first", hint.text);
+
+		let hint = Hint::nth(hints(), 2).unwrap();
+		assert_eq!("second", hint.text);
+	}
+
+	#[test]
+	fn it_repeats_the_last_hint() {
+		let hint = Hint::nth(hints(), 5).unwrap();
+		assert_eq!(2, hint.number);
+		assert_eq!("second", hint.text);
+	}
+
+	#[test]
+	fn it_has_no_hint_without_hints() {
+		assert_eq!(None, Hint::nth(Vec::new(), 1));
+	}
+
+	#[test]
+	fn it_parses_difficulties() {
+		assert_eq!(Ok(Difficulty::Easy), "easy".parse());
+		assert_eq!(Ok(Difficulty::Hard), "3".parse());
+		assert_eq!(Ok(Difficulty::Expert), "5".parse());
+		assert!("6".parse::<Difficulty>().is_err());
+	}
+
+	#[test]
+	fn it_scales_by_difficulty() {
+		assert_eq!(3, Difficulty::Easy.scale_size(6));
+		assert_eq!(1, Difficulty::Easy.scale_size(1));
+		assert_eq!(12, Difficulty::Hard.scale_size(6));
+
+		assert_eq!(500..=5_000, Difficulty::Easy.scale_range(1_000..=10_000));
+		assert_eq!(
+			1_000..=10_000,
+			Difficulty::Medium.scale_range(1_000..=10_000)
+		);
+		assert_eq!(2_000..=20_000, Difficulty::Hard.scale_range(1_000..=10_000));
+	}
+
+	#[test]
+	fn it_generates_the_same_challenge_from_the_same_seed() {
+		let first = caesar::CaesarEncryptChallenge::generate(&mut seeded_rng(42), 12);
+		let second = caesar::CaesarEncryptChallenge::generate(&mut seeded_rng(42), 12);
+		assert_eq!(first, second);
+
+		let first = rsa::RsaEncryptChallenge::generate(&mut seeded_rng(42), 1_000..=10_000);
+		let second = rsa::RsaEncryptChallenge::generate(&mut seeded_rng(42), 1_000..=10_000);
+		assert!(first.is_some());
+		assert_eq!(first, second);
+	}
+
+	#[test]
+	fn it_draws_primes_uniformly() {
+		let mut rng = seeded_rng(1);
+
+		// 29 follows a gap of five composites, 31 a gap of one
+		let draws = (0..1_000)
+			.filter_map(|_| random_prime(&mut rng, &(24..=31)))
+			.collect::<Vec<_>>();
+
+		let twenty_nines = draws.iter().filter(|prime| **prime == 29).count();
+
+		assert_eq!(1_000, draws.len());
+		assert!((400..600).contains(&twenty_nines));
+		assert_eq!(None, random_prime(&mut rng, &(24..=28)));
+	}
+
+	#[test]
+	fn it_generates_the_smallest_key_size() {
+		let bits = KeySize::Bits(models::MIN_KEY_BITS);
+
+		assert!(rsa::RsaEncryptChallenge::generate(&mut seeded_rng(1), bits.clone()).is_some());
+		assert!(dss::DssVerifyChallenge::generate(&mut seeded_rng(1), bits.clone()).is_some());
+		assert!(
+			diffie_hellman_exchange::DiffieHellmanExchangeChallenge::generate(
+				&mut seeded_rng(1),
+				bits
+			)
+			.is_some()
+		);
+	}
+
+	#[test]
+	fn it_parses_challenge_kinds() {
+		for kind in ChallengeKind::ALL {
+			assert_eq!(Ok(kind), kind.as_str().parse());
+		}
+
+		assert!("caesar".parse::<ChallengeKind>().is_err());
+	}
+}
@@ -0,0 +1,85 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::playfair;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayfairDecryptChallenge {
+	pub keyword: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayfairDecryptPublic {
+	pub keyword: String,
+	pub cipher:  String,
+}
+
+/// The decrypted digraphs, filler letters included.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PlayfairDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl PlayfairDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, cipher_size: usize) -> Self {
+		let keyword = playfair::random_keyword(rng);
+
+		let message = Alphabetic
+			.sample_string(rng, cipher_size.max(1))
+			.to_uppercase();
+
+		let cipher = playfair::encrypt(&message, &keyword);
+
+		PlayfairDecryptChallenge {
+			keyword,
+			cipher,
+		}
+	}
+
+	/// Describes the first wrong digraph of `message`, or `None` if it is
+	/// correct.
+	pub fn mistake(&self, message: &str) -> Option<String> {
+		playfair::mistake(&playfair::decrypt(&self.cipher, &self.keyword), message)
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		self.mistake(message).is_none()
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let square = playfair::square(&self.keyword);
+		let first = &self.cipher[..2];
+
+		vec![
+			"Undo each pair: letters in the same row move left, in the same column move up, and otherwise swap columns. Keep any filler letters."
+				.to_owned(),
+			format!(
+				"The square's rows are {}.",
+				square
+					.chunks(5)
+					.map(String::from_utf8_lossy)
+					.collect::<Vec<_>>()
+					.join(", ")
+			),
+			format!(
+				"The first digraph, {first}, becomes {}.",
+				playfair::decrypt(first, &self.keyword)
+			),
+		]
+	}
+}
+
+impl From<PlayfairDecryptChallenge> for PlayfairDecryptPublic {
+	fn from(challenge: PlayfairDecryptChallenge) -> Self {
+		PlayfairDecryptPublic {
+			keyword: challenge.keyword,
+			cipher:  challenge.cipher,
+		}
+	}
+}
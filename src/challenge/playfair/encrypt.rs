@@ -0,0 +1,83 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::playfair;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayfairEncryptChallenge {
+	pub keyword: String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayfairEncryptPublic {
+	pub keyword: String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PlayfairEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl PlayfairEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let keyword = playfair::random_keyword(rng);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		PlayfairEncryptChallenge {
+			keyword,
+			message,
+		}
+	}
+
+	/// Describes the first wrong digraph of `cipher`, or `None` if it is
+	/// correct.
+	pub fn mistake(&self, cipher: &str) -> Option<String> {
+		playfair::mistake(&playfair::encrypt(&self.message, &self.keyword), cipher)
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		self.mistake(cipher).is_none()
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let square = playfair::square(&self.keyword);
+		let digraphs = playfair::digraphs(&self.message);
+		let first = String::from_utf8_lossy(&digraphs[0]);
+
+		vec![
+			"Split the message into pairs, putting an X between doubled letters. Letters in the same row move right, in the same column move down, and otherwise swap columns."
+				.to_owned(),
+			format!(
+				"The square's rows are {}.",
+				square
+					.chunks(5)
+					.map(String::from_utf8_lossy)
+					.collect::<Vec<_>>()
+					.join(", ")
+			),
+			format!(
+				"The first digraph, {first}, becomes {}.",
+				playfair::encrypt(&first, &self.keyword)
+			),
+		]
+	}
+}
+
+impl From<PlayfairEncryptChallenge> for PlayfairEncryptPublic {
+	fn from(challenge: PlayfairEncryptChallenge) -> Self {
+		PlayfairEncryptPublic {
+			keyword: challenge.keyword,
+			message: challenge.message,
+		}
+	}
+}
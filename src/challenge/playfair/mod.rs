@@ -0,0 +1,234 @@
+mod encrypt;
+pub use crate::challenge::playfair::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::playfair::decrypt::*;
+
+use rand::{Rng, seq::IndexedRandom};
+
+use crate::challenge::english;
+
+/// Letter inserted between doubled letters and after an odd last letter.
+pub const FILLER: u8 = b'X';
+
+/// Filler used when the letter that needs padding is itself the filler.
+pub const ALTERNATE_FILLER: u8 = b'Q';
+
+/// Keywords are drawn from common words at least this long.
+const KEYWORD_SIZE: usize = 5;
+
+/// Builds the 5×5 square, row by row, from the keyword's distinct letters
+/// followed by the rest of the alphabet. J is merged into I.
+pub fn square(keyword: &str) -> [u8; 25] {
+	let mut square = [0; 25];
+	let mut len = 0;
+
+	let letters = keyword
+		.bytes()
+		.map(|byte| byte.to_ascii_uppercase())
+		.chain(b'A'..=b'Z');
+
+	for letter in letters.filter_map(merge) {
+		if !square[..len].contains(&letter) {
+			square[len] = letter;
+			len += 1;
+		}
+	}
+
+	square
+}
+
+/// Uppercases the letters of `message`, merges J into I and splits them into
+/// digraphs, inserting a filler between doubled letters and after an odd last
+/// letter.
+pub fn digraphs(message: &str) -> Vec<[u8; 2]> {
+	let mut letters = message.bytes().filter_map(merge).peekable();
+	let mut digraphs = Vec::new();
+
+	while let Some(first) = letters.next() {
+		let filler = if first == FILLER {
+			ALTERNATE_FILLER
+		} else {
+			FILLER
+		};
+
+		let second = match letters.peek() {
+			Some(second) if *second != first => letters.next().unwrap_or(filler),
+			_ => filler,
+		};
+
+		digraphs.push([first, second]);
+	}
+
+	digraphs
+}
+
+pub fn encrypt(message: &str, keyword: &str) -> String {
+	transform(&digraphs(message), &square(keyword), 1)
+}
+
+/// Decrypts to the prepared message, fillers included, since they cannot be
+/// told apart from real letters.
+pub fn decrypt(cipher: &str, keyword: &str) -> String {
+	let letters = cipher
+		.bytes()
+		.filter_map(merge)
+		.collect::<Vec<_>>();
+
+	let digraphs = letters
+		.chunks(2)
+		.map(|pair| [pair[0], pair.get(1).copied().unwrap_or(FILLER)])
+		.collect::<Vec<_>>();
+
+	transform(&digraphs, &square(keyword), 4)
+}
+
+pub fn random_keyword<R: Rng + ?Sized>(rng: &mut R) -> String {
+	let keywords = english::WORDS
+		.iter()
+		.filter(|word| word.len() >= KEYWORD_SIZE)
+		.collect::<Vec<_>>();
+
+	keywords
+		.choose(rng)
+		.map(|word| word.to_string())
+		.unwrap_or_default()
+}
+
+/// Describes the first digraph of `submitted` that differs from `expected`,
+/// comparing letters only and merging J into I, or `None` if they match.
+pub fn mistake(expected: &str, submitted: &str) -> Option<String> {
+	let expected = expected
+		.bytes()
+		.filter_map(merge)
+		.collect::<Vec<_>>();
+	let submitted = submitted
+		.bytes()
+		.filter_map(merge)
+		.collect::<Vec<_>>();
+
+	let wrong = expected
+		.chunks(2)
+		.zip(submitted.chunks(2))
+		.position(|(expected, submitted)| expected != submitted);
+
+	match wrong {
+		Some(index) => Some(format!(
+			"digraph {} ({}) is wrong",
+			index + 1,
+			String::from_utf8_lossy(submitted.chunks(2).nth(index)?)
+		)),
+		None if expected.len() != submitted.len() => Some(format!(
+			"expected {} letters but got {}",
+			expected.len(),
+			submitted.len()
+		)),
+		None => None,
+	}
+}
+
+/// Uppercases ASCII letters and merges J into I, dropping everything else.
+fn merge(byte: u8) -> Option<u8> {
+	match byte.to_ascii_uppercase() {
+		b'J' => Some(b'I'),
+		letter @ b'A'..=b'Z' => Some(letter),
+		_ => None,
+	}
+}
+
+/// Applies the Playfair rules to each digraph, moving `shift` places along a
+/// shared row or column: 1 encrypts and 4 decrypts.
+fn transform(digraphs: &[[u8; 2]], square: &[u8; 25], shift: usize) -> String {
+	let position = |letter: u8| {
+		let index = square
+			.iter()
+			.position(|cell| *cell == letter)
+			.unwrap_or_default();
+
+		(index / 5, index % 5)
+	};
+
+	digraphs
+		.iter()
+		.flat_map(|[first, second]| {
+			let (row_1, column_1) = position(*first);
+			let (row_2, column_2) = position(*second);
+
+			let (first, second) = if row_1 == row_2 {
+				(
+					(row_1, (column_1 + shift) % 5),
+					(row_2, (column_2 + shift) % 5),
+				)
+			} else if column_1 == column_2 {
+				(
+					((row_1 + shift) % 5, column_1),
+					((row_2 + shift) % 5, column_2),
+				)
+			} else {
+				((row_1, column_2), (row_2, column_1))
+			};
+
+			[first, second].map(|(row, column)| square[row * 5 + column] as char)
+		})
+		.collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::challenge::playfair::*;
+
+	const KEYWORD: &str = "PLAYFAIR EXAMPLE";
+
+	#[test]
+	fn it_builds_squares() {
+		assert_eq!(b"PLAYFIREXMBCDGHKNOQSTUVWZ", &square(KEYWORD));
+		assert_eq!(b"IABCDEFGHKLMNOPQRSTUVWXYZ", &square("jj"));
+	}
+
+	#[test]
+	fn it_splits_digraphs() {
+		assert_eq!(vec![*b"TR", *b"EX", *b"ES"], digraphs("trees"));
+		assert_eq!(vec![*b"XQ", *b"XQ"], digraphs("XX"));
+		assert_eq!(vec![*b"IX", *b"IN", *b"XQ"], digraphs("jinx"));
+		assert_eq!(vec![*b"AX"], digraphs("A"));
+	}
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		let cipher = encrypt("Hide the gold in the tree stump", KEYWORD);
+
+		assert_eq!("BMODZBXDNABEKUDMUIXMMOUVIF", cipher);
+		assert_eq!("HIDETHEGOLDINTHETREXESTUMP", decrypt(&cipher, KEYWORD));
+	}
+
+	#[test]
+	fn it_describes_mistakes() {
+		assert_eq!(None, mistake("BMODZB", "bm od zb"));
+		assert_eq!(
+			Some("digraph 2 (OE) is wrong".to_owned()),
+			mistake("BMODZB", "BMOEZB")
+		);
+		assert_eq!(
+			Some("expected 6 letters but got 4".to_owned()),
+			mistake("BMODZB", "BMOD")
+		);
+	}
+
+	#[test]
+	fn it_hints_at_empty_sizes() {
+		let mut rng = rand::rng();
+
+		assert_eq!(
+			3,
+			PlayfairEncryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			PlayfairDecryptChallenge::generate(&mut rng, 0)
+				.hints()
+				.len()
+		);
+	}
+}
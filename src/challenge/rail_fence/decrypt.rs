@@ -0,0 +1,78 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::rail_fence;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RailFenceDecryptChallenge {
+	pub rails:  i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RailFenceDecryptPublic {
+	pub rails:  i32,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RailFenceDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl RailFenceDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, cipher_size: usize) -> Self {
+		let rails = rail_fence::random_rails(rng);
+
+		let message = Alphabetic
+			.sample_string(rng, cipher_size)
+			.to_uppercase();
+
+		let cipher = rail_fence::encrypt(&message, rails);
+
+		RailFenceDecryptChallenge {
+			rails,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		rail_fence::decrypt(&self.cipher, self.rails) == message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let message = rail_fence::decrypt(&self.cipher, self.rails);
+		let lengths = (0..self.rails as usize)
+			.map(|rail| {
+				(0..self.cipher.len())
+					.filter(|index| rail_fence::rail_of(*index, self.rails) == rail)
+					.count()
+					.to_string()
+			})
+			.collect::<Vec<_>>();
+
+		vec![
+			"Work out how many letters fall on each rail, cut the cipher into the rails in that order, then read them back in a zigzag."
+				.to_owned(),
+			format!("The rails hold {} letters.", lengths.join(", ")),
+			format!(
+				"The message starts with {}.",
+				&message[..message.len().min(3)]
+			),
+		]
+	}
+}
+
+impl From<RailFenceDecryptChallenge> for RailFenceDecryptPublic {
+	fn from(challenge: RailFenceDecryptChallenge) -> Self {
+		RailFenceDecryptPublic {
+			rails:  challenge.rails,
+			cipher: challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,71 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::rail_fence;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RailFenceEncryptChallenge {
+	pub rails:   i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RailFenceEncryptPublic {
+	pub rails:   i32,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RailFenceEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl RailFenceEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let rails = rail_fence::random_rails(rng);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size)
+			.to_uppercase();
+
+		RailFenceEncryptChallenge {
+			rails,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		rail_fence::encrypt(&self.message, self.rails) == cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let cipher = rail_fence::encrypt(&self.message, self.rails);
+		let first_rail = (0..self.message.len())
+			.filter(|index| rail_fence::rail_of(*index, self.rails) == 0)
+			.count();
+
+		vec![
+			"Write the message diagonally down and up across the rails, then read each rail from left to right, top rail first."
+				.to_owned(),
+			format!(
+				"The top rail holds {first_rail} letters, every {} letters of the message.",
+				2 * (self.rails - 1)
+			),
+			format!("The cipher starts with {}.", &cipher[..first_rail]),
+		]
+	}
+}
+
+impl From<RailFenceEncryptChallenge> for RailFenceEncryptPublic {
+	fn from(challenge: RailFenceEncryptChallenge) -> Self {
+		RailFenceEncryptPublic {
+			rails:   challenge.rails,
+			message: challenge.message,
+		}
+	}
+}
@@ -0,0 +1,89 @@
+mod encrypt;
+pub use crate::challenge::rail_fence::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::rail_fence::decrypt::*;
+
+use std::ops::RangeInclusive;
+
+use rand::{Rng, RngExt};
+
+/// Rail counts that generated challenges draw from.
+pub const RAILS: RangeInclusive<i32> = 2..=5;
+
+/// Writes the message in a zigzag down and up across `rails` rails and reads
+/// the rails off top to bottom.
+pub fn encrypt(message: &str, rails: i32) -> String {
+	let letters = message.as_bytes();
+
+	order(letters.len(), rails)
+		.into_iter()
+		.map(|index| letters[index] as char)
+		.collect()
+}
+
+pub fn decrypt(cipher: &str, rails: i32) -> String {
+	let mut letters = vec![0; cipher.len()];
+
+	for (index, letter) in order(cipher.len(), rails)
+		.into_iter()
+		.zip(cipher.bytes())
+	{
+		letters[index] = letter;
+	}
+
+	String::from_utf8_lossy(&letters).into_owned()
+}
+
+pub fn random_rails<R: Rng + ?Sized>(rng: &mut R) -> i32 {
+	rng.random_range(RAILS)
+}
+
+/// Returns the rail that the letter at `index` is written on. Fewer than two
+/// rails leave every letter on the first.
+pub fn rail_of(index: usize, rails: i32) -> usize {
+	let period = 2 * (rails.max(1) as usize - 1);
+
+	if period == 0 {
+		return 0;
+	}
+
+	let offset = index % period;
+	offset.min(period - offset)
+}
+
+/// Positions of the message in the order they appear in the cipher.
+fn order(len: usize, rails: i32) -> Vec<usize> {
+	let mut positions = (0..len).collect::<Vec<_>>();
+	positions.sort_by_key(|index| rail_of(*index, rails));
+	positions
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::challenge::rail_fence::*;
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		let cipher = encrypt("WEAREDISCOVEREDFLEEATONCE", 3);
+
+		assert_eq!("WECRLTEERDSOEEFEAOCAIVDEN", cipher);
+		assert_eq!("WEAREDISCOVEREDFLEEATONCE", decrypt(&cipher, 3));
+	}
+
+	#[test]
+	fn it_leaves_single_rails_alone() {
+		assert_eq!("HELLO", encrypt("HELLO", 1));
+		assert_eq!("HELLO", decrypt("HELLO", 1));
+		assert_eq!("HLOEL", encrypt("HELLO", 2));
+	}
+
+	#[test]
+	fn it_zigzags_across_rails() {
+		let rails = (0..8)
+			.map(|index| rail_of(index, 4))
+			.collect::<Vec<_>>();
+
+		assert_eq!(vec![0, 1, 2, 3, 2, 1, 0, 1], rails);
+	}
+}
@@ -0,0 +1,88 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+	challenge::{KeySize, rsa::RsaKeyPair},
+	math::{self, big::BigUint},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsaDecryptChallenge {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub e: BigUint,
+	#[serde(with = "math::big")]
+	pub d: BigUint,
+	#[serde(with = "math::big")]
+	pub c: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RsaDecryptPublic {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub e: BigUint,
+	#[serde(with = "math::big")]
+	pub d: BigUint,
+	#[serde(with = "math::big")]
+	pub c: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RsaDecryptSubmission {
+	#[serde(with = "math::big")]
+	pub m: BigUint,
+}
+
+impl RsaDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		let key_pair = RsaKeyPair::generate(rng, size)?;
+		let m = key_pair.random_message(rng);
+		let c = m.modpow(&key_pair.e, &key_pair.n());
+
+		let challenge = RsaDecryptChallenge {
+			p: key_pair.p,
+			q: key_pair.q,
+			e: key_pair.e,
+			d: key_pair.d,
+			c,
+		};
+
+		Some(challenge)
+	}
+
+	pub fn verify(&self, m: &BigUint) -> bool {
+		m == &self.c.modpow(&self.d, &(&self.p * &self.q))
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			format!("The modulus is n = pq = {}.", &self.p * &self.q),
+			format!(
+				"The totient is (p-1)(q-1) = {}, and d is the inverse of e modulo it.",
+				(&self.p - 1u32) * (&self.q - 1u32)
+			),
+			"The message is m = c^d mod n.".to_owned(),
+			"Use square-and-multiply, reducing mod n after every multiplication.".to_owned(),
+		]
+	}
+}
+
+impl From<RsaDecryptChallenge> for RsaDecryptPublic {
+	fn from(challenge: RsaDecryptChallenge) -> Self {
+		RsaDecryptPublic {
+			p: challenge.p,
+			q: challenge.q,
+			e: challenge.e,
+			d: challenge.d,
+			c: challenge.c,
+		}
+	}
+}
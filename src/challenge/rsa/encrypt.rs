@@ -0,0 +1,83 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+	challenge::{KeySize, rsa::RsaKeyPair},
+	math::{self, big::BigUint},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsaEncryptChallenge {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub e: BigUint,
+	#[serde(with = "math::big")]
+	pub d: BigUint,
+	#[serde(with = "math::big")]
+	pub m: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RsaEncryptPublic {
+	#[serde(with = "math::big")]
+	pub p: BigUint,
+	#[serde(with = "math::big")]
+	pub q: BigUint,
+	#[serde(with = "math::big")]
+	pub e: BigUint,
+	#[serde(with = "math::big")]
+	pub d: BigUint,
+	#[serde(with = "math::big")]
+	pub m: BigUint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RsaEncryptSubmission {
+	#[serde(with = "math::big")]
+	pub c: BigUint,
+}
+
+impl RsaEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		let key_pair = RsaKeyPair::generate(rng, size)?;
+		let m = key_pair.random_message(rng);
+
+		let challenge = RsaEncryptChallenge {
+			p: key_pair.p,
+			q: key_pair.q,
+			e: key_pair.e,
+			d: key_pair.d,
+			m,
+		};
+
+		Some(challenge)
+	}
+
+	pub fn verify(&self, c: &BigUint) -> bool {
+		c == &self.m.modpow(&self.e, &(&self.p * &self.q))
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			format!("The modulus is n = pq = {}.", &self.p * &self.q),
+			"The cipher is c = m^e mod n.".to_owned(),
+			"Use square-and-multiply, reducing mod n after every multiplication.".to_owned(),
+		]
+	}
+}
+
+impl From<RsaEncryptChallenge> for RsaEncryptPublic {
+	fn from(challenge: RsaEncryptChallenge) -> Self {
+		RsaEncryptPublic {
+			p: challenge.p,
+			q: challenge.q,
+			e: challenge.e,
+			d: challenge.d,
+			m: challenge.m,
+		}
+	}
+}
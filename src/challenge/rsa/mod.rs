@@ -0,0 +1,109 @@
+mod encrypt;
+pub use crate::challenge::rsa::encrypt::*;
+
+mod decrypt;
+use std::ops::RangeInclusive;
+
+use num_traits::One;
+use rand::{Rng, RngExt};
+
+pub use crate::challenge::rsa::decrypt::*;
+use crate::{
+	challenge::{self, KeySize},
+	math::{
+		self,
+		big::{self, BigUint},
+	},
+};
+
+/// Public exponent used for generated keys of a given bit size.
+const PUBLIC_EXPONENT: u32 = 65_537;
+
+#[derive(Debug, Clone)]
+pub struct RsaKeyPair {
+	pub p: BigUint,
+	pub q: BigUint,
+	pub e: BigUint,
+	pub d: BigUint,
+}
+
+impl RsaKeyPair {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: impl Into<KeySize>) -> Option<Self> {
+		match size.into() {
+			KeySize::Range(pq_range) => RsaKeyPair::generate_in_range(rng, pq_range),
+			KeySize::Bits(bits) => Some(RsaKeyPair::generate_with_bits(rng, bits)),
+		}
+	}
+
+	pub fn n(&self) -> BigUint {
+		&self.p * &self.q
+	}
+
+	fn generate_in_range<R: Rng + ?Sized>(
+		rng: &mut R,
+		pq_range: RangeInclusive<usize>,
+	) -> Option<Self> {
+		let p = challenge::random_prime(rng, &pq_range)?;
+		let mut q = challenge::random_prime(rng, &pq_range)?;
+
+		while p == q {
+			q = challenge::random_prime(rng, &pq_range)?;
+		}
+
+		let totient = (p - 1) * (q - 1);
+
+		let mut e: u64;
+		let mut maybe_d: Option<u64>;
+
+		loop {
+			e = rng.random_range(1..(totient - 1));
+			maybe_d = math::inverse_mod(e, totient);
+
+			if math::gcd(e, totient) == 1 && maybe_d.is_some_and(|d| d != e) {
+				break;
+			}
+		}
+
+		let key_pair = RsaKeyPair {
+			p: p.into(),
+			q: q.into(),
+			e: e.into(),
+			d: maybe_d?.into(),
+		};
+
+		Some(key_pair)
+	}
+
+	/// Generates a key pair whose modulus is about `bits` bits long, using the
+	/// conventional public exponent.
+	fn generate_with_bits<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> Self {
+		let e = BigUint::from(PUBLIC_EXPONENT);
+
+		loop {
+			let p = big::random_prime(rng, bits / 2);
+			let q = big::random_prime(rng, bits - bits / 2);
+
+			if p == q {
+				continue;
+			}
+
+			let totient = (&p - BigUint::one()) * (&q - BigUint::one());
+
+			if let Some(d) = big::inverse_mod(&e, &totient) {
+				return RsaKeyPair {
+					p,
+					q,
+					e,
+					d,
+				};
+			}
+		}
+	}
+
+	fn random_message<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUint {
+		let n = self.n();
+		let m_min = &n / 2u32;
+
+		big::random_range(rng, &m_min, &n)
+	}
+}
@@ -0,0 +1,141 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::{english, substitution};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubstitutionAttackChallenge {
+	pub key:     String,
+	pub message: String,
+	pub cipher:  String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubstitutionAttackPublic {
+	pub cipher: String,
+}
+
+/// Either the recovered key, listing the cipher letters for A to Z, or the
+/// recovered message. Spaces and punctuation in the message are ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct SubstitutionAttackSubmission {
+	#[validate(
+		length(equal = 26, message = "Key must have 26 letters."),
+		custom(function = "substitution::validate_key")
+	)]
+	pub key: Option<String>,
+
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: Option<String>,
+}
+
+impl SubstitutionAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, message_size: usize) -> Self {
+		let key = substitution::random_key(rng);
+		let message = english::passage(rng, message_size);
+		let cipher = substitution::encrypt(&message, &key);
+
+		SubstitutionAttackChallenge {
+			key,
+			message,
+			cipher,
+		}
+	}
+
+	/// Fraction of the cipher's letters that the submission decrypts
+	/// correctly, or `None` when it holds neither a key nor a message. Key
+	/// letters for plaintext letters that never occur are not counted, and a
+	/// message is compared by edit distance so that a dropped or extra letter
+	/// only costs that letter.
+	pub fn score(&self, submission: &SubstitutionAttackSubmission) -> Option<f64> {
+		let letters = self
+			.message
+			.bytes()
+			.filter(u8::is_ascii_uppercase)
+			.collect::<Vec<_>>();
+
+		let correct = match (&submission.key, &submission.message) {
+			(Some(key), _) => {
+				let key = key.to_uppercase().into_bytes();
+				let answer = self.key.as_bytes();
+
+				letters
+					.iter()
+					.filter(|letter| {
+						let index = (*letter - b'A') as usize;
+						key.get(index) == Some(&answer[index])
+					})
+					.count()
+			},
+			(None, Some(message)) => {
+				// anything past twice the message's length is wrong anyway, and
+				// bounds the edit distance's cost
+				let guess = message
+					.to_uppercase()
+					.bytes()
+					.filter(u8::is_ascii_uppercase)
+					.take(letters.len() * 2)
+					.collect::<Vec<_>>();
+
+				letters
+					.len()
+					.saturating_sub(edit_distance(&guess, &letters))
+			},
+			(None, None) => return None,
+		};
+
+		Some(correct as f64 / letters.len().max(1) as f64)
+	}
+
+	pub fn verify(&self, submission: &SubstitutionAttackSubmission, threshold: f64) -> bool {
+		self.score(submission)
+			.is_some_and(|score| score >= threshold)
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			"Count the cipher letters: the most common ones likely stand for E, T, A and O, and one-letter words are almost always A or I."
+				.to_owned(),
+			format!(
+				"E is written as {} and T as {}.",
+				substitution::encrypt("E", &self.key),
+				substitution::encrypt("T", &self.key)
+			),
+			format!(
+				"THE is written as {} and AND as {}.",
+				substitution::encrypt("THE", &self.key),
+				substitution::encrypt("AND", &self.key)
+			),
+		]
+	}
+}
+
+impl From<SubstitutionAttackChallenge> for SubstitutionAttackPublic {
+	fn from(challenge: SubstitutionAttackChallenge) -> Self {
+		SubstitutionAttackPublic {
+			cipher: challenge.cipher,
+		}
+	}
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+	let mut previous = (0..=b.len()).collect::<Vec<_>>();
+	let mut current = vec![0; b.len() + 1];
+
+	for (i, x) in a.iter().enumerate() {
+		current[0] = i + 1;
+
+		for (j, y) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(x != y);
+			current[j + 1] = substitution
+				.min(previous[j + 1] + 1)
+				.min(current[j] + 1);
+		}
+
+		std::mem::swap(&mut previous, &mut current);
+	}
+
+	previous[b.len()]
+}
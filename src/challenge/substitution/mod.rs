@@ -0,0 +1,157 @@
+mod attack;
+pub use crate::challenge::substitution::attack::*;
+
+use rand::{Rng, seq::SliceRandom};
+use validator::ValidationError;
+
+pub const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Replaces each uppercase letter with the letter at its position in `key`,
+/// which lists the cipher letters for A to Z. Everything else is kept as is.
+pub fn encrypt(message: &str, key: &str) -> String {
+	let key = key.as_bytes();
+
+	message
+		.bytes()
+		.map(|byte| match byte {
+			b'A'..=b'Z' => key[(byte - b'A') as usize] as char,
+			_ => byte as char,
+		})
+		.collect::<String>()
+}
+
+/// Returns `None` when `key` is not a permutation of the alphabet.
+pub fn decrypt(cipher: &str, key: &str) -> Option<String> {
+	Some(encrypt(cipher, &inverse_key(key)?))
+}
+
+/// A key is a permutation of the 26 uppercase letters.
+pub fn is_key(key: &str) -> bool {
+	let mut letters = key.as_bytes().to_vec();
+	letters.sort_unstable();
+
+	letters == ALPHABET.as_bytes()
+}
+
+/// Rejects submitted keys that are not a permutation of the alphabet, so a
+/// key cannot probe one letter at a time.
+pub fn validate_key(key: &str) -> Result<(), ValidationError> {
+	if !is_key(&key.to_uppercase()) {
+		let error = ValidationError::new("key")
+			.with_message("Key must use every letter exactly once.".into());
+
+		return Err(error);
+	}
+
+	Ok(())
+}
+
+/// The key that undoes `key`.
+pub fn inverse_key(key: &str) -> Option<String> {
+	if !is_key(key) {
+		return None;
+	}
+
+	let mut inverse = [b'A'; 26];
+
+	for (plain, cipher) in key.bytes().enumerate() {
+		inverse[(cipher - b'A') as usize] = b'A' + plain as u8;
+	}
+
+	String::from_utf8(inverse.to_vec()).ok()
+}
+
+pub fn random_key<R: Rng + ?Sized>(rng: &mut R) -> String {
+	let mut key = ALPHABET.as_bytes().to_vec();
+	key.shuffle(rng);
+
+	String::from_utf8(key).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use validator::Validate;
+
+	use crate::challenge::substitution::*;
+
+	const KEY: &str = "QWERTYUIOPASDFGHJKLZXCVBNM";
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		let cipher = encrypt("HELLO, WORLD!", KEY);
+
+		assert_eq!("ITSSG, VGKSR!", cipher);
+		assert_eq!(Some("HELLO, WORLD!".to_owned()), decrypt(&cipher, KEY));
+		assert_eq!(None, decrypt(&cipher, "QWERTY"));
+	}
+
+	#[test]
+	fn it_checks_keys() {
+		assert!(is_key(KEY));
+		assert!(is_key(ALPHABET));
+		assert!(is_key(&random_key(&mut rand::rng())));
+		assert!(!is_key("QQERTYUIOPASDFGHJKLZXCVBNM"));
+		assert!(!is_key("qwertyuiopasdfghjklzxcvbnm"));
+	}
+
+	#[test]
+	fn it_inverts_keys() {
+		let inverse = inverse_key(KEY).unwrap();
+
+		assert_eq!(ALPHABET, encrypt(&encrypt(ALPHABET, KEY), &inverse));
+		assert_eq!(Some(KEY.to_owned()), inverse_key(&inverse));
+	}
+
+	#[test]
+	fn it_scores_partial_solutions() {
+		let challenge = SubstitutionAttackChallenge {
+			key:     KEY.to_owned(),
+			message: "HELLO, WORLD!".to_owned(),
+			cipher:  encrypt("HELLO, WORLD!", KEY),
+		};
+
+		let key = |key: &str| SubstitutionAttackSubmission {
+			key: Some(key.to_owned()),
+			..Default::default()
+		};
+		let message = |message: &str| SubstitutionAttackSubmission {
+			message: Some(message.to_owned()),
+			..Default::default()
+		};
+
+		assert_eq!(Some(1.0), challenge.score(&key(KEY)));
+		assert_eq!(
+			Some(0.7),
+			challenge.score(&key("QWERTYUIOPSADFGHJKLZXCVBNM"))
+		);
+		assert_eq!(Some(1.0), challenge.score(&message("hello world")));
+		assert_eq!(Some(0.8), challenge.score(&message("HELLO WOPLE")));
+		assert_eq!(Some(0.9), challenge.score(&message("HELO WORLD")));
+		assert_eq!(Some(0.9), challenge.score(&message("HELLLO WORLD")));
+		assert_eq!(None, challenge.score(&Default::default()));
+
+		assert!(challenge.verify(&message("HELLO WOPLE"), 0.8));
+		assert!(!challenge.verify(&message("HELLO WOPLE"), 0.9));
+	}
+
+	#[test]
+	fn it_rejects_keys_that_are_not_permutations() {
+		let key = |key: &str| SubstitutionAttackSubmission {
+			key: Some(key.to_owned()),
+			..Default::default()
+		};
+
+		assert!(key(KEY).validate().is_ok());
+		assert!(key(&KEY.to_lowercase()).validate().is_ok());
+		assert!(
+			key("QAAAAAAAAAAAAAAAAAAAAAAAAA")
+				.validate()
+				.is_err()
+		);
+		assert!(
+			key("QWERTYUIOPAXDFGHJKLZXCVBNM")
+				.validate()
+				.is_err()
+		);
+	}
+}
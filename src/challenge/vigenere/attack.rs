@@ -0,0 +1,67 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::{english, vigenere};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VigenereAttackChallenge {
+	pub key:     String,
+	pub message: String,
+	pub cipher:  String,
+}
+
+/// Only the cipher is public; the key length has to be recovered from it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VigenereAttackPublic {
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct VigenereAttackSubmission {
+	#[validate(length(min = 1, message = "Key cannot be empty."))]
+	pub key: String,
+}
+
+impl VigenereAttackChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, message_size: usize) -> Self {
+		let key = vigenere::random_key(rng, key_size);
+		let message = english::words(rng, message_size);
+		let cipher = vigenere::encrypt(&message, &key);
+
+		VigenereAttackChallenge {
+			key,
+			message,
+			cipher,
+		}
+	}
+
+	/// Accepts any key that decrypts the cipher to the message, such as one
+	/// period of a key that repeats itself.
+	pub fn verify(&self, key: &str) -> bool {
+		vigenere::is_key(key) && vigenere::decrypt(&self.cipher, key) == self.message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		vec![
+			format!(
+				"The key length divides the distances between repeated sequences in the cipher, and splitting the cipher into that many columns gives each an index of coincidence near English's {:.3}.",
+				english::INDEX_OF_COINCIDENCE
+			),
+			format!("The key is {} letters long.", self.key.len()),
+			format!(
+				"Every {}th letter is a Caesar shift of English, and the first key letter is {}.",
+				self.key.len(),
+				&self.key[..1]
+			),
+		]
+	}
+}
+
+impl From<VigenereAttackChallenge> for VigenereAttackPublic {
+	fn from(challenge: VigenereAttackChallenge) -> Self {
+		VigenereAttackPublic {
+			cipher: challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::vigenere;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VigenereDecryptChallenge {
+	pub key:    String,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VigenereDecryptPublic {
+	pub key:    String,
+	pub cipher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct VigenereDecryptSubmission {
+	#[validate(length(min = 1, message = "Message cannot be empty."))]
+	pub message: String,
+}
+
+impl VigenereDecryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, cipher_size: usize) -> Self {
+		let key = vigenere::random_key(rng, key_size);
+
+		let cipher = Alphabetic
+			.sample_string(rng, cipher_size.max(1))
+			.to_uppercase();
+
+		VigenereDecryptChallenge {
+			key,
+			cipher,
+		}
+	}
+
+	pub fn verify(&self, message: &str) -> bool {
+		vigenere::decrypt(&self.cipher, &self.key) == message
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.cipher[..1];
+		let key = &self.key[..1];
+
+		vec![
+			"Repeat the key under the cipher and shift each letter backward by the key letter below it, counting A as 0."
+				.to_owned(),
+			format!(
+				"The key {} repeats every {} letters.",
+				self.key,
+				self.key.len()
+			),
+			format!(
+				"The first letter, {first}, is shifted back by {key} and becomes {}.",
+				vigenere::decrypt(first, key)
+			),
+		]
+	}
+}
+
+impl From<VigenereDecryptChallenge> for VigenereDecryptPublic {
+	fn from(challenge: VigenereDecryptChallenge) -> Self {
+		VigenereDecryptPublic {
+			key:    challenge.key,
+			cipher: challenge.cipher,
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use rand::{
+	Rng,
+	distr::{Alphabetic, SampleString},
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::challenge::vigenere;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VigenereEncryptChallenge {
+	pub key:     String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VigenereEncryptPublic {
+	pub key:     String,
+	pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct VigenereEncryptSubmission {
+	#[validate(length(min = 1, message = "Cipher cannot be empty."))]
+	pub cipher: String,
+}
+
+impl VigenereEncryptChallenge {
+	pub fn generate<R: Rng + ?Sized>(rng: &mut R, key_size: usize, message_size: usize) -> Self {
+		let key = vigenere::random_key(rng, key_size);
+
+		let message = Alphabetic
+			.sample_string(rng, message_size.max(1))
+			.to_uppercase();
+
+		VigenereEncryptChallenge {
+			key,
+			message,
+		}
+	}
+
+	pub fn verify(&self, cipher: &str) -> bool {
+		vigenere::encrypt(&self.message, &self.key) == cipher
+	}
+
+	pub fn hints(&self) -> Vec<String> {
+		let first = &self.message[..1];
+		let key = &self.key[..1];
+
+		vec![
+			"Repeat the key under the message and shift each letter forward by the key letter below it, counting A as 0."
+				.to_owned(),
+			format!(
+				"The key {} repeats every {} letters.",
+				self.key,
+				self.key.len()
+			),
+			format!(
+				"The first letter, {first}, is shifted by {key} and becomes {}.",
+				vigenere::encrypt(first, key)
+			),
+		]
+	}
+}
+
+impl From<VigenereEncryptChallenge> for VigenereEncryptPublic {
+	fn from(challenge: VigenereEncryptChallenge) -> Self {
+		VigenereEncryptPublic {
+			key:     challenge.key,
+			message: challenge.message,
+		}
+	}
+}
@@ -0,0 +1,175 @@
+mod encrypt;
+pub use crate::challenge::vigenere::encrypt::*;
+
+mod decrypt;
+pub use crate::challenge::vigenere::decrypt::*;
+
+mod attack;
+pub use crate::challenge::vigenere::attack::*;
+
+use rand::{Rng, RngExt};
+
+use crate::challenge::english;
+
+/// Multiples of the key length fit English slightly better than the key
+/// length itself, so the shortest length within this factor of the best fit
+/// is taken.
+const FIT_TOLERANCE: f64 = 2.0;
+
+pub fn encrypt(message: &str, key: &str) -> String {
+	message
+		.bytes()
+		.zip(key.bytes().cycle())
+		.map(|(char, key)| ((char - b'A' + key - b'A') % 26 + b'A') as char)
+		.collect::<String>()
+}
+
+pub fn decrypt(cipher: &str, key: &str) -> String {
+	cipher
+		.bytes()
+		.zip(key.bytes().cycle())
+		.map(|(char, key)| ((char - b'A' + 26 - (key - b'A')) % 26 + b'A') as char)
+		.collect::<String>()
+}
+
+/// Keys are one or more uppercase letters.
+pub fn is_key(key: &str) -> bool {
+	!key.is_empty() && key.bytes().all(|byte| byte.is_ascii_uppercase())
+}
+
+pub fn random_key<R: Rng + ?Sized>(rng: &mut R, key_size: usize) -> String {
+	(0..key_size.max(1))
+		.map(|_| rng.random_range(b'A'..=b'Z') as char)
+		.collect::<String>()
+}
+
+/// Splits `text` into `length` columns, each holding every `length`th letter.
+pub fn columns(text: &str, length: usize) -> Vec<String> {
+	let mut columns = vec![String::new(); length];
+
+	for (i, char) in text.chars().enumerate() {
+		columns[i % length].push(char);
+	}
+
+	columns
+}
+
+/// Returns the distances between repeated trigrams of `cipher`. The key
+/// length divides most of them.
+pub fn kasiski(cipher: &str) -> Vec<usize> {
+	let bytes = cipher.as_bytes();
+	let mut distances = Vec::<usize>::new();
+
+	for (i, trigram) in bytes.windows(3).enumerate() {
+		if let Some(offset) = bytes[i + 1..]
+			.windows(3)
+			.position(|other| other == trigram)
+		{
+			distances.push(offset + 1);
+		}
+	}
+
+	distances
+}
+
+/// Estimates the key length by breaking every column as a Caesar shift for
+/// each length up to `max_length` and comparing how closely the decryptions
+/// fit English. The key length and its multiples fit well, while any other
+/// length leaves unrelated shifts mixed together in each column.
+pub fn key_length(cipher: &str, max_length: usize) -> Option<usize> {
+	let fits = (1..=max_length.min(cipher.len()))
+		.map(|length| {
+			let message = decrypt(cipher, &recover_key(cipher, length));
+			(length, english::chi_squared(&message))
+		})
+		.collect::<Vec<_>>();
+
+	let best = fits
+		.iter()
+		.map(|(_, fit)| *fit)
+		.min_by(f64::total_cmp)?;
+
+	fits.iter()
+		.find(|(_, fit)| *fit <= best * FIT_TOLERANCE)
+		.map(|(length, _)| *length)
+}
+
+/// Recovers each key letter as the shift that makes its column closest to
+/// English letter frequencies.
+pub fn recover_key(cipher: &str, key_length: usize) -> String {
+	columns(cipher, key_length)
+		.iter()
+		.map(|column| {
+			let counts = english::letter_counts(column);
+
+			// shifting back by the key turns each cipher letter count into the
+			// count of the letter it decrypts to
+			let fit = |shift: usize| {
+				let mut shifted = counts;
+				shifted.rotate_left(shift);
+
+				english::chi_squared_counts(&shifted)
+			};
+
+			let shift = (0..26)
+				.min_by(|a, b| fit(*a).total_cmp(&fit(*b)))
+				.unwrap_or_default();
+
+			(b'A' + shift as u8) as char
+		})
+		.collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use crate::challenge::{english, vigenere::*};
+
+	#[test]
+	fn it_encrypts_and_decrypts() {
+		assert_eq!("LXFOPVEFRNHR", encrypt("ATTACKATDAWN", "LEMON"));
+		assert_eq!("ATTACKATDAWN", decrypt("LXFOPVEFRNHR", "LEMON"));
+	}
+
+	#[test]
+	fn it_checks_keys() {
+		assert!(is_key("LEMON"));
+		assert!(!is_key(""));
+		assert!(!is_key("lemon"));
+	}
+
+	#[test]
+	fn it_finds_repeated_distances() {
+		let cipher = encrypt("THECATANDTHEHAT", "ABC");
+		assert!(kasiski(&cipher).contains(&9));
+	}
+
+	#[test]
+	fn it_recovers_keys_from_english() {
+		let mut rng = StdRng::seed_from_u64(3);
+		let message = english::words(&mut rng, 400);
+		let cipher = encrypt(&message, "CIPHER");
+
+		assert_eq!(Some(6), key_length(&cipher, 20));
+		assert_eq!("CIPHER", recover_key(&cipher, 6));
+	}
+
+	#[test]
+	fn it_hints_at_empty_sizes() {
+		let mut rng = StdRng::seed_from_u64(3);
+
+		assert_eq!(
+			3,
+			VigenereEncryptChallenge::generate(&mut rng, 0, 0)
+				.hints()
+				.len()
+		);
+		assert_eq!(
+			3,
+			VigenereDecryptChallenge::generate(&mut rng, 0, 0)
+				.hints()
+				.len()
+		);
+	}
+}
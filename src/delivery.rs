@@ -0,0 +1,173 @@
+use std::{
+	env,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use axum::{
+	body::{Body, Bytes, HttpBody},
+	response::{IntoResponse, Response},
+};
+use http_body::{Frame, SizeHint};
+
+use crate::{error::Error, state::AppState};
+
+/// A challenge whose timer starts when it is first delivered to the user
+/// rather than when it is created.
+pub trait Deliverable: Send + 'static {
+	fn is_delivered(&self) -> bool;
+
+	/// Records the delivery time. Does nothing if the challenge was already
+	/// delivered or has since been completed.
+	fn mark_delivered(self, state: &AppState) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryTiming {
+	/// Start the timer when the handler produces the first response.
+	Handler,
+
+	/// Start the timer once the first response has been fully written out, so
+	/// its transfer does not count against the user.
+	Transfer,
+}
+
+impl DeliveryTiming {
+	pub fn from_env() -> Self {
+		match env::var("DELIVERY_TIMING").ok().as_deref() {
+			Some("transfer") => DeliveryTiming::Transfer,
+			_ => DeliveryTiming::Handler,
+		}
+	}
+}
+
+/// Sends a challenge response, recording the challenge as delivered the first
+/// time it is sent.
+pub async fn deliver<T>(
+	state: &AppState,
+	challenge: T,
+	response: impl IntoResponse,
+) -> Result<Response, Error>
+where
+	T: Deliverable,
+{
+	if challenge.is_delivered() {
+		return Ok(response.into_response());
+	}
+
+	if DeliveryTiming::from_env() == DeliveryTiming::Handler {
+		challenge.mark_delivered(state).await?;
+		return Ok(response.into_response());
+	}
+
+	let state = state.clone();
+
+	let on_sent = Box::new(move || {
+		tokio::spawn(async move {
+			if let Err(err) = challenge.mark_delivered(&state).await {
+				tracing::error!("could not mark challenge delivered: {err}");
+			}
+		});
+	});
+
+	let response = response.into_response().map(|body| {
+		Body::new(OnSent {
+			body,
+			on_sent: Some(on_sent),
+		})
+	});
+
+	Ok(response)
+}
+
+/// Wraps a response body and runs a callback once the inner body has handed
+/// over its last frame. A body dropped before then, such as when the client
+/// disconnects mid-transfer, never runs it.
+struct OnSent {
+	body:    Body,
+	on_sent: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl HttpBody for OnSent {
+	type Data = Bytes;
+	type Error = axum::Error;
+
+	fn poll_frame(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+	) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+		let poll = Pin::new(&mut self.body).poll_frame(cx);
+
+		// hyper stops polling once the body reports its end, so the last data
+		// frame may be the final poll rather than an explicit `None`
+		let finished = match &poll {
+			Poll::Ready(None) => true,
+			Poll::Ready(Some(Ok(_))) => self.body.is_end_stream(),
+			_ => false,
+		};
+
+		if finished && let Some(on_sent) = self.on_sent.take() {
+			on_sent();
+		}
+
+		poll
+	}
+
+	fn is_end_stream(&self) -> bool {
+		self.body.is_end_stream()
+	}
+
+	fn size_hint(&self) -> SizeHint {
+		self.body.size_hint()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		pin::Pin,
+		sync::{
+			Arc,
+			atomic::{AtomicBool, Ordering},
+		},
+		task::{Context, Poll, Waker},
+	};
+
+	use axum::body::{Body, HttpBody};
+
+	use crate::delivery::*;
+
+	fn on_sent(body: &str) -> (OnSent, Arc<AtomicBool>) {
+		let sent = Arc::new(AtomicBool::new(false));
+		let flag = sent.clone();
+
+		let on_sent = OnSent {
+			body:    Body::from(body.to_owned()),
+			on_sent: Some(Box::new(move || flag.store(true, Ordering::SeqCst))),
+		};
+
+		(on_sent, sent)
+	}
+
+	#[test]
+	fn it_runs_the_callback_after_the_last_frame() {
+		let (mut body, sent) = on_sent("challenge");
+		let mut cx = Context::from_waker(Waker::noop());
+
+		assert!(!sent.load(Ordering::SeqCst));
+		assert!(matches!(
+			Pin::new(&mut body).poll_frame(&mut cx),
+			Poll::Ready(Some(Ok(_)))
+		));
+		assert!(sent.load(Ordering::SeqCst));
+	}
+
+	#[test]
+	fn it_skips_the_callback_for_unsent_bodies() {
+		let (body, sent) = on_sent("challenge");
+
+		drop(body);
+
+		assert!(!sent.load(Ordering::SeqCst));
+	}
+}
@@ -8,18 +8,25 @@ use std::{
 use axum::{
 	Error as AxumError,
 	extract::multipart::MultipartError,
-	http::{HeaderValue, StatusCode, header::CONTENT_TYPE},
+	http::{
+		HeaderValue,
+		StatusCode,
+		header::{CONTENT_TYPE, RETRY_AFTER},
+	},
 	response::{IntoResponse, Response},
 };
 use deadpool_diesel::InteractError;
 use diesel::result::Error as DieselError;
 use paper_client::PaperClientError;
 use postcard::Error as PostcardError;
+use serde_json::Error as SerdeJsonError;
+use tokio::task::JoinError;
 
 #[derive(Debug)]
 pub struct Error {
-	code:    StatusCode,
-	message: String,
+	code:        StatusCode,
+	message:     String,
+	retry_after: Option<u64>,
 }
 
 impl Error {
@@ -40,6 +47,12 @@ impl Error {
 		self.set_message(message);
 		self
 	}
+
+	/// Tells the client how many seconds to wait before retrying.
+	pub fn with_retry_after(mut self, seconds: u64) -> Self {
+		self.retry_after = Some(seconds);
+		self
+	}
 }
 
 impl std::error::Error for Error {}
@@ -59,6 +72,12 @@ impl IntoResponse for Error {
 			.headers_mut()
 			.insert(CONTENT_TYPE, HeaderValue::from_static("plain/text"));
 
+		if let Some(seconds) = self.retry_after {
+			response
+				.headers_mut()
+				.insert(RETRY_AFTER, HeaderValue::from(seconds));
+		}
+
 		response
 	}
 }
@@ -123,6 +142,18 @@ impl From<PostcardError> for Error {
 	}
 }
 
+impl From<SerdeJsonError> for Error {
+	fn from(_: SerdeJsonError) -> Self {
+		Error::default()
+	}
+}
+
+impl From<JoinError> for Error {
+	fn from(_: JoinError) -> Self {
+		Error::default()
+	}
+}
+
 impl From<AxumError> for Error {
 	fn from(_: AxumError) -> Self {
 		Error::default()
@@ -140,6 +171,7 @@ impl From<StatusCode> for Error {
 		Error {
 			code,
 			message: get_default_code_message(code).into(),
+			retry_after: None,
 		}
 	}
 }
@@ -0,0 +1,190 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::challenge::{ChallengeKind, Difficulty};
+
+pub const DEFAULT_PER_PAGE: usize = 20;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct HistoryQuery {
+	#[validate(range(min = 1, message = "Page must be at least 1."))]
+	pub page: Option<usize>,
+
+	#[validate(range(min = 1, max = 100, message = "Page size must be between 1 and 100."))]
+	pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryPage {
+	pub page:     usize,
+	pub per_page: usize,
+	pub total:    usize,
+	pub entries:  Vec<HistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+	pub challenge:  ChallengeKind,
+	pub id:         i32,
+	pub parameters: Value,
+	pub attempts:   Vec<HistoryAttempt>,
+	pub hints_used: usize,
+	pub difficulty: Difficulty,
+
+	pub started_at:   DateTime<Utc>,
+	pub completed_at: Option<DateTime<Utc>>,
+	pub abandoned_at: Option<DateTime<Utc>>,
+	pub expired_at:   Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryAttempt {
+	pub payload:      Value,
+	pub correct:      bool,
+	pub submitted_at: DateTime<Utc>,
+}
+
+impl HistoryQuery {
+	pub fn page(&self) -> usize {
+		self.page.unwrap_or(1)
+	}
+
+	pub fn per_page(&self) -> usize {
+		self.per_page.unwrap_or(DEFAULT_PER_PAGE)
+	}
+
+	/// Entries before the requested page.
+	pub fn offset(&self) -> usize {
+		(self.page() - 1).saturating_mul(self.per_page())
+	}
+}
+
+impl HistoryPage {
+	/// Orders the entries newest first and keeps only those on the requested
+	/// page, out of `total` entries overall. Each challenge's entries must
+	/// include at least its newest `offset + per_page`, so that merging them
+	/// leaves the page intact. Attempts are not touched.
+	pub fn new(mut entries: Vec<HistoryEntry>, total: usize, query: &HistoryQuery) -> Self {
+		let page = query.page();
+		let per_page = query.per_page();
+
+		entries.sort_by_key(|entry| Reverse(entry.started_at));
+
+		let entries = entries
+			.into_iter()
+			.skip(query.offset())
+			.take(per_page)
+			.collect();
+
+		HistoryPage {
+			page,
+			per_page,
+			total,
+			entries,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{DateTime, Utc};
+	use serde_json::Value;
+
+	use crate::{
+		challenge::{ChallengeKind, Difficulty},
+		history::*,
+	};
+
+	fn entry(id: i32) -> HistoryEntry {
+		HistoryEntry {
+			challenge: ChallengeKind::CaesarEncrypt,
+			id,
+			parameters: Value::Null,
+			attempts: Vec::new(),
+			hints_used: 0,
+			difficulty: Difficulty::Medium,
+
+			started_at: DateTime::<Utc>::from_timestamp(id as i64, 0).unwrap(),
+			completed_at: None,
+			abandoned_at: None,
+			expired_at: None,
+		}
+	}
+
+	#[test]
+	fn it_paginates_newest_first() {
+		let entries = (1..=5).map(entry).collect::<Vec<_>>();
+
+		let query = HistoryQuery {
+			page:     Some(2),
+			per_page: Some(2),
+		};
+
+		let page = HistoryPage::new(entries, 5, &query);
+		let ids = page
+			.entries
+			.iter()
+			.map(|entry| entry.id)
+			.collect::<Vec<_>>();
+
+		assert_eq!(5, page.total);
+		assert_eq!(vec![3, 2], ids);
+	}
+
+	#[test]
+	fn it_pages_the_newest_rows_of_each_challenge() {
+		let query = HistoryQuery {
+			page:     Some(3),
+			per_page: Some(2),
+		};
+
+		let challenges = [(0..10).step_by(2), (1..10).step_by(2)];
+
+		let all = challenges
+			.clone()
+			.into_iter()
+			.flatten()
+			.map(entry)
+			.collect::<Vec<_>>();
+
+		let newest = challenges
+			.into_iter()
+			.flat_map(|ids| {
+				ids.rev()
+					.take(query.offset() + query.per_page())
+					.map(entry)
+			})
+			.collect::<Vec<_>>();
+
+		let ids = |page: HistoryPage| {
+			page.entries
+				.iter()
+				.map(|entry| entry.id)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(4, query.offset());
+		assert_eq!(vec![5, 4], ids(HistoryPage::new(newest, 10, &query)));
+		assert_eq!(vec![5, 4], ids(HistoryPage::new(all, 10, &query)));
+	}
+
+	#[test]
+	fn it_returns_empty_pages_past_the_end() {
+		let entries = (1..=3).map(entry).collect::<Vec<_>>();
+
+		let query = HistoryQuery {
+			page:     Some(3),
+			per_page: None,
+		};
+
+		let page = HistoryPage::new(entries, 3, &query);
+
+		assert_eq!(3, page.total);
+		assert_eq!(DEFAULT_PER_PAGE, page.per_page);
+		assert!(page.entries.is_empty());
+	}
+}
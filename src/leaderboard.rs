@@ -3,18 +3,21 @@ use std::{
 	time::Duration,
 };
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
 
 #[derive(Default)]
 pub struct Leaderboard {
 	user_map: HashMap<i32, LeaderboardResult>,
 }
 
-#[derive(Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LeaderboardResult {
 	pub username: String,
 
-	#[serde(serialize_with = "serialize_duration")]
+	#[serde(
+		serialize_with = "serialize_duration",
+		deserialize_with = "deserialize_duration"
+	)]
 	pub duration: Duration,
 }
 
@@ -46,6 +49,33 @@ impl Leaderboard {
 	}
 }
 
+pub fn parse_duration(value: &str) -> Option<Duration> {
+	let (number, unit_nanos) = [
+		("ns", 1),
+		("µs", 1_000),
+		("ms", 1_000_000),
+		("s", 1_000_000_000),
+	]
+	.into_iter()
+	.find_map(|(suffix, unit_nanos)| {
+		value
+			.strip_suffix(suffix)
+			.map(|number| (number, unit_nanos))
+	})?;
+
+	let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+	let whole = whole.parse::<u128>().ok()?;
+	let fraction_nanos = if fraction.is_empty() {
+		0
+	} else {
+		fraction.parse::<u128>().ok()? * unit_nanos / 10u128.pow(fraction.len() as u32)
+	};
+
+	let nanos = whole * unit_nanos + fraction_nanos;
+	Some(Duration::from_nanos(nanos.try_into().ok()?))
+}
+
 fn serialize_duration<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
 where
 	S: Serializer,
@@ -53,3 +83,38 @@ where
 	let value = format!("{duration:?}");
 	serializer.serialize_str(&value)
 }
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let value = String::deserialize(deserializer)?;
+	parse_duration(&value).ok_or_else(|| DeError::custom(format!("invalid duration: {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use crate::leaderboard::*;
+
+	#[test]
+	fn it_parses_durations() {
+		assert_eq!(Some(Duration::from_nanos(0)), parse_duration("0ns"));
+		assert_eq!(Some(Duration::from_nanos(1_500)), parse_duration("1.5µs"));
+		assert_eq!(Some(Duration::from_millis(250)), parse_duration("250ms"));
+		assert_eq!(
+			Some(Duration::from_nanos(12_000_000_001)),
+			parse_duration("12.000000001s")
+		);
+		assert_eq!(None, parse_duration("12 minutes"));
+	}
+
+	#[test]
+	fn it_parses_formatted_durations() {
+		for nanos in [0, 7, 1_234, 5_678_912, 3_000_000_007, 98_765_432_109] {
+			let duration = Duration::from_nanos(nanos);
+			assert_eq!(Some(duration), parse_duration(&format!("{duration:?}")));
+		}
+	}
+}
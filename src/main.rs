@@ -1,21 +1,11 @@
-mod error;
-mod leaderboard;
-mod math;
-mod middleware;
-mod models;
-mod routes;
-mod schema;
-mod state;
-
 use std::env;
 
 use axum::Router;
+use ctc_api::{middleware, pool, routes, state::AppState, sweeper};
 use mimalloc::MiMalloc;
 use tokio::net::TcpListener;
 use tower_http::{compression::CompressionLayer, cors::CorsLayer};
 
-use crate::state::AppState;
-
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
@@ -32,6 +22,9 @@ async fn main() -> anyhow::Result<()> {
 
 	let state = AppState::init().await?;
 
+	pool::run(&state);
+	tokio::spawn(sweeper::run(state.clone()));
+
 	let app = Router::new()
 		.merge(routes::guarded_router())
 		.layer(axum::middleware::from_fn_with_state(
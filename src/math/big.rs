@@ -0,0 +1,330 @@
+use std::{fmt, sync::LazyLock};
+
+pub use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use rand::Rng;
+use serde::{
+	Deserializer,
+	Serializer,
+	de::{self, Visitor},
+};
+
+use crate::math;
+
+/// Miller–Rabin bases used above the deterministic u64 range. Candidates are
+/// random, so the chance of a composite passing all of them is negligible.
+const MILLER_RABIN_BASES: [u64; 20] = [
+	2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+];
+
+/// Primes tried as divisors before running Miller–Rabin.
+static TRIAL_DIVISORS: LazyLock<Vec<u64>> = LazyLock::new(|| {
+	(2..2_000)
+		.filter(|n| math::is_prime(*n))
+		.collect()
+});
+
+/// Serializes a number as a decimal string, since JSON numbers cannot hold
+/// more than 53 bits reliably. Use with `#[serde(with = "math::big")]`.
+pub fn serialize<S>(num: &BigUint, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&num.to_str_radix(10))
+}
+
+/// Accepts a decimal string, a `0x` prefixed hexadecimal string or a plain
+/// JSON number.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<BigUint, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	deserializer.deserialize_any(BigUintVisitor)
+}
+
+pub fn parse(value: &str) -> Option<BigUint> {
+	let value = value.trim();
+
+	match value
+		.strip_prefix("0x")
+		.or_else(|| value.strip_prefix("0X"))
+	{
+		Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+		None => BigUint::parse_bytes(value.as_bytes(), 10),
+	}
+}
+
+pub fn is_prime(num: &BigUint) -> bool {
+	if let Some(num) = num.to_u64() {
+		return math::is_prime(num);
+	}
+
+	for divisor in TRIAL_DIVISORS.iter() {
+		if (num % divisor).is_zero() {
+			return false;
+		}
+	}
+
+	let one = BigUint::one();
+	let num_minus_one = num - &one;
+
+	let s = num_minus_one.trailing_zeros().unwrap_or_default();
+	let d = &num_minus_one >> s;
+
+	MILLER_RABIN_BASES.iter().all(|base| {
+		let mut x = BigUint::from(*base).modpow(&d, num);
+
+		if x == one || x == num_minus_one {
+			return true;
+		}
+
+		for _ in 1..s {
+			x = &x * &x % num;
+
+			if x == num_minus_one {
+				return true;
+			}
+		}
+
+		false
+	})
+}
+
+/// Returns a uniformly random number in `[0, bound)`. `bound` must be
+/// positive.
+pub fn random_below<R: Rng + ?Sized>(rng: &mut R, bound: &BigUint) -> BigUint {
+	let bits = bound.bits();
+
+	loop {
+		let num = random_up_to_bits(rng, bits);
+
+		if &num < bound {
+			return num;
+		}
+	}
+}
+
+/// Returns a uniformly random number in `[low, high)`.
+pub fn random_range<R: Rng + ?Sized>(rng: &mut R, low: &BigUint, high: &BigUint) -> BigUint {
+	low + random_below(rng, &(high - low))
+}
+
+/// Returns a random number that is exactly `bits` bits long.
+pub fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> BigUint {
+	let mut num = random_up_to_bits(rng, bits);
+	num.set_bit(bits - 1, true);
+	num
+}
+
+pub fn random_prime<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> BigUint {
+	loop {
+		let mut candidate = random_bits(rng, bits);
+		candidate.set_bit(0, true);
+
+		if is_prime(&candidate) {
+			return candidate;
+		}
+	}
+}
+
+/// Finds primes `p` of `p_bits` bits and `q` of `q_bits` bits with `q`
+/// dividing `p - 1`, and a generator `g` of the subgroup of order `q`.
+pub fn prime_order_group<R: Rng + ?Sized>(
+	rng: &mut R,
+	p_bits: u64,
+	q_bits: u64,
+) -> Option<(BigUint, BigUint, BigUint)> {
+	if q_bits < 2 || q_bits >= p_bits {
+		return None;
+	}
+
+	let one = BigUint::one();
+	let q = random_prime(rng, q_bits);
+
+	// p = kq + 1 has p_bits bits exactly when k is in [k_min, k_max)
+	let k_min = (&one << (p_bits - 1)).div_ceil(&q);
+	let k_max = ((&one << p_bits) - &one) / &q;
+
+	let p = loop {
+		let mut k = random_range(rng, &k_min, &k_max);
+		k.set_bit(0, false);
+
+		let p = &k * &q + &one;
+
+		if p.bits() == p_bits && is_prime(&p) {
+			break p;
+		}
+	};
+
+	let exponent = (&p - &one) / &q;
+
+	let g = (2u32..)
+		.map(|h| BigUint::from(h).modpow(&exponent, &p))
+		.find(|g| g != &one)?;
+
+	Some((p, q, g))
+}
+
+/// Subgroup order size for a prime of `p_bits` bits, following the DSA
+/// parameter sizes.
+pub fn subgroup_bits(p_bits: u64) -> u64 {
+	let q_bits = if p_bits <= 1_024 {
+		160
+	} else if p_bits <= 2_048 {
+		224
+	} else {
+		256
+	};
+
+	q_bits.min(p_bits / 2)
+}
+
+pub fn inverse_mod(num: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+	if modulus <= &BigUint::one() {
+		return None;
+	}
+
+	(num % modulus).modinv(modulus)
+}
+
+/// Computes `(a - b) mod modulus` without underflowing.
+pub fn sub_mod(a: &BigUint, b: &BigUint, modulus: &BigUint) -> BigUint {
+	let a = a % modulus;
+	let b = b % modulus;
+
+	if a >= b { a - b } else { modulus - b + a }
+}
+
+pub fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+	a.gcd(b)
+}
+
+fn random_up_to_bits<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> BigUint {
+	let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+	rng.fill_bytes(&mut bytes);
+
+	let excess = bytes.len() as u64 * 8 - bits;
+
+	if let Some(last) = bytes.last_mut() {
+		*last &= 0xff >> excess;
+	}
+
+	BigUint::from_bytes_le(&bytes)
+}
+
+struct BigUintVisitor;
+
+impl Visitor<'_> for BigUintVisitor {
+	type Value = BigUint;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a non-negative integer or a decimal or hexadecimal string")
+	}
+
+	fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		Ok(BigUint::from(value))
+	}
+
+	fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		u64::try_from(value)
+			.map(BigUint::from)
+			.map_err(|_| E::custom("expected a non-negative integer"))
+	}
+
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		parse(value).ok_or_else(|| E::custom(format!("invalid integer: {value}")))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+	use serde::{Deserialize, Serialize};
+
+	use crate::math::big::*;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper {
+		#[serde(with = "crate::math::big")]
+		num: BigUint,
+	}
+
+	#[test]
+	fn it_checks_large_primes() {
+		let mersenne = (BigUint::one() << 127u32) - BigUint::one();
+		let curve = (BigUint::one() << 255u32) - BigUint::from(19u32);
+
+		assert!(is_prime(&mersenne));
+		assert!(is_prime(&curve));
+		assert!(!is_prime(&(&mersenne * &curve)));
+		assert!(!is_prime(&((BigUint::one() << 128u32) + BigUint::one())));
+	}
+
+	#[test]
+	fn it_generates_primes_of_the_requested_size() {
+		let mut rng = StdRng::seed_from_u64(1);
+
+		for bits in [16, 64, 200] {
+			let prime = random_prime(&mut rng, bits);
+
+			assert_eq!(bits, prime.bits());
+			assert!(is_prime(&prime));
+		}
+	}
+
+	#[test]
+	fn it_generates_prime_order_groups() {
+		let mut rng = StdRng::seed_from_u64(2);
+		let (p, q, g) = prime_order_group(&mut rng, 256, subgroup_bits(256)).unwrap();
+
+		assert_eq!(256, p.bits());
+		assert_eq!(128, q.bits());
+		assert!(((&p - BigUint::one()) % &q).is_zero());
+		assert!(g.modpow(&q, &p).is_one());
+	}
+
+	#[test]
+	fn it_finds_large_inverse_mods() {
+		let modulus = (BigUint::one() << 127u32) - BigUint::one();
+		let num = BigUint::from(123_456_789u64);
+		let inverse = inverse_mod(&num, &modulus).unwrap();
+
+		assert!((num * inverse % modulus).is_one());
+		assert_eq!(
+			None,
+			inverse_mod(&BigUint::from(6u32), &BigUint::from(9u32))
+		);
+	}
+
+	#[test]
+	fn it_subtracts_without_underflow() {
+		let modulus = BigUint::from(13u32);
+
+		assert_eq!(
+			BigUint::from(10u32),
+			sub_mod(&BigUint::from(3u32), &BigUint::from(6u32), &modulus)
+		);
+	}
+
+	#[test]
+	fn it_serializes_as_strings() {
+		let wrapper = Wrapper {
+			num: BigUint::from(255u32),
+		};
+
+		assert_eq!(r#"{"num":"255"}"#, serde_json::to_string(&wrapper).unwrap());
+		assert_eq!(wrapper, serde_json::from_str(r#"{"num":"0xff"}"#).unwrap());
+		assert_eq!(wrapper, serde_json::from_str(r#"{"num":255}"#).unwrap());
+		assert!(serde_json::from_str::<Wrapper>(r#"{"num":-1}"#).is_err());
+	}
+}
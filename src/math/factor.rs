@@ -0,0 +1,281 @@
+use crate::math::{gcd, is_prime, mul_mod, power_mod};
+
+/// Numbers up to this size are factored by trial division alone.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 32;
+
+/// Small primes are divided out before any other method runs.
+const SMALL_FACTOR_BOUND: u64 = 1_000;
+
+const FERMAT_ITERATIONS: u64 = 10_000;
+const P_MINUS_ONE_BOUND: u64 = 10_000;
+
+/// Factors `num` into primes in ascending order. Small numbers use trial
+/// division; larger cofactors try Fermat's method for factors close to the
+/// square root, Pollard's p−1 for factors with a smooth p−1, and fall back to
+/// Pollard's rho.
+pub fn factorize(num: u64) -> Vec<u64> {
+	if num == 0 {
+		return Vec::new();
+	}
+
+	if num <= TRIAL_DIVISION_LIMIT {
+		let (factors, _) = trial_division(num, num.isqrt());
+		return factors;
+	}
+
+	let (mut factors, rest) = trial_division(num, SMALL_FACTOR_BOUND);
+	split(rest, &mut factors);
+
+	factors.sort_unstable();
+	factors
+}
+
+/// Divides out every prime up to `bound`, returning them along with the
+/// remaining cofactor, which is appended to the factors when it is known to be
+/// prime.
+pub fn trial_division(num: u64, bound: u64) -> (Vec<u64>, u64) {
+	let mut factors = Vec::<u64>::new();
+	let mut num = num;
+
+	if num == 0 {
+		return (factors, num);
+	}
+
+	while num & 1 == 0 {
+		factors.push(2);
+		num /= 2;
+	}
+
+	let mut i = 3;
+
+	while i <= bound && i <= num / i {
+		while num.is_multiple_of(i) {
+			factors.push(i);
+			num /= i;
+		}
+
+		i += 2;
+	}
+
+	if num > 1 && num / i < i {
+		factors.push(num);
+		num = 1;
+	}
+
+	(factors, num)
+}
+
+/// Searches for `num = a² - b²` starting from `a = ⌈√num⌉`, which quickly
+/// finds two factors that are close together. `num` must be odd.
+pub fn fermat(num: u64, iterations: u64) -> Option<u64> {
+	if num & 1 == 0 {
+		return None;
+	}
+
+	let num = num as u128;
+	let mut a = num.isqrt();
+
+	if a * a < num {
+		a += 1;
+	}
+
+	for _ in 0..iterations {
+		let b_squared = a * a - num;
+		let b = b_squared.isqrt();
+
+		if b * b == b_squared {
+			let factor = (a - b) as u64;
+			return (factor > 1).then_some(factor);
+		}
+
+		a += 1;
+	}
+
+	None
+}
+
+/// Finds a factor `p` of `num` for which every prime power dividing `p - 1`
+/// is at most `bound`.
+pub fn pollard_p_minus_one(num: u64, bound: u64) -> Option<u64> {
+	if num < 4 {
+		return None;
+	}
+
+	let mut a = 2;
+
+	for exponent in 2..=bound {
+		a = power_mod(a, exponent, num);
+
+		if exponent.is_multiple_of(64) || exponent == bound {
+			match gcd(a.checked_sub(1).unwrap_or(num - 1), num) {
+				1 => {},
+				factor if factor == num => return None,
+				factor => return Some(factor),
+			}
+		}
+	}
+
+	None
+}
+
+/// Finds a non-trivial factor of a composite `num` with Brent's variant of
+/// Pollard's rho, retrying with a new polynomial whenever a cycle yields
+/// `num` itself.
+pub fn pollard_rho(num: u64) -> Option<u64> {
+	if num < 4 || is_prime(num) {
+		return None;
+	}
+
+	if num & 1 == 0 {
+		return Some(2);
+	}
+
+	(1..num).find_map(|c| brent(num, c))
+}
+
+fn brent(num: u64, c: u64) -> Option<u64> {
+	const BATCH: u64 = 128;
+
+	let step = |x: u64| ((mul_mod(x, x, num) as u128 + c as u128) % num as u128) as u64;
+
+	let mut y = 2;
+	let mut x = y;
+	let mut saved = y;
+	let mut product = 1;
+	let mut factor = 1;
+	let mut length = 1;
+
+	while factor == 1 {
+		x = y;
+
+		for _ in 0..length {
+			y = step(y);
+		}
+
+		let mut k = 0;
+
+		while k < length && factor == 1 {
+			saved = y;
+
+			for _ in 0..BATCH.min(length - k) {
+				y = step(y);
+				product = mul_mod(product, x.abs_diff(y), num);
+			}
+
+			factor = gcd(product, num);
+			k += BATCH;
+		}
+
+		length *= 2;
+	}
+
+	if factor == num {
+		// the batch overshot; step through it one value at a time
+		loop {
+			saved = step(saved);
+			factor = gcd(x.abs_diff(saved), num);
+
+			if factor > 1 {
+				break;
+			}
+		}
+	}
+
+	(factor != num).then_some(factor)
+}
+
+fn split(num: u64, factors: &mut Vec<u64>) {
+	if num == 1 {
+		return;
+	}
+
+	if is_prime(num) {
+		factors.push(num);
+		return;
+	}
+
+	let factor = fermat(num, FERMAT_ITERATIONS)
+		.or_else(|| pollard_p_minus_one(num, P_MINUS_ONE_BOUND))
+		.or_else(|| pollard_rho(num));
+
+	match factor {
+		Some(factor) => {
+			split(factor, factors);
+			split(num / factor, factors);
+		},
+
+		// unreachable for composites, but keep the number rather than lose it
+		None => factors.push(num),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use crate::math::{factor::*, is_prime};
+
+	const CLOSE_PRIMES: (u64, u64) = (4_294_967_279, 4_294_967_291);
+	const SMOOTH_PRIME: u64 = 106_696_591;
+	const SAFE_PRIME: u64 = 2_147_483_783;
+	const UNRELATED_PRIMES: (u64, u64) = (1_068_840_287, 16_153_210_693);
+
+	#[test]
+	fn it_trial_divides_up_to_a_bound() {
+		assert_eq!((vec![2, 2, 5, 5, 5], 1), trial_division(500, 500));
+		assert_eq!(
+			(vec![3], 1_543 * 1_549),
+			trial_division(3 * 1_543 * 1_549, 100)
+		);
+	}
+
+	#[test]
+	fn it_finds_close_factors_with_fermat() {
+		let (p, q) = CLOSE_PRIMES;
+		assert_eq!(Some(p), fermat(p * q, 10));
+		assert_eq!(None, fermat(SMOOTH_PRIME * SAFE_PRIME, 10));
+	}
+
+	#[test]
+	fn it_finds_smooth_factors_with_p_minus_one() {
+		assert_eq!(
+			Some(SMOOTH_PRIME),
+			pollard_p_minus_one(SMOOTH_PRIME * SAFE_PRIME, 100)
+		);
+	}
+
+	#[test]
+	fn it_finds_factors_with_rho() {
+		let (p, q) = UNRELATED_PRIMES;
+		let factor = pollard_rho(p * q).unwrap();
+
+		assert!(factor == p || factor == q);
+		assert_eq!(None, pollard_rho(SAFE_PRIME));
+	}
+
+	#[test]
+	fn it_factorizes_large_semiprimes() {
+		let (p, q) = UNRELATED_PRIMES;
+		assert_eq!(vec![p, q], factorize(p * q));
+
+		let (p, q) = CLOSE_PRIMES;
+		assert_eq!(vec![p, q], factorize(p * q));
+
+		assert_eq!(vec![SAFE_PRIME], factorize(SAFE_PRIME));
+		assert_eq!(
+			vec![2, 2, 3, SMOOTH_PRIME, SAFE_PRIME],
+			factorize(12 * SMOOTH_PRIME * SAFE_PRIME)
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn it_factorizes_any_number(num in 1u64..) {
+			let factors = factorize(num);
+
+			prop_assert_eq!(num, factors.iter().product::<u64>());
+			prop_assert!(factors.iter().all(|factor| is_prime(*factor)));
+			prop_assert!(factors.is_sorted());
+		}
+	}
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use crate::math::{inverse_mod, mul_mod, order, power_mod, prime_factors};
+
+/// Prime subgroup orders up to this size are solved with baby-step
+/// giant-step; larger ones use Pollard's rho to avoid the lookup table.
+const BABY_STEP_LIMIT: u64 = 1 << 32;
+
+/// Walks started by Pollard's rho before giving up.
+const RHO_ATTEMPTS: u64 = 32;
+
+/// Finds the smallest `x` with `g^x ≡ h (mod p)`, reducing the problem to the
+/// prime-power subgroups of `g`'s order with Pohlig–Hellman.
+pub fn discrete_log(g: u64, h: u64, p: u64) -> Option<u64> {
+	pohlig_hellman(g, h, p)
+}
+
+/// Finds `x` in `[0, n)` with `g^x ≡ h (mod p)`, where `n` bounds the order of
+/// `g`, using about √n multiplications and √n stored values.
+pub fn baby_step_giant_step(g: u64, h: u64, p: u64, n: u64) -> Option<u64> {
+	if p < 2 || n == 0 {
+		return None;
+	}
+
+	let m = n.isqrt() + u64::from(n.isqrt().pow(2) < n);
+	let mut baby_steps = HashMap::<u64, u64>::with_capacity(m as usize);
+	let mut value = 1 % p;
+
+	for j in 0..m {
+		baby_steps.entry(value).or_insert(j);
+		value = mul_mod(value, g, p);
+	}
+
+	let giant_step = power_mod(inverse_mod(g, p)?, m, p);
+	let mut gamma = h % p;
+
+	for i in 0..m {
+		if let Some(j) = baby_steps.get(&gamma) {
+			let x = i * m + j;
+			return (x < n).then_some(x);
+		}
+
+		gamma = mul_mod(gamma, giant_step, p);
+	}
+
+	None
+}
+
+/// Finds `x` in `[0, n)` with `g^x ≡ h (mod p)` using Pollard's rho with
+/// Floyd's cycle detection. `n` must be the prime order of `g`.
+pub fn pollard_rho_log(g: u64, h: u64, p: u64, n: u64) -> Option<u64> {
+	if p < 2 || n == 0 {
+		return None;
+	}
+
+	let h = h % p;
+
+	if h == 1 % p {
+		return Some(0);
+	}
+
+	// partitions the group into three sets by residue, each with its own
+	// update to x = g^a·h^b
+	let step = |(x, a, b): (u64, u64, u64)| match x % 3 {
+		0 => (mul_mod(x, x, p), mul_mod(a, 2, n), mul_mod(b, 2, n)),
+		1 => (mul_mod(x, g, p), (a + 1) % n, b),
+		_ => (mul_mod(x, h, p), a, (b + 1) % n),
+	};
+
+	for attempt in 0..RHO_ATTEMPTS {
+		let a = attempt % n;
+		let b = 1 % n;
+		let start = (mul_mod(power_mod(g, a, p), h, p), a, b);
+
+		let mut tortoise = step(start);
+		let mut hare = step(step(start));
+
+		while tortoise.0 != hare.0 {
+			tortoise = step(tortoise);
+			hare = step(step(hare));
+		}
+
+		let (_, a_tortoise, b_tortoise) = tortoise;
+		let (_, a_hare, b_hare) = hare;
+
+		// g^(a_t - a_h) = h^(b_h - b_t), so x = (a_t - a_h) / (b_h - b_t)
+		let numerator = (a_tortoise + n - a_hare) % n;
+		let denominator = (b_hare + n - b_tortoise) % n;
+
+		let Some(inverse) = inverse_mod(denominator, n) else {
+			continue;
+		};
+
+		let x = mul_mod(numerator, inverse, n);
+
+		if power_mod(g, x, p) == h {
+			return Some(x);
+		}
+	}
+
+	None
+}
+
+/// Splits the order of `g` into prime powers, solves the logarithm in each
+/// subgroup one digit at a time and combines the results with the Chinese
+/// remainder theorem.
+pub fn pohlig_hellman(g: u64, h: u64, p: u64) -> Option<u64> {
+	let n = order(g, p)?;
+	let h = h % p;
+
+	let mut residues = Vec::<(u64, u64)>::new();
+
+	for (q, e) in prime_powers(prime_factors(n)) {
+		// gamma generates the subgroup of order q
+		let gamma = power_mod(g, n / q, p);
+		let g_inverse = inverse_mod(g, p)?;
+
+		let mut x = 0;
+		let mut q_power = 1;
+
+		for k in 0..e {
+			let shifted = mul_mod(power_mod(g_inverse, x, p), h, p);
+			let h_k = power_mod(shifted, n / (q_power * q), p);
+
+			let digit = if q <= BABY_STEP_LIMIT {
+				baby_step_giant_step(gamma, h_k, p, q)?
+			} else {
+				pollard_rho_log(gamma, h_k, p, q)?
+			};
+
+			x += digit * q_power;
+
+			if k + 1 < e {
+				q_power *= q;
+			}
+		}
+
+		residues.push((x, q_power * q));
+	}
+
+	let (x, _) = crt(&residues)?;
+
+	(power_mod(g, x, p) == h).then_some(x)
+}
+
+/// Combines `x ≡ r (mod m)` for each `(r, m)` pair with pairwise coprime
+/// moduli into a single residue and modulus.
+pub fn crt(residues: &[(u64, u64)]) -> Option<(u64, u64)> {
+	residues
+		.iter()
+		.try_fold((0u64, 1u64), |(x, modulus), (r, m)| {
+			let inverse = inverse_mod(modulus % m, *m).or((*m == 1).then_some(0))?;
+			let difference = (r % m + m - x % m) % m;
+			let t = mul_mod(difference, inverse, *m);
+
+			let combined = modulus.checked_mul(*m)?;
+			let x = (x as u128 + modulus as u128 * t as u128) % combined as u128;
+
+			Some((x as u64, combined))
+		})
+}
+
+fn prime_powers(factors: Vec<u64>) -> Vec<(u64, u32)> {
+	let mut powers = Vec::<(u64, u32)>::new();
+
+	for factor in factors {
+		match powers.last_mut() {
+			Some((prime, exponent)) if *prime == factor => *exponent += 1,
+			_ => powers.push((factor, 1)),
+		}
+	}
+
+	powers
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use crate::math::{is_prime, log::*, order, power_mod, primitive};
+
+	const SAFE_PRIME: u64 = 2_147_483_783;
+	const SMOOTH_PRIME: u64 = 106_696_591;
+
+	#[test]
+	fn it_finds_logs_with_baby_step_giant_step() {
+		assert_eq!(Some(6), baby_step_giant_step(2, 9, 11, 10));
+		assert_eq!(Some(0), baby_step_giant_step(2, 1, 11, 10));
+		assert_eq!(None, baby_step_giant_step(4, 2, 11, 10));
+	}
+
+	#[test]
+	fn it_finds_logs_with_pollard_rho() {
+		// squares mod a safe prime form the subgroup of order (p - 1) / 2
+		let q = (SAFE_PRIME - 1) / 2;
+		let x = 987_654_321;
+		let h = power_mod(4, x, SAFE_PRIME);
+
+		assert_eq!(Some(x), pollard_rho_log(4, h, SAFE_PRIME, q));
+	}
+
+	#[test]
+	fn it_finds_logs_with_pohlig_hellman() {
+		let g = primitive(SMOOTH_PRIME).unwrap();
+		let x = 12_345_678;
+		let h = power_mod(g, x, SMOOTH_PRIME);
+
+		assert_eq!(Some(x), pohlig_hellman(g, h, SMOOTH_PRIME));
+		assert_eq!(Some(x), discrete_log(g, h, SMOOTH_PRIME));
+	}
+
+	#[test]
+	fn it_combines_residues() {
+		assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+		assert_eq!(Some((0, 1)), crt(&[]));
+	}
+
+	proptest! {
+		#[test]
+		fn it_recovers_exponents(start in 3u64..1_000_000, g in 2u64.., x in 0u64..) {
+			let p = (start..).find(|num| is_prime(*num)).unwrap();
+			prop_assume!(g % p > 1);
+
+			let h = power_mod(g, x, p);
+			let n = order(g, p).unwrap();
+
+			prop_assert_eq!(Some(x % n), discrete_log(g, h, p));
+			prop_assert_eq!(Some(x % n), baby_step_giant_step(g, h, p, n));
+		}
+	}
+}
@@ -0,0 +1,167 @@
+use crate::math::{gcd, inverse_mod, mul_mod};
+
+/// A square matrix of residues, stored row by row.
+pub type Matrix = Vec<Vec<u64>>;
+
+/// Multiplies `a` by `b`, reducing every entry mod `modulus`. `b` may have any
+/// number of columns, so column vectors are multiplied as one-column matrices.
+pub fn mul_matrix_mod(a: &[Vec<u64>], b: &[Vec<u64>], modulus: u64) -> Matrix {
+	let columns = b.first().map_or(0, Vec::len);
+
+	a.iter()
+		.map(|row| {
+			(0..columns)
+				.map(|column| {
+					row.iter().zip(b).fold(0, |sum, (entry, b_row)| {
+						(sum + mul_mod(*entry, b_row[column], modulus)) % modulus
+					})
+				})
+				.collect()
+		})
+		.collect()
+}
+
+/// Finds the determinant by cofactor expansion along the first row, which
+/// avoids division and so works for composite moduli.
+pub fn determinant_mod(matrix: &[Vec<u64>], modulus: u64) -> u64 {
+	match matrix.len() {
+		0 => 1 % modulus,
+		1 => matrix[0][0] % modulus,
+		_ => (0..matrix.len()).fold(0, |sum, column| {
+			let term = mul_mod(
+				matrix[0][column],
+				determinant_mod(&minor(matrix, 0, column), modulus),
+				modulus,
+			);
+
+			match column % 2 {
+				0 => (sum + term) % modulus,
+				_ => (sum + modulus - term) % modulus,
+			}
+		}),
+	}
+}
+
+/// A matrix is invertible mod `modulus` exactly when its determinant is
+/// coprime to the modulus.
+pub fn is_invertible_mod(matrix: &[Vec<u64>], modulus: u64) -> bool {
+	modulus > 1 && gcd(determinant_mod(matrix, modulus), modulus) == 1
+}
+
+/// Inverts `matrix` as the inverse of its determinant times its adjugate.
+pub fn inverse_matrix_mod(matrix: &[Vec<u64>], modulus: u64) -> Option<Matrix> {
+	let inverse_determinant = inverse_mod(determinant_mod(matrix, modulus), modulus)?;
+	let size = matrix.len();
+
+	let inverse = (0..size)
+		.map(|row| {
+			(0..size)
+				.map(|column| {
+					// the adjugate is the transposed matrix of cofactors
+					let cofactor = determinant_mod(&minor(matrix, column, row), modulus);
+					let cofactor = match (row + column) % 2 {
+						0 => cofactor,
+						_ => (modulus - cofactor) % modulus,
+					};
+
+					mul_mod(cofactor, inverse_determinant, modulus)
+				})
+				.collect()
+		})
+		.collect();
+
+	Some(inverse)
+}
+
+/// Removes `row` and `column` from `matrix`.
+fn minor(matrix: &[Vec<u64>], row: usize, column: usize) -> Matrix {
+	matrix
+		.iter()
+		.enumerate()
+		.filter(|(index, _)| *index != row)
+		.map(|(_, entries)| {
+			entries
+				.iter()
+				.enumerate()
+				.filter(|(index, _)| *index != column)
+				.map(|(_, entry)| *entry)
+				.collect()
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use crate::math::matrix::*;
+
+	fn identity(size: usize) -> Matrix {
+		(0..size)
+			.map(|row| {
+				(0..size)
+					.map(|column| u64::from(row == column))
+					.collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn it_finds_determinants() {
+		assert_eq!(1, determinant_mod(&[], 26));
+		assert_eq!(9, determinant_mod(&[vec![3, 3], vec![2, 5]], 26));
+		assert_eq!(
+			25,
+			determinant_mod(&[vec![6, 24, 1], vec![13, 16, 10], vec![20, 17, 15]], 26)
+		);
+	}
+
+	#[test]
+	fn it_inverts_matrices() {
+		assert_eq!(
+			Some(vec![vec![15, 17], vec![20, 9]]),
+			inverse_matrix_mod(&[vec![3, 3], vec![2, 5]], 26)
+		);
+		assert_eq!(
+			Some(vec![vec![8, 5, 10], vec![21, 8, 21], vec![21, 12, 8]]),
+			inverse_matrix_mod(&[vec![6, 24, 1], vec![13, 16, 10], vec![20, 17, 15]], 26)
+		);
+		assert_eq!(None, inverse_matrix_mod(&[vec![2, 4], vec![6, 8]], 26));
+		assert!(!is_invertible_mod(&[vec![2, 4], vec![6, 8]], 26));
+	}
+
+	#[test]
+	fn it_multiplies_matrices() {
+		assert_eq!(
+			vec![vec![15], vec![14], vec![7]],
+			mul_matrix_mod(
+				&[vec![6, 24, 1], vec![13, 16, 10], vec![20, 17, 15]],
+				&[vec![0], vec![2], vec![19]],
+				26
+			)
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn it_multiplies_inverses_to_the_identity(
+			entries in prop::collection::vec(0u64..26, 9),
+			size in 1usize..=3,
+		) {
+			let matrix = entries
+				.chunks(3)
+				.take(size)
+				.map(|row| row[..size].to_vec())
+				.collect::<Vec<_>>();
+
+			match inverse_matrix_mod(&matrix, 26) {
+				Some(inverse) => {
+					prop_assert!(is_invertible_mod(&matrix, 26));
+					prop_assert_eq!(identity(size), mul_matrix_mod(&matrix, &inverse, 26));
+					prop_assert_eq!(identity(size), mul_matrix_mod(&inverse, &matrix, 26));
+				},
+				None => prop_assert!(!is_invertible_mod(&matrix, 26)),
+			}
+		}
+	}
+}
@@ -0,0 +1,345 @@
+pub mod big;
+pub mod factor;
+pub mod log;
+pub mod matrix;
+
+use num_traits::AsPrimitive;
+
+/// Bases that make Miller–Rabin deterministic for every u64.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub fn safe_mod(num: impl AsPrimitive<i64>, modulus: impl AsPrimitive<i64>) -> u64 {
+	let num = num.as_();
+	let modulus = modulus.as_();
+
+	num.rem_euclid(modulus) as u64
+}
+
+/// Multiplies in u128 so the product cannot overflow before it is reduced.
+pub fn mul_mod(
+	a: impl AsPrimitive<u64>,
+	b: impl AsPrimitive<u64>,
+	modulus: impl AsPrimitive<u64>,
+) -> u64 {
+	let a = a.as_() as u128;
+	let b = b.as_() as u128;
+	let modulus = modulus.as_() as u128;
+
+	(a * b % modulus) as u64
+}
+
+pub fn power_mod(
+	num: impl AsPrimitive<u64>,
+	exp: impl AsPrimitive<u64>,
+	modulus: impl AsPrimitive<u64>,
+) -> u64 {
+	let mut exp = exp.as_();
+	let modulus = modulus.as_();
+
+	let mut result = 1 % modulus;
+	let mut num = num.as_() % modulus;
+
+	while exp > 0 {
+		if exp & 1 > 0 {
+			result = mul_mod(result, num, modulus);
+		}
+
+		exp >>= 1;
+		num = mul_mod(num, num, modulus);
+	}
+
+	result
+}
+
+pub fn is_prime(num: impl AsPrimitive<u64>) -> bool {
+	let num = num.as_();
+
+	if num < 2 {
+		return false;
+	}
+
+	for base in MILLER_RABIN_BASES {
+		if num % base == 0 {
+			return num == base;
+		}
+	}
+
+	let mut d = num - 1;
+	let mut s = 0;
+
+	while d & 1 == 0 {
+		d >>= 1;
+		s += 1;
+	}
+
+	MILLER_RABIN_BASES.iter().all(|base| {
+		let mut x = power_mod(*base, d, num);
+
+		if x == 1 || x == num - 1 {
+			return true;
+		}
+
+		for _ in 1..s {
+			x = mul_mod(x, x, num);
+
+			if x == num - 1 {
+				return true;
+			}
+		}
+
+		false
+	})
+}
+
+/// Returns the prime factors of `num` in ascending order. See
+/// [`factor::factorize`] for how the method is chosen.
+pub fn prime_factors(num: impl AsPrimitive<u64>) -> Vec<u64> {
+	factor::factorize(num.as_())
+}
+
+/// Returns the smallest primitive root of the prime `num`.
+pub fn primitive(num: impl AsPrimitive<u64>) -> Option<u64> {
+	let num = num.as_();
+
+	if !is_prime(num) {
+		return None;
+	}
+
+	if num == 2 {
+		return Some(1);
+	}
+
+	let phi = num - 1;
+	let factors = distinct(prime_factors(phi));
+
+	(2..num).find(|i| {
+		factors
+			.iter()
+			.all(|factor| power_mod(*i, phi / factor, num) != 1)
+	})
+}
+
+pub fn gcd(a: impl AsPrimitive<u64>, b: impl AsPrimitive<u64>) -> u64 {
+	let mut a = a.as_();
+	let mut b = b.as_();
+
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+
+	a
+}
+
+/// Finds the inverse with the extended Euclidean algorithm.
+pub fn inverse_mod(num: impl AsPrimitive<u64>, modulus: impl AsPrimitive<u64>) -> Option<u64> {
+	let modulus = modulus.as_();
+
+	if modulus < 2 {
+		return None;
+	}
+
+	let (mut r, mut next_r) = (modulus as i128, (num.as_() % modulus) as i128);
+	let (mut t, mut next_t) = (0i128, 1i128);
+
+	while next_r != 0 {
+		let quotient = r / next_r;
+
+		(r, next_r) = (next_r, r - quotient * next_r);
+		(t, next_t) = (next_t, t - quotient * next_t);
+	}
+
+	if r != 1 {
+		return None;
+	}
+
+	Some(t.rem_euclid(modulus as i128) as u64)
+}
+
+/// Returns the multiplicative order of `num` mod `p`, found by dividing
+/// Euler's totient of `p` down by its prime factors.
+pub fn order(num: impl AsPrimitive<u64>, p: impl AsPrimitive<u64>) -> Option<u64> {
+	let num = num.as_();
+	let p = p.as_();
+
+	if p < 2 || gcd(num, p) != 1 {
+		return None;
+	}
+
+	let mut order = totient(p);
+
+	for factor in distinct(prime_factors(order)) {
+		while order % factor == 0 && power_mod(num, order / factor, p) == 1 {
+			order /= factor;
+		}
+	}
+
+	Some(order)
+}
+
+pub fn totient(num: impl AsPrimitive<u64>) -> u64 {
+	let num = num.as_();
+
+	distinct(prime_factors(num))
+		.into_iter()
+		.fold(num, |phi, factor| phi / factor * (factor - 1))
+}
+
+fn distinct(mut factors: Vec<u64>) -> Vec<u64> {
+	factors.dedup();
+	factors
+}
+
+#[cfg(test)]
+mod tests {
+	use primal_sieve::Sieve;
+	use proptest::prelude::*;
+
+	use crate::math::*;
+
+	const LARGE_PRIME: u64 = 18_446_744_073_709_551_557;
+
+	fn naive_is_prime(num: u64) -> bool {
+		Sieve::new(num as usize + 1).is_prime(num as usize)
+	}
+
+	fn naive_inverse_mod(num: u64, modulus: u64) -> Option<u64> {
+		(1..modulus).find(|i| (num % modulus) * i % modulus == 1)
+	}
+
+	fn naive_order(num: u64, p: u64) -> Option<u64> {
+		(1..=p).find(|i| power_mod(num, *i, p) == 1)
+	}
+
+	fn naive_power_mod(num: u64, exp: u64, modulus: u64) -> u64 {
+		(0..exp).fold(1 % modulus, |result, _| result * num % modulus)
+	}
+
+	#[test]
+	fn it_calculates_safe_mods() {
+		assert_eq!(5, safe_mod(125, 20));
+		assert_eq!(8, safe_mod(600, 37));
+		assert_eq!(10, safe_mod(-523, 13));
+	}
+
+	#[test]
+	fn it_calculates_power_mods() {
+		assert_eq!(101, power_mod(8, 20, 125));
+		assert_eq!(12, power_mod(3, 5, 21));
+		assert_eq!(68, power_mod(18, 325, 500));
+	}
+
+	#[test]
+	fn it_checks_prime_numbers() {
+		assert!(is_prime(5));
+		assert!(is_prime(1543));
+		assert!(is_prime(7477));
+		assert!(!is_prime(125));
+		assert!(!is_prime(620));
+	}
+
+	#[test]
+	fn it_finds_prime_factors() {
+		assert_eq!(vec![2, 2, 5, 5, 5], prime_factors(500));
+		assert_eq!(vec![3, 41], prime_factors(123));
+		assert_eq!(vec![7, 103], prime_factors(721));
+		assert_eq!(vec![1543], prime_factors(1543));
+	}
+
+	#[test]
+	fn it_reduces_empty_powers() {
+		assert_eq!(0, power_mod(7, 0, 1));
+		assert_eq!(1, power_mod(7, 0, 2));
+		assert_eq!(0, power_mod(0, 5, 1));
+	}
+
+	#[test]
+	fn it_finds_primitives() {
+		assert_eq!(Some(2), primitive(5));
+		assert_eq!(Some(5), primitive(1543));
+		assert_eq!(Some(2), primitive(7477));
+		assert_eq!(None, primitive(125));
+		assert_eq!(None, primitive(620));
+	}
+
+	#[test]
+	fn it_finds_gcds() {
+		assert_eq!(5, gcd(20, 125));
+		assert_eq!(25, gcd(600, 325));
+		assert_eq!(1, gcd(27, 1543));
+	}
+
+	#[test]
+	fn it_finds_inverse_mods() {
+		assert_eq!(Some(4), inverse_mod(16, 7));
+		assert_eq!(Some(13), inverse_mod(225, 17));
+		assert_eq!(Some(10), inverse_mod(1543, 37));
+	}
+
+	#[test]
+	fn it_calculates_orders() {
+		assert_eq!(Some(3), order(16, 7));
+		assert_eq!(Some(4), order(225, 17));
+		assert_eq!(Some(3), order(1543, 37));
+	}
+
+	#[test]
+	fn it_does_not_overflow_large_moduli() {
+		assert_eq!(1, mul_mod(LARGE_PRIME - 1, LARGE_PRIME - 1, LARGE_PRIME));
+		assert_eq!(1, power_mod(2, LARGE_PRIME - 1, LARGE_PRIME));
+		assert!(is_prime(LARGE_PRIME));
+		assert!(!is_prime(LARGE_PRIME - 2));
+
+		let inverse = inverse_mod(LARGE_PRIME - 2, LARGE_PRIME).unwrap();
+		assert_eq!(1, mul_mod(inverse, LARGE_PRIME - 2, LARGE_PRIME));
+	}
+
+	#[test]
+	fn it_calculates_totients() {
+		assert_eq!(1, totient(1));
+		assert_eq!(6, totient(7));
+		assert_eq!(200, totient(500));
+		assert_eq!(1542, totient(1543));
+	}
+
+	proptest! {
+		#[test]
+		fn it_matches_naive_power_mods(num in 0u64..1_000, exp in 0u64..200, modulus in 1u64..1_000) {
+			prop_assert_eq!(naive_power_mod(num, exp, modulus), power_mod(num, exp, modulus));
+		}
+
+		#[test]
+		fn it_matches_naive_primality(num in 0u64..20_000) {
+			prop_assert_eq!(naive_is_prime(num), is_prime(num));
+		}
+
+		#[test]
+		fn it_matches_naive_inverse_mods(num in 0u64..2_000, modulus in 1u64..2_000) {
+			prop_assert_eq!(naive_inverse_mod(num, modulus), inverse_mod(num, modulus));
+		}
+
+		#[test]
+		fn it_matches_naive_orders(num in 0u64..2_000, p in 1u64..2_000) {
+			prop_assert_eq!(naive_order(num, p), order(num, p));
+		}
+
+		#[test]
+		fn it_multiplies_prime_factors_back(num in 1u64..1_000_000) {
+			let factors = prime_factors(num);
+
+			prop_assert_eq!(num, factors.iter().product::<u64>());
+			prop_assert!(factors.iter().all(|factor| is_prime(*factor)));
+		}
+
+		#[test]
+		fn it_finds_primitive_roots(num in 2u64..2_000) {
+			let root = primitive(num);
+
+			prop_assert_eq!(is_prime(num), root.is_some());
+
+			if let Some(root) = root {
+				prop_assert_eq!(Some(num - 1), naive_order(root, num));
+				prop_assert!((1..root).all(|i| naive_order(i, num) != Some(num - 1)));
+			}
+		}
+	}
+}
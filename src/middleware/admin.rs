@@ -0,0 +1,21 @@
+use axum::{
+	body::Body,
+	extract::Extension,
+	http::{Request, StatusCode},
+	middleware::Next,
+	response::Response,
+};
+
+use crate::{error::Error, models::user::User};
+
+pub async fn admin(
+	Extension(user): Extension<User>,
+	req: Request<Body>,
+	next: Next,
+) -> Result<Response<Body>, Error> {
+	if !user.is_admin {
+		return Err(StatusCode::FORBIDDEN.into());
+	}
+
+	Ok(next.run(req).await)
+}
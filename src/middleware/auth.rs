@@ -30,6 +30,10 @@ pub async fn auth(
 		.await?
 		.ok_or(StatusCode::UNAUTHORIZED)?;
 
+	if user.is_banned() {
+		return Err(StatusCode::FORBIDDEN.into());
+	}
+
 	req.extensions_mut().insert(user);
 	Ok(next.run(req).await)
 }
@@ -17,7 +17,7 @@ use crate::{
 };
 
 #[derive(Debug, Serialize, Deserialize)]
-struct AccessPeriod {
+pub struct AccessPeriod {
 	start: u64,
 	count: u32,
 }
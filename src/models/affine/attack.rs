@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		affine::{AffineAttackChallenge, AffineAttackPublic, AffineAttackSubmission},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::affine_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AffineAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	a:       i32,
+	b:       i32,
+	message: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::affine_attacks)]
+struct NewAffineAttack {
+	user_id: i32,
+
+	a:       i32,
+	b:       i32,
+	message: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      AffineAttack,
+	table:      affine_attacks,
+	pool:       affine_attack,
+	name:       "affine attack",
+	cache_key:  "affine:attack",
+	expiry:     "AFFINE_ATTACK_EXPIRY_SECONDS",
+	challenge:  AffineAttackChallenge,
+	public:     AffineAttackPublic,
+	submission: AffineAttackSubmission,
+}
+
+impl AffineAttack {
+	pub fn challenge(&self) -> Result<AffineAttackChallenge, Error> {
+		let challenge = AffineAttackChallenge {
+			a:       self.a,
+			b:       self.b,
+			message: self.message.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	/// Always two known pairs whatever the difficulty, the fewest that pin
+	/// down the key.
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		_difficulty: Difficulty,
+	) -> Option<AffineAttackChallenge> {
+		Some(AffineAttackChallenge::generate(rng))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: AffineAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewAffineAttack {
+		NewAffineAttack {
+			user_id,
+
+			a: challenge.a,
+			b: challenge.b,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &AffineAttackSubmission) -> Result<Option<String>, Error> {
+		if self
+			.challenge()?
+			.verify(submission.a, submission.b)
+		{
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect key.".to_string()))
+	}
+}
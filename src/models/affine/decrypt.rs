@@ -0,0 +1,115 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		affine::{AffineDecryptChallenge, AffineDecryptPublic, AffineDecryptSubmission},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::affine_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AffineDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	a:      i32,
+	b:      i32,
+	cipher: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::affine_decrypts)]
+struct NewAffineDecrypt {
+	user_id: i32,
+
+	a:      i32,
+	b:      i32,
+	cipher: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      AffineDecrypt,
+	table:      affine_decrypts,
+	pool:       affine_decrypt,
+	name:       "affine decrypt",
+	cache_key:  "affine:decrypt",
+	expiry:     "AFFINE_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  AffineDecryptChallenge,
+	public:     AffineDecryptPublic,
+	submission: AffineDecryptSubmission,
+}
+
+impl AffineDecrypt {
+	pub fn challenge(&self) -> Result<AffineDecryptChallenge, Error> {
+		let challenge = AffineDecryptChallenge {
+			a:      self.a,
+			b:      self.b,
+			cipher: self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<AffineDecryptChallenge> {
+		let cipher_size = env::var("AFFINE_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(6);
+
+		Some(AffineDecryptChallenge::generate(
+			rng,
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: AffineDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewAffineDecrypt {
+		NewAffineDecrypt {
+			user_id,
+
+			a: challenge.a,
+			b: challenge.b,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &AffineDecryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
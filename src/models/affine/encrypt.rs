@@ -0,0 +1,115 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		affine::{AffineEncryptChallenge, AffineEncryptPublic, AffineEncryptSubmission},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::affine_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AffineEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	a:       i32,
+	b:       i32,
+	message: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::affine_encrypts)]
+struct NewAffineEncrypt {
+	user_id: i32,
+
+	a:       i32,
+	b:       i32,
+	message: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      AffineEncrypt,
+	table:      affine_encrypts,
+	pool:       affine_encrypt,
+	name:       "affine encrypt",
+	cache_key:  "affine:encrypt",
+	expiry:     "AFFINE_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  AffineEncryptChallenge,
+	public:     AffineEncryptPublic,
+	submission: AffineEncryptSubmission,
+}
+
+impl AffineEncrypt {
+	pub fn challenge(&self) -> Result<AffineEncryptChallenge, Error> {
+		let challenge = AffineEncryptChallenge {
+			a:       self.a,
+			b:       self.b,
+			message: self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<AffineEncryptChallenge> {
+		let message_size = env::var("AFFINE_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(6);
+
+		Some(AffineEncryptChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: AffineEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewAffineEncrypt {
+		NewAffineEncrypt {
+			user_id,
+
+			a: challenge.a,
+			b: challenge.b,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &AffineEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
+	}
+}
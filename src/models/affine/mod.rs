@@ -0,0 +1,8 @@
+mod encrypt;
+pub use crate::models::affine::encrypt::*;
+
+mod decrypt;
+pub use crate::models::affine::decrypt::*;
+
+mod attack;
+pub use crate::models::affine::attack::*;
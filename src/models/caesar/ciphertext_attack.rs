@@ -0,0 +1,131 @@
+use std::env;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		caesar::{
+			CaesarCiphertextAttackChallenge,
+			CaesarCiphertextAttackPublic,
+			CaesarCiphertextAttackSubmission,
+		},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::caesar_ciphertext_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct CaesarCiphertextAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     i32,
+	message: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::caesar_ciphertext_attacks)]
+struct NewCaesarCiphertextAttack {
+	user_id: i32,
+
+	key:     i32,
+	message: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      CaesarCiphertextAttack,
+	table:      caesar_ciphertext_attacks,
+	pool:       caesar_ciphertext_attack,
+	name:       "caesar ciphertext attack",
+	cache_key:  "caesar:ciphertext-attack",
+	expiry:     "CAESAR_CIPHERTEXT_ATTACK_EXPIRY_SECONDS",
+	challenge:  CaesarCiphertextAttackChallenge,
+	public:     CaesarCiphertextAttackPublic,
+	submission: CaesarCiphertextAttackSubmission,
+}
+
+impl CaesarCiphertextAttack {
+	pub fn challenge(&self) -> Result<CaesarCiphertextAttackChallenge, Error> {
+		let challenge = CaesarCiphertextAttackChallenge {
+			key:     self.key,
+			message: self.message.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<CaesarCiphertextAttackChallenge> {
+		let message_size = env::var("CAESAR_CIPHERTEXT_ATTACK_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(30);
+
+		Some(CaesarCiphertextAttackChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: CaesarCiphertextAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewCaesarCiphertextAttack {
+		NewCaesarCiphertextAttack {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(
+		&self,
+		submission: &CaesarCiphertextAttackSubmission,
+	) -> Result<Option<String>, Error> {
+		if submission.key.is_none() && submission.message.is_none() {
+			let error = Error::default()
+				.with_code(StatusCode::BAD_REQUEST)
+				.with_message("Submit either a key or a message.");
+
+			return Err(error);
+		}
+
+		if self.challenge()?.verify(submission) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect key or message.".to_string()))
+	}
+}
@@ -1,16 +1,18 @@
-use std::{env, time::Duration};
+use std::env;
 
-use axum::http::StatusCode;
 use chrono::{DateTime, Utc};
-use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
-use rand::distr::{Alphabetic, SampleString};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+	challenge::{
+		Difficulty,
+		caesar::{CaesarEncryptChallenge, CaesarEncryptPublic, CaesarEncryptSubmission},
+	},
 	error::Error,
+	models::challenge_model::impl_challenge_model,
 	schema,
-	state::{AppState, Cacheable},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
@@ -25,6 +27,12 @@ pub struct CaesarEncrypt {
 
 	created_at:   DateTime<Utc>,
 	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
 }
 
 #[derive(Insertable)]
@@ -34,150 +42,70 @@ struct NewCaesarEncrypt {
 
 	key:     i32,
 	message: String,
+
+	difficulty: String,
+	seed:       i64,
 }
 
-#[derive(Serialize)]
-pub struct CaesarEncryptPublic {
-	key:     i32,
-	message: String,
+impl_challenge_model! {
+	model:      CaesarEncrypt,
+	table:      caesar_encrypts,
+	pool:       caesar_encrypt,
+	name:       "caesar encrypt",
+	cache_key:  "caesar:encrypt",
+	expiry:     "CAESAR_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  CaesarEncryptChallenge,
+	public:     CaesarEncryptPublic,
+	submission: CaesarEncryptSubmission,
 }
 
 impl CaesarEncrypt {
-	pub fn completed_duration(&self) -> Option<Duration> {
-		let delta = self
-			.completed_at?
-			.signed_duration_since(self.created_at);
-		let nanoseconds = delta.num_nanoseconds()? as u64;
-
-		Some(Duration::from_nanos(nanoseconds))
-	}
-
-	pub async fn find_user_incomplete(
-		state: &AppState,
-		user_id: i32,
-	) -> Result<Option<Self>, Error> {
-		if let Some(cached_incomplete) = CaesarEncrypt::from_cached(state.cache(), user_id)? {
-			return Ok(Some(cached_incomplete));
-		}
-
-		let mut db = state.db().await?;
-
-		let maybe_got = schema::caesar_encrypts::dsl::caesar_encrypts
-			.filter(schema::caesar_encrypts::user_id.eq(user_id))
-			.filter(schema::caesar_encrypts::completed_at.is_null())
-			.select(CaesarEncrypt::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.next();
-
-		if let Some(got) = &maybe_got {
-			got.to_cached(state.cache(), user_id)?;
-		}
+	pub fn challenge(&self) -> Result<CaesarEncryptChallenge, Error> {
+		let challenge = CaesarEncryptChallenge {
+			key:     self.key,
+			message: self.message.clone(),
+		};
 
-		Ok(maybe_got)
+		Ok(challenge)
 	}
 
-	pub async fn create(state: &AppState, user_id: i32) -> Result<Self, Error> {
-		let key = rand::random_range(8..=18);
-
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<CaesarEncryptChallenge> {
 		let message_size = env::var("CAESAR_ENCRYPTION_MESSAGE_SIZE")
 			.ok()
 			.and_then(|value| value.parse::<usize>().ok())
 			.unwrap_or(6);
 
-		let message = Alphabetic
-			.sample_string(&mut rand::rng(), message_size)
-			.to_uppercase();
+		Some(CaesarEncryptChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
 
-		let new_encrypt = NewCaesarEncrypt {
+	fn new_row(
+		user_id: i32,
+		challenge: CaesarEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewCaesarEncrypt {
+		NewCaesarEncrypt {
 			user_id,
 
-			key,
-			message,
-		};
-
-		let mut db = state.db().await?;
-
-		let encrypt = diesel::insert_into(schema::caesar_encrypts::table)
-			.values(&new_encrypt)
-			.returning(CaesarEncrypt::as_returning())
-			.get_result(&mut db)
-			.await?;
+			key: challenge.key,
+			message: challenge.message,
 
-		encrypt.to_cached(state.cache(), user_id)?;
-
-		Ok(encrypt)
-	}
-
-	pub async fn try_into_completed(
-		self,
-		state: &AppState,
-		cipher: String,
-	) -> Result<Duration, Error> {
-		let encrypted = self
-			.message
-			.chars()
-			.map(|char| {
-				let old_ascii_index = char as u8 - 65;
-				let new_ascii_index = (old_ascii_index + self.key as u8) % 26;
-
-				(new_ascii_index + 65) as char
-			})
-			.collect::<String>();
-
-		if encrypted != cipher {
-			let error = Error::default()
-				.with_code(StatusCode::BAD_REQUEST)
-				.with_message("Incorrect cipher.");
-
-			return Err(error);
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
 		}
-
-		let mut db = state.db().await?;
-
-		let completed = diesel::update(schema::caesar_encrypts::dsl::caesar_encrypts.find(self.id))
-			.set(schema::caesar_encrypts::dsl::completed_at.eq(diesel::dsl::now))
-			.get_result::<Self>(&mut db)
-			.await?;
-
-		CaesarEncrypt::purge_cache(state.cache(), self.user_id)?;
-
-		let duration = completed
-			.completed_duration()
-			.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		Ok(duration)
-	}
-
-	pub async fn find_all_completed(state: &AppState) -> Result<Vec<Self>, Error> {
-		let mut db = state.db().await?;
-
-		let got = schema::caesar_encrypts::dsl::caesar_encrypts
-			.filter(schema::caesar_encrypts::completed_at.is_not_null())
-			.select(CaesarEncrypt::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.collect();
-
-		Ok(got)
 	}
-}
-
-impl Cacheable for CaesarEncrypt {
-	type Id = i32;
 
-	fn cache_key(user_id: Self::Id) -> String {
-		format!("caesar:encrypt:{user_id}:incomplete")
-	}
-}
-
-impl From<CaesarEncrypt> for CaesarEncryptPublic {
-	fn from(encrypt: CaesarEncrypt) -> Self {
-		CaesarEncryptPublic {
-			key:     encrypt.key,
-			message: encrypt.message,
+	fn mistake(&self, submission: &CaesarEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
 		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
 	}
 }
@@ -6,3 +6,6 @@ pub use crate::models::caesar::decrypt::*;
 
 mod attack;
 pub use crate::models::caesar::attack::*;
+
+mod ciphertext_attack;
+pub use crate::models::caesar::ciphertext_attack::*;
@@ -0,0 +1,765 @@
+use std::time::Duration;
+
+use serde::{Serialize, de::DeserializeOwned};
+use validator::Validate;
+
+use crate::{
+	challenge::{Difficulty, Hint},
+	delivery::Deliverable,
+	error::Error,
+	history::HistoryEntry,
+	leaderboard::LeaderboardResult,
+	models::user::User,
+	state::{AppState, Cacheable},
+	stats::ChallengeStats,
+};
+
+/// A stored challenge attempt. Implemented for every challenge table by
+/// `impl_challenge_model!`, so the routes and the admin tooling can treat all
+/// of them alike.
+pub trait ChallengeModel: Deliverable + Cacheable<Id = i32> + Clone + Sync {
+	/// How the challenge is referred to in error messages.
+	const NAME: &'static str;
+
+	type Public: Serialize + Send;
+	type Submission: Serialize + DeserializeOwned + Validate + Send + 'static;
+
+	fn difficulty(&self) -> Difficulty;
+
+	/// The challenge as shown to the user, without the answer.
+	fn public(&self) -> Result<Self::Public, Error>;
+
+	fn find_by_id(
+		state: &AppState,
+		id: i32,
+	) -> impl Future<Output = Result<Option<Self>, Error>> + Send;
+
+	/// Returns the user's pending challenge, marking it expired instead if it
+	/// has been left unsolved for longer than the configured expiry.
+	fn find_user_incomplete(
+		state: &AppState,
+		user_id: i32,
+	) -> impl Future<Output = Result<Option<Self>, Error>> + Send;
+
+	fn create(
+		state: &AppState,
+		user_id: i32,
+		difficulty: Difficulty,
+	) -> impl Future<Output = Result<Self, Error>> + Send;
+
+	fn take_hint(self, state: &AppState) -> impl Future<Output = Result<Hint, Error>> + Send;
+
+	/// Records the submission and completes the challenge if it is correct.
+	fn try_into_completed(
+		self,
+		state: &AppState,
+		submission: Self::Submission,
+	) -> impl Future<Output = Result<Duration, Error>> + Send;
+
+	fn abandon(self, state: &AppState) -> impl Future<Output = Result<(), Error>> + Send;
+
+	/// Marks every pending challenge delivered longer ago than the configured
+	/// expiry as expired and returns how many were.
+	fn expire_stale(state: &AppState) -> impl Future<Output = Result<usize, Error>> + Send;
+
+	fn leaderboard(
+		state: &AppState,
+		difficulty: Difficulty,
+	) -> impl Future<Output = Result<Vec<LeaderboardResult>, Error>> + Send;
+
+	fn count_by_user(
+		state: &AppState,
+		user_id: i32,
+	) -> impl Future<Output = Result<usize, Error>> + Send;
+
+	/// Returns the user's `limit` most recently started challenges, newest
+	/// first.
+	fn history(
+		state: &AppState,
+		user_id: i32,
+		limit: usize,
+	) -> impl Future<Output = Result<Vec<HistoryEntry>, Error>> + Send;
+
+	fn stats(
+		state: &AppState,
+		user: &User,
+		difficulties: &[Difficulty],
+	) -> impl Future<Output = Result<Vec<ChallengeStats>, Error>> + Send;
+
+	/// Deletes the user's completed challenges, or only the one with `id`.
+	fn delete_completed(
+		state: &AppState,
+		user_id: i32,
+		id: Option<i32>,
+	) -> impl Future<Output = Result<usize, Error>> + Send;
+}
+
+/// Implements `ChallengeModel` and the shared queries for a challenge table.
+/// The model supplies its columns and four inherent functions:
+///
+/// - `challenge(&self) -> Result<Challenge, Error>`, rebuilding the challenge
+///   from its row;
+/// - `generate(rng, difficulty) -> Option<Challenge>`, drawing a new one from
+///   the configured ranges;
+/// - `new_row(user_id, challenge, difficulty, seed)`, the row to insert for a
+///   pooled challenge;
+/// - `mistake(&self, &Submission) -> Result<Option<String>, Error>`, what is
+///   wrong with a submission, or an error if it cannot be checked at all.
+macro_rules! impl_challenge_model {
+	(
+		model:      $model:ident,
+		table:      $table:ident,
+		pool:       $pool:ident,
+		name:       $name:literal,
+		cache_key:  $cache_key:literal,
+		expiry:     $expiry:literal,
+		challenge:  $challenge:ty,
+		public:     $public:ty,
+		submission: $submission:ty $(,)?
+	) => {
+		const _: () = {
+			use std::{env, time::Duration};
+
+			use axum::http::StatusCode;
+			use chrono::{DateTime, TimeDelta, Utc};
+			use diesel::prelude::*;
+			use diesel_async::RunQueryDsl;
+			use $crate::{
+				admin::RegeneratedChallenge,
+				challenge::{self, ChallengeKind, Difficulty, Hint},
+				delivery::Deliverable,
+				error::Error,
+				history::HistoryEntry,
+				leaderboard::{Leaderboard, LeaderboardResult},
+				models::{
+					self,
+					challenge_model::ChallengeModel,
+					submission::Submission,
+					user::User,
+				},
+				schema,
+				state::{AppState, Cacheable},
+				stats::ChallengeStats,
+			};
+
+			impl $model {
+				/// Rows created before delivery was tracked fall back to their
+				/// creation time.
+				pub fn started_at(&self) -> DateTime<Utc> {
+					self.delivered_at.unwrap_or(self.created_at)
+				}
+
+				pub fn completed_duration(&self) -> Option<Duration> {
+					let delta = self
+						.completed_at?
+						.signed_duration_since(self.started_at());
+					let nanoseconds = delta.num_nanoseconds()? as u64;
+					let penalty = models::hint_penalty() * self.hints_used as u32;
+
+					Some(Duration::from_nanos(nanoseconds) + penalty)
+				}
+
+				pub fn expiry() -> TimeDelta {
+					let seconds = env::var($expiry)
+						.ok()
+						.and_then(|value| value.parse::<i64>().ok())
+						.unwrap_or(3_600);
+
+					TimeDelta::seconds(seconds)
+				}
+
+				pub fn is_pending(&self) -> bool {
+					self.completed_at.is_none()
+						&& self.abandoned_at.is_none()
+						&& self.expired_at.is_none()
+				}
+
+				/// Challenges are timed from delivery, so one that was never
+				/// delivered has not started its expiry either.
+				pub fn is_expired(&self) -> bool {
+					self.delivered_at
+						.is_some_and(|delivered_at| delivered_at + $model::expiry() < Utc::now())
+				}
+
+				/// Reruns generation from the stored seed and difficulty. Only
+				/// reproduces the stored parameters while the configured ranges
+				/// are unchanged.
+				pub fn regenerate(&self) -> Option<$challenge> {
+					let seed = self.seed? as u64;
+					$model::generate(&mut challenge::seeded_rng(seed), self.difficulty())
+				}
+
+				async fn find_user_pending(
+					state: &AppState,
+					user_id: i32,
+				) -> Result<Option<Self>, Error> {
+					if let Some(cached_pending) = $model::from_cached(state.cache(), user_id)? {
+						return Ok(Some(cached_pending));
+					}
+
+					let mut db = state.db().await?;
+
+					let maybe_got = schema::$table::dsl::$table
+						.filter(schema::$table::user_id.eq(user_id))
+						.filter(schema::$table::completed_at.is_null())
+						.filter(schema::$table::abandoned_at.is_null())
+						.filter(schema::$table::expired_at.is_null())
+						.select($model::as_select())
+						.load(&mut db)
+						.await?
+						.into_iter()
+						.next();
+
+					if let Some(got) = &maybe_got {
+						got.to_cached(state.cache(), user_id)?;
+					}
+
+					Ok(maybe_got)
+				}
+
+				pub async fn expire(self, state: &AppState) -> Result<(), Error> {
+					let mut db = state.db().await?;
+
+					diesel::update(schema::$table::dsl::$table.find(self.id))
+						.set(schema::$table::dsl::expired_at.eq(diesel::dsl::now))
+						.execute(&mut db)
+						.await?;
+
+					$model::purge_cache(state.cache(), self.user_id)?;
+
+					Ok(())
+				}
+
+				pub async fn find_all_completed(
+					state: &AppState,
+					difficulty: Difficulty,
+				) -> Result<Vec<Self>, Error> {
+					let mut db = state.db().await?;
+
+					let got = schema::$table::dsl::$table
+						.filter(schema::$table::completed_at.is_not_null())
+						.filter(schema::$table::difficulty.eq(difficulty.as_str()))
+						.select($model::as_select())
+						.load(&mut db)
+						.await?;
+
+					Ok(got)
+				}
+
+				pub async fn find_all_by_user(
+					state: &AppState,
+					user_id: i32,
+				) -> Result<Vec<Self>, Error> {
+					let mut db = state.db().await?;
+
+					let got = schema::$table::dsl::$table
+						.filter(schema::$table::user_id.eq(user_id))
+						.order(schema::$table::created_at.asc())
+						.select($model::as_select())
+						.load(&mut db)
+						.await?;
+
+					Ok(got)
+				}
+			}
+
+			impl ChallengeModel for $model {
+				const NAME: &'static str = $name;
+
+				type Public = $public;
+				type Submission = $submission;
+
+				fn difficulty(&self) -> Difficulty {
+					self.difficulty.parse().unwrap_or_default()
+				}
+
+				fn public(&self) -> Result<$public, Error> {
+					Ok(self.challenge()?.into())
+				}
+
+				async fn find_by_id(state: &AppState, id: i32) -> Result<Option<Self>, Error> {
+					let mut db = state.db().await?;
+
+					let got = schema::$table::dsl::$table
+						.find(id)
+						.select($model::as_select())
+						.first(&mut db)
+						.await
+						.optional()?;
+
+					Ok(got)
+				}
+
+				async fn find_user_incomplete(
+					state: &AppState,
+					user_id: i32,
+				) -> Result<Option<Self>, Error> {
+					let Some(pending) = $model::find_user_pending(state, user_id).await? else {
+						return Ok(None);
+					};
+
+					if pending.is_expired() {
+						pending.expire(state).await?;
+						return Ok(None);
+					}
+
+					Ok(Some(pending))
+				}
+
+				async fn create(
+					state: &AppState,
+					user_id: i32,
+					difficulty: Difficulty,
+				) -> Result<Self, Error> {
+					let pooled = state.pools().$pool.take(difficulty).await?;
+					let new_row =
+						$model::new_row(user_id, pooled.challenge, difficulty, pooled.seed);
+
+					let mut db = state.db().await?;
+
+					let created = diesel::insert_into(schema::$table::table)
+						.values(&new_row)
+						.returning($model::as_returning())
+						.get_result(&mut db)
+						.await?;
+
+					created.to_cached(state.cache(), user_id)?;
+
+					Ok(created)
+				}
+
+				async fn take_hint(self, state: &AppState) -> Result<Hint, Error> {
+					let hints = self.challenge()?.hints();
+
+					if self.hints_used as usize >= hints.len() {
+						let hint = Hint::nth(hints, self.hints_used as usize)
+							.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+						return Ok(hint);
+					}
+
+					let mut db = state.db().await?;
+
+					let hinted = diesel::update(schema::$table::dsl::$table.find(self.id))
+						.set(
+							schema::$table::dsl::hints_used.eq(schema::$table::dsl::hints_used + 1),
+						)
+						.returning($model::as_returning())
+						.get_result(&mut db)
+						.await?;
+
+					hinted.to_cached(state.cache(), self.user_id)?;
+
+					let hint = Hint::nth(hints, hinted.hints_used as usize)
+						.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+					Ok(hint)
+				}
+
+				async fn try_into_completed(
+					self,
+					state: &AppState,
+					submission: $submission,
+				) -> Result<Duration, Error> {
+					let mistake = self.mistake(&submission)?;
+
+					Submission::create(
+						state,
+						self.user_id,
+						ChallengeKind::$model,
+						self.id,
+						&submission,
+						mistake.is_none(),
+					)
+					.await?;
+
+					if let Some(mistake) = mistake {
+						let error = Error::default()
+							.with_code(StatusCode::BAD_REQUEST)
+							.with_message(mistake);
+
+						return Err(error);
+					}
+
+					let mut db = state.db().await?;
+
+					let completed = diesel::update(schema::$table::dsl::$table.find(self.id))
+						.set(schema::$table::dsl::completed_at.eq(diesel::dsl::now))
+						.get_result::<Self>(&mut db)
+						.await?;
+
+					$model::purge_cache(state.cache(), self.user_id)?;
+
+					let duration = completed
+						.completed_duration()
+						.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+					Ok(duration)
+				}
+
+				async fn abandon(self, state: &AppState) -> Result<(), Error> {
+					let mut db = state.db().await?;
+
+					diesel::update(schema::$table::dsl::$table.find(self.id))
+						.set(schema::$table::dsl::abandoned_at.eq(diesel::dsl::now))
+						.execute(&mut db)
+						.await?;
+
+					$model::purge_cache(state.cache(), self.user_id)?;
+
+					Ok(())
+				}
+
+				async fn expire_stale(state: &AppState) -> Result<usize, Error> {
+					let cutoff = Utc::now() - $model::expiry();
+
+					let mut db = state.db().await?;
+
+					let user_ids = diesel::update(schema::$table::dsl::$table)
+						.filter(schema::$table::completed_at.is_null())
+						.filter(schema::$table::abandoned_at.is_null())
+						.filter(schema::$table::expired_at.is_null())
+						.filter(schema::$table::delivered_at.lt(cutoff))
+						.set(schema::$table::dsl::expired_at.eq(diesel::dsl::now))
+						.returning(schema::$table::user_id)
+						.get_results::<i32>(&mut db)
+						.await?;
+
+					for user_id in &user_ids {
+						$model::purge_cache(state.cache(), *user_id)?;
+					}
+
+					Ok(user_ids.len())
+				}
+
+				async fn leaderboard(
+					state: &AppState,
+					difficulty: Difficulty,
+				) -> Result<Vec<LeaderboardResult>, Error> {
+					let mut leaderboard = Leaderboard::default();
+
+					for completed in $model::find_all_completed(state, difficulty).await? {
+						let duration = completed
+							.completed_duration()
+							.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+						if !leaderboard.is_faster_result(completed.user_id, duration) {
+							continue;
+						}
+
+						let user = User::find_by_id(state, completed.user_id)
+							.await?
+							.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+						let result = LeaderboardResult {
+							username: user.username,
+							duration,
+						};
+
+						leaderboard.insert(completed.user_id, result);
+					}
+
+					Ok(leaderboard.into_results())
+				}
+
+				async fn count_by_user(state: &AppState, user_id: i32) -> Result<usize, Error> {
+					let mut db = state.db().await?;
+
+					let count = schema::$table::dsl::$table
+						.filter(schema::$table::user_id.eq(user_id))
+						.count()
+						.get_result::<i64>(&mut db)
+						.await?;
+
+					Ok(count as usize)
+				}
+
+				async fn history(
+					state: &AppState,
+					user_id: i32,
+					limit: usize,
+				) -> Result<Vec<HistoryEntry>, Error> {
+					let mut db = state.db().await?;
+
+					schema::$table::dsl::$table
+						.filter(schema::$table::user_id.eq(user_id))
+						.order(models::started_at().desc())
+						.limit(limit as i64)
+						.select($model::as_select())
+						.load(&mut db)
+						.await?
+						.into_iter()
+						.map(HistoryEntry::try_from)
+						.collect()
+				}
+
+				async fn stats(
+					state: &AppState,
+					user: &User,
+					difficulties: &[Difficulty],
+				) -> Result<Vec<ChallengeStats>, Error> {
+					let ranks = models::ranks(state, stringify!($table), user.id).await?;
+					let attempts = $model::find_all_by_user(state, user.id).await?;
+
+					let stats = difficulties
+						.iter()
+						.map(|difficulty| {
+							let attempts = attempts
+								.iter()
+								.filter(|attempt| attempt.difficulty() == *difficulty)
+								.collect::<Vec<_>>();
+
+							ChallengeStats::new(
+								ChallengeKind::$model,
+								*difficulty,
+								attempts
+									.iter()
+									.map(|attempt| attempt.completed_duration()),
+								attempts
+									.iter()
+									.any(|attempt| attempt.is_pending()),
+								ranks.get(difficulty).copied(),
+							)
+						})
+						.collect();
+
+					Ok(stats)
+				}
+
+				async fn delete_completed(
+					state: &AppState,
+					user_id: i32,
+					id: Option<i32>,
+				) -> Result<usize, Error> {
+					let mut db = state.db().await?;
+
+					let mut query = diesel::delete(schema::$table::dsl::$table)
+						.filter(schema::$table::user_id.eq(user_id))
+						.filter(schema::$table::completed_at.is_not_null())
+						.into_boxed();
+
+					if let Some(id) = id {
+						query = query.filter(schema::$table::id.eq(id));
+					}
+
+					Ok(query.execute(&mut db).await?)
+				}
+			}
+
+			impl Deliverable for $model {
+				fn is_delivered(&self) -> bool {
+					self.delivered_at.is_some()
+				}
+
+				async fn mark_delivered(self, state: &AppState) -> Result<(), Error> {
+					let mut db = state.db().await?;
+
+					let delivered = diesel::update(schema::$table::dsl::$table.find(self.id))
+						.filter(schema::$table::delivered_at.is_null())
+						.filter(schema::$table::completed_at.is_null())
+						.set(schema::$table::dsl::delivered_at.eq(diesel::dsl::now))
+						.returning($model::as_returning())
+						.get_result(&mut db)
+						.await
+						.optional()?;
+
+					if let Some(delivered) = delivered {
+						delivered.to_cached(state.cache(), self.user_id)?;
+					}
+
+					Ok(())
+				}
+			}
+
+			impl Cacheable for $model {
+				type Id = i32;
+
+				fn cache_key(user_id: Self::Id) -> String {
+					format!("{}:{user_id}:incomplete", $cache_key)
+				}
+			}
+
+			impl TryFrom<$model> for RegeneratedChallenge {
+				type Error = Error;
+
+				fn try_from(model: $model) -> Result<Self, Self::Error> {
+					let Some(seed) = model.seed else {
+						let error = Error::default()
+							.with_code(StatusCode::BAD_REQUEST)
+							.with_message(
+								"This challenge predates seeding and cannot be regenerated.",
+							);
+
+						return Err(error);
+					};
+
+					let regenerated = model
+						.regenerate()
+						.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+					let result = RegeneratedChallenge {
+						challenge:  ChallengeKind::$model,
+						id:         model.id,
+						seed:       seed as u64,
+						difficulty: model.difficulty(),
+						parameters: serde_json::to_value(&regenerated)?,
+						matches:    regenerated == model.challenge()?,
+					};
+
+					Ok(result)
+				}
+			}
+
+			impl TryFrom<$model> for HistoryEntry {
+				type Error = Error;
+
+				fn try_from(model: $model) -> Result<Self, Self::Error> {
+					let entry = HistoryEntry {
+						challenge:  ChallengeKind::$model,
+						id:         model.id,
+						parameters: serde_json::to_value(model.public()?)?,
+						attempts:   Vec::new(),
+						hints_used: model.hints_used as usize,
+						difficulty: model.difficulty(),
+
+						started_at:   model.started_at(),
+						completed_at: model.completed_at,
+						abandoned_at: model.abandoned_at,
+						expired_at:   model.expired_at,
+					};
+
+					Ok(entry)
+				}
+			}
+		};
+	};
+}
+
+pub(crate) use impl_challenge_model;
+
+/// Runs `$body` with `$model` naming the model type of the challenge `$kind`,
+/// for code that handles every challenge alike. `ChallengeModel` has to be in
+/// scope for `$body` to call its functions.
+#[macro_export]
+macro_rules! with_challenge_model {
+	($kind:expr, $model:ident => $body:expr) => {
+		match $kind {
+			$crate::challenge::ChallengeKind::CaesarEncrypt => {
+				type $model = $crate::models::caesar::CaesarEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::CaesarDecrypt => {
+				type $model = $crate::models::caesar::CaesarDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::CaesarAttack => {
+				type $model = $crate::models::caesar::CaesarAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::DiffieHellmanExchange => {
+				type $model = $crate::models::diffie_hellman_exchange::DiffieHellmanExchange;
+				$body
+			},
+			$crate::challenge::ChallengeKind::RsaEncrypt => {
+				type $model = $crate::models::rsa::RsaEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::RsaDecrypt => {
+				type $model = $crate::models::rsa::RsaDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::DssSign => {
+				type $model = $crate::models::dss::DssSign;
+				$body
+			},
+			$crate::challenge::ChallengeKind::DssVerify => {
+				type $model = $crate::models::dss::DssVerify;
+				$body
+			},
+			$crate::challenge::ChallengeKind::VigenereEncrypt => {
+				type $model = $crate::models::vigenere::VigenereEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::VigenereDecrypt => {
+				type $model = $crate::models::vigenere::VigenereDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::VigenereAttack => {
+				type $model = $crate::models::vigenere::VigenereAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::AffineEncrypt => {
+				type $model = $crate::models::affine::AffineEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::AffineDecrypt => {
+				type $model = $crate::models::affine::AffineDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::AffineAttack => {
+				type $model = $crate::models::affine::AffineAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::SubstitutionAttack => {
+				type $model = $crate::models::substitution::SubstitutionAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::CaesarCiphertextAttack => {
+				type $model = $crate::models::caesar::CaesarCiphertextAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::PlayfairEncrypt => {
+				type $model = $crate::models::playfair::PlayfairEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::PlayfairDecrypt => {
+				type $model = $crate::models::playfair::PlayfairDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::HillEncrypt => {
+				type $model = $crate::models::hill::HillEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::HillDecrypt => {
+				type $model = $crate::models::hill::HillDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::HillAttack => {
+				type $model = $crate::models::hill::HillAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::RailFenceEncrypt => {
+				type $model = $crate::models::rail_fence::RailFenceEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::RailFenceDecrypt => {
+				type $model = $crate::models::rail_fence::RailFenceDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::ColumnarEncrypt => {
+				type $model = $crate::models::columnar::ColumnarEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::ColumnarDecrypt => {
+				type $model = $crate::models::columnar::ColumnarDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::ColumnarDoubleAttack => {
+				type $model = $crate::models::columnar::ColumnarDoubleAttack;
+				$body
+			},
+			$crate::challenge::ChallengeKind::EnigmaEncrypt => {
+				type $model = $crate::models::enigma::EnigmaEncrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::EnigmaDecrypt => {
+				type $model = $crate::models::enigma::EnigmaDecrypt;
+				$body
+			},
+			$crate::challenge::ChallengeKind::EnigmaAttack => {
+				type $model = $crate::models::enigma::EnigmaAttack;
+				$body
+			},
+		}
+	};
+}
@@ -0,0 +1,127 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		columnar::{
+			ColumnarDoubleAttackChallenge,
+			ColumnarDoubleAttackPublic,
+			ColumnarDoubleAttackSubmission,
+		},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, columnar},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::columnar_double_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ColumnarDoubleAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	first_key:  String,
+	second_key: String,
+	message:    String,
+	cipher:     String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::columnar_double_attacks)]
+struct NewColumnarDoubleAttack {
+	user_id: i32,
+
+	first_key:  String,
+	second_key: String,
+	message:    String,
+	cipher:     String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      ColumnarDoubleAttack,
+	table:      columnar_double_attacks,
+	pool:       columnar_double_attack,
+	name:       "columnar double attack",
+	cache_key:  "columnar:double-attack",
+	expiry:     "COLUMNAR_DOUBLE_ATTACK_EXPIRY_SECONDS",
+	challenge:  ColumnarDoubleAttackChallenge,
+	public:     ColumnarDoubleAttackPublic,
+	submission: ColumnarDoubleAttackSubmission,
+}
+
+impl ColumnarDoubleAttack {
+	pub fn challenge(&self) -> Result<ColumnarDoubleAttackChallenge, Error> {
+		let challenge = ColumnarDoubleAttackChallenge {
+			first_key:  self.first_key.clone(),
+			second_key: self.second_key.clone(),
+			message:    self.message.clone(),
+			cipher:     self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<ColumnarDoubleAttackChallenge> {
+		let message_size = env::var("COLUMNAR_DOUBLE_ATTACK_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(40);
+
+		Some(ColumnarDoubleAttackChallenge::generate(
+			rng,
+			columnar::key_size(difficulty),
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: ColumnarDoubleAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewColumnarDoubleAttack {
+		NewColumnarDoubleAttack {
+			user_id,
+
+			first_key: challenge.first_key,
+			second_key: challenge.second_key,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(
+		&self,
+		submission: &ColumnarDoubleAttackSubmission,
+	) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
@@ -0,0 +1,112 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		columnar::{ColumnarEncryptChallenge, ColumnarEncryptPublic, ColumnarEncryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, columnar},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::columnar_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ColumnarEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     String,
+	message: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::columnar_encrypts)]
+struct NewColumnarEncrypt {
+	user_id: i32,
+
+	key:     String,
+	message: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      ColumnarEncrypt,
+	table:      columnar_encrypts,
+	pool:       columnar_encrypt,
+	name:       "columnar encrypt",
+	cache_key:  "columnar:encrypt",
+	expiry:     "COLUMNAR_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  ColumnarEncryptChallenge,
+	public:     ColumnarEncryptPublic,
+	submission: ColumnarEncryptSubmission,
+}
+
+impl ColumnarEncrypt {
+	pub fn challenge(&self) -> Result<ColumnarEncryptChallenge, Error> {
+		let challenge = ColumnarEncryptChallenge {
+			key:     self.key.clone(),
+			message: self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<ColumnarEncryptChallenge> {
+		let message_size = env::var("COLUMNAR_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(ColumnarEncryptChallenge::generate(
+			rng,
+			columnar::key_size(difficulty),
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: ColumnarEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewColumnarEncrypt {
+		NewColumnarEncrypt {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &ColumnarEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
+	}
+}
@@ -0,0 +1,26 @@
+mod encrypt;
+pub use crate::models::columnar::encrypt::*;
+
+mod decrypt;
+pub use crate::models::columnar::decrypt::*;
+
+mod double_attack;
+pub use crate::models::columnar::double_attack::*;
+
+use std::env;
+
+use crate::challenge::Difficulty;
+
+/// Length of the generated keys, configured for medium difficulty. Each tier
+/// above or below adds or removes a column, since the number of column orders
+/// grows factorially.
+pub(crate) fn key_size(difficulty: Difficulty) -> usize {
+	let key_size = env::var("COLUMNAR_KEY_SIZE")
+		.ok()
+		.and_then(|value| value.parse::<usize>().ok())
+		.unwrap_or(4);
+
+	(key_size + difficulty.tier())
+		.saturating_sub(2)
+		.max(2)
+}
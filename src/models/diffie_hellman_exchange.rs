@@ -1,18 +1,22 @@
-use std::{env, time::Duration};
+use std::env;
 
-use axum::http::StatusCode;
 use chrono::{DateTime, Utc};
-use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
-use primal_sieve::Sieve;
-use rand::seq::IteratorRandom;
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+	challenge::{
+		Difficulty,
+		diffie_hellman_exchange::{
+			DiffieHellmanExchangeChallenge,
+			DiffieHellmanExchangePublic,
+			DiffieHellmanExchangeSubmission,
+		},
+	},
 	error::Error,
-	math,
+	models::{self, challenge_model::impl_challenge_model},
 	schema,
-	state::{AppState, Cacheable},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
@@ -22,12 +26,18 @@ pub struct DiffieHellmanExchange {
 	id:          i32,
 	pub user_id: i32,
 
-	g:         i64,
-	n:         i64,
-	sk_server: i64,
+	g:         String,
+	n:         String,
+	sk_server: String,
 
 	created_at:   DateTime<Utc>,
 	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
 }
 
 #[derive(Insertable)]
@@ -35,60 +45,41 @@ pub struct DiffieHellmanExchange {
 struct NewDiffieHellmanExchange {
 	user_id: i32,
 
-	g:         i64,
-	n:         i64,
-	sk_server: i64,
+	g:         String,
+	n:         String,
+	sk_server: String,
+
+	difficulty: String,
+	seed:       i64,
 }
 
-#[derive(Serialize)]
-pub struct DiffieHellmanExchangePublic {
-	g:         u64,
-	n:         u64,
-	pk_server: u64,
+impl_challenge_model! {
+	model:      DiffieHellmanExchange,
+	table:      diffie_hellman_exchanges,
+	pool:       diffie_hellman_exchange,
+	name:       "diffie-hellman exchange",
+	cache_key:  "diffie-hellman-exchange",
+	expiry:     "DIFFIE_HELLMAN_EXPIRY_SECONDS",
+	challenge:  DiffieHellmanExchangeChallenge,
+	public:     DiffieHellmanExchangePublic,
+	submission: DiffieHellmanExchangeSubmission,
 }
 
 impl DiffieHellmanExchange {
-	pub fn completed_duration(&self) -> Option<Duration> {
-		let delta = self
-			.completed_at?
-			.signed_duration_since(self.created_at);
-		let nanoseconds = delta.num_nanoseconds()? as u64;
-
-		Some(Duration::from_nanos(nanoseconds))
-	}
-
-	fn pk_server(&self) -> u64 {
-		math::power_mod(self.g, self.sk_server, self.n)
-	}
-
-	pub async fn find_user_incomplete(
-		state: &AppState,
-		user_id: i32,
-	) -> Result<Option<Self>, Error> {
-		if let Some(cached_incomplete) = DiffieHellmanExchange::from_cached(state.cache(), user_id)?
-		{
-			return Ok(Some(cached_incomplete));
-		}
-
-		let mut db = state.db().await?;
-
-		let maybe_got = schema::diffie_hellman_exchanges::dsl::diffie_hellman_exchanges
-			.filter(schema::diffie_hellman_exchanges::user_id.eq(user_id))
-			.filter(schema::diffie_hellman_exchanges::completed_at.is_null())
-			.select(DiffieHellmanExchange::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.next();
-
-		if let Some(got) = &maybe_got {
-			got.to_cached(state.cache(), user_id)?;
-		}
+	pub fn challenge(&self) -> Result<DiffieHellmanExchangeChallenge, Error> {
+		let challenge = DiffieHellmanExchangeChallenge {
+			g:         models::parse_big(&self.g)?,
+			n:         models::parse_big(&self.n)?,
+			sk_server: models::parse_big(&self.sk_server)?,
+		};
 
-		Ok(maybe_got)
+		Ok(challenge)
 	}
 
-	pub async fn create(state: &AppState, user_id: i32) -> Result<Self, Error> {
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<DiffieHellmanExchangeChallenge> {
 		let n_min = env::var("DIFFIE_HELLMAN_N_MIN")
 			.ok()
 			.and_then(|value| value.parse::<usize>().ok())
@@ -99,100 +90,38 @@ impl DiffieHellmanExchange {
 			.and_then(|value| value.parse::<usize>().ok())
 			.unwrap_or(10_000);
 
-		let n = Sieve::new(n_max)
-			.primes_from(n_min)
-			.take_while(|n| *n <= n_max)
-			.choose(&mut rand::rng())
-			.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		let g = math::primitive(n).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		let sk_server_min = n / 2;
-		let sk_server_max = n - 1;
-		let sk_server = rand::random_range(sk_server_min..=sk_server_max);
+		DiffieHellmanExchangeChallenge::generate(rng, models::key_size(difficulty, n_min..=n_max))
+	}
 
-		let new_exchange = NewDiffieHellmanExchange {
+	fn new_row(
+		user_id: i32,
+		challenge: DiffieHellmanExchangeChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewDiffieHellmanExchange {
+		NewDiffieHellmanExchange {
 			user_id,
 
-			g: g as i64,
-			n: n as i64,
-			sk_server: sk_server as i64,
-		};
-
-		let mut db = state.db().await?;
+			g: challenge.g.to_string(),
+			n: challenge.n.to_string(),
+			sk_server: challenge.sk_server.to_string(),
 
-		let exchange = diesel::insert_into(schema::diffie_hellman_exchanges::table)
-			.values(&new_exchange)
-			.returning(DiffieHellmanExchange::as_returning())
-			.get_result(&mut db)
-			.await?;
-
-		exchange.to_cached(state.cache(), user_id)?;
-
-		Ok(exchange)
-	}
-
-	pub async fn try_into_completed(
-		self,
-		state: &AppState,
-		pk_user: u64,
-		k: u64,
-	) -> Result<Duration, Error> {
-		if k != math::power_mod(pk_user, self.sk_server, self.n) {
-			let error = Error::default()
-				.with_code(StatusCode::BAD_REQUEST)
-				.with_message("Incorrect derived key.");
-
-			return Err(error);
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
 		}
-
-		let mut db = state.db().await?;
-
-		let completed = diesel::update(
-			schema::diffie_hellman_exchanges::dsl::diffie_hellman_exchanges.find(self.id),
-		)
-		.set(schema::diffie_hellman_exchanges::dsl::completed_at.eq(diesel::dsl::now))
-		.get_result::<Self>(&mut db)
-		.await?;
-
-		DiffieHellmanExchange::purge_cache(state.cache(), self.user_id)?;
-
-		let duration = completed
-			.completed_duration()
-			.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		Ok(duration)
-	}
-
-	pub async fn find_all_completed(state: &AppState) -> Result<Vec<Self>, Error> {
-		let mut db = state.db().await?;
-
-		let got = schema::diffie_hellman_exchanges::dsl::diffie_hellman_exchanges
-			.filter(schema::diffie_hellman_exchanges::completed_at.is_not_null())
-			.select(DiffieHellmanExchange::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.collect();
-
-		Ok(got)
-	}
-}
-
-impl Cacheable for DiffieHellmanExchange {
-	type Id = i32;
-
-	fn cache_key(user_id: Self::Id) -> String {
-		format!("diffie-hellman-exchange:{user_id}:incomplete")
 	}
-}
 
-impl From<DiffieHellmanExchange> for DiffieHellmanExchangePublic {
-	fn from(exchange: DiffieHellmanExchange) -> Self {
-		DiffieHellmanExchangePublic {
-			g:         exchange.g as u64,
-			n:         exchange.n as u64,
-			pk_server: exchange.pk_server(),
+	fn mistake(
+		&self,
+		submission: &DiffieHellmanExchangeSubmission,
+	) -> Result<Option<String>, Error> {
+		if self
+			.challenge()?
+			.verify(&submission.pk_user, &submission.k)
+		{
+			return Ok(None);
 		}
+
+		Ok(Some("Incorrect derived key.".to_string()))
 	}
 }
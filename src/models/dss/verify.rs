@@ -1,19 +1,18 @@
-use std::{env, time::Duration};
+use std::env;
 
-use axum::http::StatusCode;
 use chrono::{DateTime, Utc};
-use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
-use num_traits::AsPrimitive;
-use primal_sieve::Sieve;
-use rand::seq::{IndexedRandom, IteratorRandom};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+	challenge::{
+		Difficulty,
+		dss::{DssVerifyChallenge, DssVerifyPublic, DssVerifySubmission},
+	},
 	error::Error,
-	math,
+	models::{self, challenge_model::impl_challenge_model},
 	schema,
-	state::{AppState, Cacheable},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
@@ -23,17 +22,23 @@ pub struct DssVerify {
 	id:          i32,
 	pub user_id: i32,
 
-	n_p: i64,
-	n_q: i64,
-	g:   i64,
+	n_p: String,
+	n_q: String,
+	g:   String,
 	h:   String,
-	pk:  i64,
-	m:   i64,
-	r:   i64,
-	s:   i64,
+	pk:  String,
+	m:   String,
+	r:   String,
+	s:   String,
 
 	created_at:   DateTime<Utc>,
 	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
 }
 
 #[derive(Insertable)]
@@ -41,65 +46,51 @@ pub struct DssVerify {
 struct NewDssVerify {
 	user_id: i32,
 
-	n_p: i64,
-	n_q: i64,
-	g:   i64,
+	n_p: String,
+	n_q: String,
+	g:   String,
 	h:   String,
-	pk:  i64,
-	m:   i64,
-	r:   i64,
-	s:   i64,
+	pk:  String,
+	m:   String,
+	r:   String,
+	s:   String,
+
+	difficulty: String,
+	seed:       i64,
 }
 
-#[derive(Serialize)]
-pub struct DssVerifyPublic {
-	p:  i64,
-	q:  i64,
-	g:  i64,
-	h:  String,
-	pk: i64,
-	m:  i64,
-	r:  i64,
-	s:  i64,
+impl_challenge_model! {
+	model:      DssVerify,
+	table:      dss_verifies,
+	pool:       dss_verify,
+	name:       "dss verify",
+	cache_key:  "rsa:verify",
+	expiry:     "DSS_VERIFY_EXPIRY_SECONDS",
+	challenge:  DssVerifyChallenge,
+	public:     DssVerifyPublic,
+	submission: DssVerifySubmission,
 }
 
 impl DssVerify {
-	pub fn completed_duration(&self) -> Option<Duration> {
-		let delta = self
-			.completed_at?
-			.signed_duration_since(self.created_at);
-		let nanoseconds = delta.num_nanoseconds()? as u64;
-
-		Some(Duration::from_nanos(nanoseconds))
-	}
-
-	pub async fn find_user_incomplete(
-		state: &AppState,
-		user_id: i32,
-	) -> Result<Option<Self>, Error> {
-		if let Some(cached_incomplete) = DssVerify::from_cached(state.cache(), user_id)? {
-			return Ok(Some(cached_incomplete));
-		}
-
-		let mut db = state.db().await?;
-
-		let maybe_got = schema::dss_verifies::dsl::dss_verifies
-			.filter(schema::dss_verifies::user_id.eq(user_id))
-			.filter(schema::dss_verifies::completed_at.is_null())
-			.select(DssVerify::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.next();
-
-		if let Some(got) = &maybe_got {
-			got.to_cached(state.cache(), user_id)?;
-		}
+	pub fn challenge(&self) -> Result<DssVerifyChallenge, Error> {
+		let challenge = DssVerifyChallenge {
+			p:  models::parse_big(&self.n_p)?,
+			q:  models::parse_big(&self.n_q)?,
+			g:  models::parse_big(&self.g)?,
+			h:  self.h.clone(),
+			pk: models::parse_big(&self.pk)?,
+			m:  models::parse_big(&self.m)?,
+			r:  models::parse_big(&self.r)?,
+			s:  models::parse_big(&self.s)?,
+		};
 
-		Ok(maybe_got)
+		Ok(challenge)
 	}
 
-	pub async fn create(state: &AppState, user_id: i32) -> Result<Self, Error> {
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<DssVerifyChallenge> {
 		let p_min = env::var("DSS_P_MIN")
 			.ok()
 			.and_then(|value| value.parse::<usize>().ok())
@@ -110,203 +101,40 @@ impl DssVerify {
 			.and_then(|value| value.parse::<usize>().ok())
 			.unwrap_or(10_000);
 
-		let sieve = Sieve::new(p_max);
-
-		let mut p = sieve
-			.primes_from(p_min)
-			.take_while(|n| *n <= p_max)
-			.choose(&mut rand::rng())
-			.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		let mut q: u64;
-		let mut g: u64;
-
-		loop {
-			let mut factors = math::prime_factors(p - 1);
-
-			while factors.is_empty() {
-				p = sieve
-					.primes_from(p_min)
-					.take_while(|n| *n <= p_max)
-					.choose(&mut rand::rng())
-					.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-				factors = math::prime_factors(p - 1);
-			}
-
-			q = factors
-				.into_iter()
-				.last()
-				.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-			g = 2;
-
-			while math::order(g, p).is_none_or(|order| order != q) && g <= p as u64 {
-				g += 1;
-			}
-
-			if math::order(g, p).is_some_and(|order| order == q) {
-				break;
-			}
-		}
-
-		let h = get_random_h()?;
-		let mut k: u64;
-		let mut r: u64;
-
-		loop {
-			k = rand::random_range(1..q);
-			r = math::safe_mod(math::power_mod(g, k, p), q);
-
-			if r > 0 {
-				break;
-			}
-		}
-
-		let sk = rand::random_range(1..q);
-		let pk = math::power_mod(g, sk, p);
-
-		let m_min = p / 2;
-		let m_max = p - 1;
-		let mut m = rand::random_range(m_min..=m_max);
-		let mut digest = get_h_digest(&h, m, q)?;
-
-		while digest == 0 {
-			m = rand::random_range(m_min..=m_max);
-			digest = get_h_digest(&h, m, q)?;
-		}
-
-		let k_inv_q = math::inverse_mod(k, q).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-		let s = math::safe_mod((digest as i64 - sk as i64 * r as i64) * k_inv_q as i64, q);
-
-		let new_verify = NewDssVerify {
-			user_id,
-
-			n_p: p as i64,
-			n_q: q as i64,
-			g: g as i64,
-			h,
-			pk: pk as i64,
-			m: m as i64,
-			r: r as i64,
-			s: s as i64,
-		};
-
-		let mut db = state.db().await?;
-
-		let verify = diesel::insert_into(schema::dss_verifies::table)
-			.values(&new_verify)
-			.returning(DssVerify::as_returning())
-			.get_result(&mut db)
-			.await?;
-
-		verify.to_cached(state.cache(), user_id)?;
-
-		Ok(verify)
+		DssVerifyChallenge::generate(rng, models::key_size(difficulty, p_min..=p_max))
 	}
 
-	pub async fn try_into_completed(
-		self,
-		state: &AppState,
-		u: u64,
-		v: u64,
-		w: u64,
-	) -> Result<Duration, Error> {
-		let error = Error::default()
-			.with_code(StatusCode::BAD_REQUEST)
-			.with_message("Incorrect signature.");
-
-		if self.pk >= self.n_p || self.r >= self.n_q || self.s >= self.n_q {
-			return Err(error);
-		}
-
-		let digest = get_h_digest(&self.h, self.m, self.n_q)?;
-		let s_inv_q =
-			math::inverse_mod(self.s, self.n_q).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		let correct_u = math::safe_mod(digest * s_inv_q, self.n_q);
-		let correct_v = math::safe_mod(math::safe_mod(-self.r, self.n_q) * s_inv_q, self.n_q);
-
-		let correct_w = math::safe_mod(
-			math::safe_mod(
-				math::power_mod(self.g, u, self.n_p) * math::power_mod(self.pk, v, self.n_p),
-				self.n_p,
-			),
-			self.n_q,
-		);
+	fn new_row(
+		user_id: i32,
+		challenge: DssVerifyChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewDssVerify {
+		NewDssVerify {
+			user_id,
 
-		if u != correct_u || v != correct_v || w != correct_w || correct_w != self.r as u64 {
-			return Err(error);
+			n_p: challenge.p.to_string(),
+			n_q: challenge.q.to_string(),
+			g: challenge.g.to_string(),
+			h: challenge.h,
+			pk: challenge.pk.to_string(),
+			m: challenge.m.to_string(),
+			r: challenge.r.to_string(),
+			s: challenge.s.to_string(),
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
 		}
-
-		let mut db = state.db().await?;
-
-		let completed = diesel::update(schema::dss_verifies::dsl::dss_verifies.find(self.id))
-			.set(schema::dss_verifies::dsl::completed_at.eq(diesel::dsl::now))
-			.get_result::<Self>(&mut db)
-			.await?;
-
-		DssVerify::purge_cache(state.cache(), self.user_id)?;
-
-		let duration = completed
-			.completed_duration()
-			.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-
-		Ok(duration)
 	}
 
-	pub async fn find_all_completed(state: &AppState) -> Result<Vec<Self>, Error> {
-		let mut db = state.db().await?;
-
-		let got = schema::dss_verifies::dsl::dss_verifies
-			.filter(schema::dss_verifies::completed_at.is_not_null())
-			.select(DssVerify::as_select())
-			.load(&mut db)
-			.await?
-			.into_iter()
-			.collect();
-
-		Ok(got)
-	}
-}
-
-fn get_random_h() -> Result<String, Error> {
-	[
-		"m mod q", "2m mod q", "3m mod q",
-	]
-	.choose(&mut rand::rng())
-	.map(|value| value.to_string())
-	.ok_or(Error::default())
-}
-
-fn get_h_digest(h: &str, m: impl AsPrimitive<u64>, q: impl AsPrimitive<u64>) -> Result<u64, Error> {
-	match h {
-		"m mod q" => Ok(math::safe_mod(m.as_(), q.as_())),
-		"2m mod q" => Ok(math::safe_mod(m.as_() * 2, q.as_())),
-		"3m mod q" => Ok(math::safe_mod(m.as_() * 3, q.as_())),
-
-		_ => Err(Error::default()),
-	}
-}
-
-impl Cacheable for DssVerify {
-	type Id = i32;
-
-	fn cache_key(user_id: Self::Id) -> String {
-		format!("rsa:verify:{user_id}:incomplete")
-	}
-}
-
-impl From<DssVerify> for DssVerifyPublic {
-	fn from(verify: DssVerify) -> Self {
-		DssVerifyPublic {
-			p:  verify.n_p,
-			q:  verify.n_q,
-			g:  verify.g,
-			h:  verify.h,
-			pk: verify.pk,
-			m:  verify.m,
-			r:  verify.r,
-			s:  verify.s,
+	fn mistake(&self, submission: &DssVerifySubmission) -> Result<Option<String>, Error> {
+		if self
+			.challenge()?
+			.verify(&submission.u, &submission.v, &submission.w)
+		{
+			return Ok(None);
 		}
+
+		Ok(Some("Incorrect signature.".to_string()))
 	}
 }
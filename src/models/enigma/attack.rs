@@ -0,0 +1,134 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		enigma::{EnigmaAttackChallenge, EnigmaAttackPublic, EnigmaAttackSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, enigma},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::enigma_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EnigmaAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	crib:      String,
+	cipher:    String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::enigma_attacks)]
+struct NewEnigmaAttack {
+	user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	crib:      String,
+	cipher:    String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      EnigmaAttack,
+	table:      enigma_attacks,
+	pool:       enigma_attack,
+	name:       "enigma attack",
+	cache_key:  "enigma:attack",
+	expiry:     "ENIGMA_ATTACK_EXPIRY_SECONDS",
+	challenge:  EnigmaAttackChallenge,
+	public:     EnigmaAttackPublic,
+	submission: EnigmaAttackSubmission,
+}
+
+impl EnigmaAttack {
+	pub fn challenge(&self) -> Result<EnigmaAttackChallenge, Error> {
+		let challenge = EnigmaAttackChallenge {
+			rotors:    self.rotors.clone(),
+			rings:     self.rings.clone(),
+			positions: self.positions.clone(),
+			plugboard: self.plugboard.clone(),
+			crib:      self.crib.clone(),
+			cipher:    self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<EnigmaAttackChallenge> {
+		let crib_size = env::var("ENIGMA_ATTACK_CRIB_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(10);
+
+		let message_size = env::var("ENIGMA_ATTACK_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(30);
+
+		Some(EnigmaAttackChallenge::generate(
+			rng,
+			enigma::plugboard_pairs(difficulty),
+			crib_size,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: EnigmaAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewEnigmaAttack {
+		NewEnigmaAttack {
+			user_id,
+
+			rotors: challenge.rotors,
+			rings: challenge.rings,
+			positions: challenge.positions,
+			plugboard: challenge.plugboard,
+			crib: challenge.crib,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &EnigmaAttackSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.positions) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect positions.".to_string()))
+	}
+}
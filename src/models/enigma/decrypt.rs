@@ -0,0 +1,124 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		enigma::{EnigmaDecryptChallenge, EnigmaDecryptPublic, EnigmaDecryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, enigma},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::enigma_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EnigmaDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	cipher:    String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::enigma_decrypts)]
+struct NewEnigmaDecrypt {
+	user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	cipher:    String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      EnigmaDecrypt,
+	table:      enigma_decrypts,
+	pool:       enigma_decrypt,
+	name:       "enigma decrypt",
+	cache_key:  "enigma:decrypt",
+	expiry:     "ENIGMA_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  EnigmaDecryptChallenge,
+	public:     EnigmaDecryptPublic,
+	submission: EnigmaDecryptSubmission,
+}
+
+impl EnigmaDecrypt {
+	pub fn challenge(&self) -> Result<EnigmaDecryptChallenge, Error> {
+		let challenge = EnigmaDecryptChallenge {
+			rotors:    self.rotors.clone(),
+			rings:     self.rings.clone(),
+			positions: self.positions.clone(),
+			plugboard: self.plugboard.clone(),
+			cipher:    self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<EnigmaDecryptChallenge> {
+		let cipher_size = env::var("ENIGMA_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(EnigmaDecryptChallenge::generate(
+			rng,
+			enigma::plugboard_pairs(difficulty),
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: EnigmaDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewEnigmaDecrypt {
+		NewEnigmaDecrypt {
+			user_id,
+
+			rotors: challenge.rotors,
+			rings: challenge.rings,
+			positions: challenge.positions,
+			plugboard: challenge.plugboard,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &EnigmaDecryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
@@ -0,0 +1,124 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		enigma::{EnigmaEncryptChallenge, EnigmaEncryptPublic, EnigmaEncryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, enigma},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::enigma_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EnigmaEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	message:   String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::enigma_encrypts)]
+struct NewEnigmaEncrypt {
+	user_id: i32,
+
+	rotors:    String,
+	rings:     String,
+	positions: String,
+	plugboard: String,
+	message:   String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      EnigmaEncrypt,
+	table:      enigma_encrypts,
+	pool:       enigma_encrypt,
+	name:       "enigma encrypt",
+	cache_key:  "enigma:encrypt",
+	expiry:     "ENIGMA_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  EnigmaEncryptChallenge,
+	public:     EnigmaEncryptPublic,
+	submission: EnigmaEncryptSubmission,
+}
+
+impl EnigmaEncrypt {
+	pub fn challenge(&self) -> Result<EnigmaEncryptChallenge, Error> {
+		let challenge = EnigmaEncryptChallenge {
+			rotors:    self.rotors.clone(),
+			rings:     self.rings.clone(),
+			positions: self.positions.clone(),
+			plugboard: self.plugboard.clone(),
+			message:   self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<EnigmaEncryptChallenge> {
+		let message_size = env::var("ENIGMA_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(EnigmaEncryptChallenge::generate(
+			rng,
+			enigma::plugboard_pairs(difficulty),
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: EnigmaEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewEnigmaEncrypt {
+		NewEnigmaEncrypt {
+			user_id,
+
+			rotors: challenge.rotors,
+			rings: challenge.rings,
+			positions: challenge.positions,
+			plugboard: challenge.plugboard,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &EnigmaEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
+	}
+}
@@ -0,0 +1,23 @@
+mod encrypt;
+pub use crate::models::enigma::encrypt::*;
+
+mod decrypt;
+pub use crate::models::enigma::decrypt::*;
+
+mod attack;
+pub use crate::models::enigma::attack::*;
+
+use std::env;
+
+use crate::challenge::{Difficulty, enigma};
+
+/// Cables on the plugboard, configured for medium difficulty and capped at the
+/// 13 pairs the alphabet allows.
+pub(crate) fn plugboard_pairs(difficulty: Difficulty) -> usize {
+	let pairs = env::var("ENIGMA_PLUGBOARD_PAIRS")
+		.ok()
+		.and_then(|value| value.parse::<usize>().ok())
+		.unwrap_or(enigma::PLUGBOARD_PAIRS);
+
+	difficulty.scale_size(pairs).min(13)
+}
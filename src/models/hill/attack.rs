@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		hill::{HillAttackChallenge, HillAttackPublic, HillAttackSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, hill},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::hill_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct HillAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::hill_attacks)]
+struct NewHillAttack {
+	user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      HillAttack,
+	table:      hill_attacks,
+	pool:       hill_attack,
+	name:       "hill attack",
+	cache_key:  "hill:attack",
+	expiry:     "HILL_ATTACK_EXPIRY_SECONDS",
+	challenge:  HillAttackChallenge,
+	public:     HillAttackPublic,
+	submission: HillAttackSubmission,
+}
+
+impl HillAttack {
+	pub fn challenge(&self) -> Result<HillAttackChallenge, Error> {
+		let challenge = HillAttackChallenge {
+			key:     self.key.clone(),
+			message: self.message.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	/// Always as many known blocks as the key has rows, the fewest that pin
+	/// down the key.
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<HillAttackChallenge> {
+		Some(HillAttackChallenge::generate(
+			rng,
+			hill::key_size(difficulty),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: HillAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewHillAttack {
+		NewHillAttack {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &HillAttackSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.key) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect key.".to_string()))
+	}
+}
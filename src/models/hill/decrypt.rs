@@ -0,0 +1,112 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		hill::{HillDecryptChallenge, HillDecryptPublic, HillDecryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, hill},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::hill_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct HillDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	key:    String,
+	cipher: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::hill_decrypts)]
+struct NewHillDecrypt {
+	user_id: i32,
+
+	key:    String,
+	cipher: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      HillDecrypt,
+	table:      hill_decrypts,
+	pool:       hill_decrypt,
+	name:       "hill decrypt",
+	cache_key:  "hill:decrypt",
+	expiry:     "HILL_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  HillDecryptChallenge,
+	public:     HillDecryptPublic,
+	submission: HillDecryptSubmission,
+}
+
+impl HillDecrypt {
+	pub fn challenge(&self) -> Result<HillDecryptChallenge, Error> {
+		let challenge = HillDecryptChallenge {
+			key:    self.key.clone(),
+			cipher: self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<HillDecryptChallenge> {
+		let cipher_size = env::var("HILL_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(6);
+
+		Some(HillDecryptChallenge::generate(
+			rng,
+			hill::key_size(difficulty),
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: HillDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewHillDecrypt {
+		NewHillDecrypt {
+			user_id,
+
+			key: challenge.key,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &HillDecryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
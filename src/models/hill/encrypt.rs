@@ -0,0 +1,112 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		hill::{HillEncryptChallenge, HillEncryptPublic, HillEncryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, hill},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::hill_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct HillEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     String,
+	message: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::hill_encrypts)]
+struct NewHillEncrypt {
+	user_id: i32,
+
+	key:     String,
+	message: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      HillEncrypt,
+	table:      hill_encrypts,
+	pool:       hill_encrypt,
+	name:       "hill encrypt",
+	cache_key:  "hill:encrypt",
+	expiry:     "HILL_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  HillEncryptChallenge,
+	public:     HillEncryptPublic,
+	submission: HillEncryptSubmission,
+}
+
+impl HillEncrypt {
+	pub fn challenge(&self) -> Result<HillEncryptChallenge, Error> {
+		let challenge = HillEncryptChallenge {
+			key:     self.key.clone(),
+			message: self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<HillEncryptChallenge> {
+		let message_size = env::var("HILL_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(6);
+
+		Some(HillEncryptChallenge::generate(
+			rng,
+			hill::key_size(difficulty),
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: HillEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewHillEncrypt {
+		NewHillEncrypt {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &HillEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
+	}
+}
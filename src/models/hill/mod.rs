@@ -0,0 +1,18 @@
+mod encrypt;
+pub use crate::models::hill::encrypt::*;
+
+mod decrypt;
+pub use crate::models::hill::decrypt::*;
+
+mod attack;
+pub use crate::models::hill::attack::*;
+
+use crate::challenge::Difficulty;
+
+/// Rows of the key matrix: 2×2 keys up to medium difficulty, 3×3 above.
+pub(crate) fn key_size(difficulty: Difficulty) -> usize {
+	match difficulty {
+		Difficulty::Easy | Difficulty::Medium => 2,
+		Difficulty::Hard | Difficulty::Advanced | Difficulty::Expert => 3,
+	}
+}
@@ -1,5 +1,127 @@
+pub mod affine;
 pub mod caesar;
+pub mod challenge_model;
+pub mod columnar;
 pub mod diffie_hellman_exchange;
 pub mod dss;
+pub mod enigma;
+pub mod hill;
+pub mod playfair;
+pub mod rail_fence;
 pub mod rsa;
+pub mod submission;
+pub mod substitution;
 pub mod user;
+pub mod vigenere;
+
+use std::{collections::HashMap, env, ops::RangeInclusive, time::Duration};
+
+use axum::http::StatusCode;
+use diesel::{
+	QueryableByName,
+	dsl::sql,
+	expression::SqlLiteral,
+	sql_types::{BigInt, Double, Integer, Text, Timestamptz},
+};
+use diesel_async::RunQueryDsl;
+
+use crate::{
+	challenge::{Difficulty, KeySize},
+	error::Error,
+	math::big::{self, BigUint},
+	state::AppState,
+};
+
+/// The smallest configurable key size.
+pub const MIN_KEY_BITS: u64 = 32;
+
+#[derive(QueryableByName)]
+struct Rank {
+	#[diesel(sql_type = Text)]
+	difficulty: String,
+
+	#[diesel(sql_type = BigInt)]
+	rank: i64,
+}
+
+/// Time added to a completed attempt for every hint taken.
+pub fn hint_penalty() -> Duration {
+	let seconds = env::var("HINT_PENALTY_SECONDS")
+		.ok()
+		.and_then(|value| value.parse::<u64>().ok())
+		.unwrap_or(30);
+
+	Duration::from_secs(seconds)
+}
+
+/// When a challenge table's attempt started: on delivery, or on creation for
+/// attempts that predate delivery tracking.
+pub(crate) fn started_at() -> SqlLiteral<Timestamptz> {
+	sql("COALESCE(delivered_at, created_at)")
+}
+
+/// Sizes the numbers of a number-theory challenge. Tiers with a key size use
+/// `<DIFFICULTY>_KEY_BITS` when set; the rest scale the configured range. Key
+/// sizes below `MIN_KEY_BITS` are ignored like unparsable ones, since RSA
+/// splits the bits between two primes and DSS halves them for the subgroup.
+pub fn key_size(difficulty: Difficulty, range: RangeInclusive<usize>) -> KeySize {
+	let Some(default_bits) = difficulty.key_bits() else {
+		return KeySize::Range(difficulty.scale_range(range));
+	};
+
+	let bits = env::var(format!("{}_KEY_BITS", difficulty.as_str().to_uppercase()))
+		.ok()
+		.and_then(|value| value.parse::<u64>().ok())
+		.filter(|bits| *bits >= MIN_KEY_BITS)
+		.unwrap_or(default_bits);
+
+	KeySize::Bits(bits)
+}
+
+/// Parses a number stored as decimal text. Text that does not parse means the
+/// row is corrupt, so it surfaces as an error rather than a silent zero.
+pub fn parse_big(value: &str) -> Result<BigUint, Error> {
+	big::parse(value).ok_or_else(|| {
+		Error::default()
+			.with_code(StatusCode::INTERNAL_SERVER_ERROR)
+			.with_message("A stored challenge number is corrupt.")
+	})
+}
+
+/// Ranks a user on the leaderboard of every difficulty they have completed a
+/// challenge at, by their best duration including hint penalties. `table` is
+/// interpolated into the query, so it must be one of the challenge tables.
+pub(crate) async fn ranks(
+	state: &AppState,
+	table: &'static str,
+	user_id: i32,
+) -> Result<HashMap<Difficulty, usize>, Error> {
+	let mut db = state.db().await?;
+
+	let query = format!(
+		"SELECT difficulty, rank FROM (
+			SELECT user_id, difficulty, RANK() OVER (
+				PARTITION BY difficulty
+				ORDER BY MIN(
+					completed_at - COALESCE(delivered_at, created_at)
+						+ hints_used * $1 * INTERVAL '1 second'
+				)
+			) AS rank
+			FROM {table}
+			WHERE completed_at IS NOT NULL
+			GROUP BY user_id, difficulty
+		) AS ranked
+		WHERE user_id = $2"
+	);
+
+	let ranks = diesel::sql_query(query)
+		.bind::<Double, _>(hint_penalty().as_secs_f64())
+		.bind::<Integer, _>(user_id)
+		.load::<Rank>(&mut db)
+		.await?
+		.into_iter()
+		.filter_map(|rank| Some((rank.difficulty.parse().ok()?, rank.rank as usize)))
+		.collect();
+
+	Ok(ranks)
+}
@@ -0,0 +1,109 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		playfair::{PlayfairDecryptChallenge, PlayfairDecryptPublic, PlayfairDecryptSubmission},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::playfair_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PlayfairDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	keyword: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::playfair_decrypts)]
+struct NewPlayfairDecrypt {
+	user_id: i32,
+
+	keyword: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      PlayfairDecrypt,
+	table:      playfair_decrypts,
+	pool:       playfair_decrypt,
+	name:       "playfair decrypt",
+	cache_key:  "playfair:decrypt",
+	expiry:     "PLAYFAIR_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  PlayfairDecryptChallenge,
+	public:     PlayfairDecryptPublic,
+	submission: PlayfairDecryptSubmission,
+}
+
+impl PlayfairDecrypt {
+	pub fn challenge(&self) -> Result<PlayfairDecryptChallenge, Error> {
+		let challenge = PlayfairDecryptChallenge {
+			keyword: self.keyword.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<PlayfairDecryptChallenge> {
+		let cipher_size = env::var("PLAYFAIR_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(10);
+
+		Some(PlayfairDecryptChallenge::generate(
+			rng,
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: PlayfairDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewPlayfairDecrypt {
+		NewPlayfairDecrypt {
+			user_id,
+
+			keyword: challenge.keyword,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &PlayfairDecryptSubmission) -> Result<Option<String>, Error> {
+		let mistake = self.challenge()?.mistake(&submission.message);
+
+		Ok(mistake.map(|mistake| format!("Incorrect message: {mistake}.")))
+	}
+}
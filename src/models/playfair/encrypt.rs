@@ -0,0 +1,109 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		playfair::{PlayfairEncryptChallenge, PlayfairEncryptPublic, PlayfairEncryptSubmission},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::playfair_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PlayfairEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	keyword: String,
+	message: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::playfair_encrypts)]
+struct NewPlayfairEncrypt {
+	user_id: i32,
+
+	keyword: String,
+	message: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      PlayfairEncrypt,
+	table:      playfair_encrypts,
+	pool:       playfair_encrypt,
+	name:       "playfair encrypt",
+	cache_key:  "playfair:encrypt",
+	expiry:     "PLAYFAIR_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  PlayfairEncryptChallenge,
+	public:     PlayfairEncryptPublic,
+	submission: PlayfairEncryptSubmission,
+}
+
+impl PlayfairEncrypt {
+	pub fn challenge(&self) -> Result<PlayfairEncryptChallenge, Error> {
+		let challenge = PlayfairEncryptChallenge {
+			keyword: self.keyword.clone(),
+			message: self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<PlayfairEncryptChallenge> {
+		let message_size = env::var("PLAYFAIR_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(10);
+
+		Some(PlayfairEncryptChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: PlayfairEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewPlayfairEncrypt {
+		NewPlayfairEncrypt {
+			user_id,
+
+			keyword: challenge.keyword,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &PlayfairEncryptSubmission) -> Result<Option<String>, Error> {
+		let mistake = self.challenge()?.mistake(&submission.cipher);
+
+		Ok(mistake.map(|mistake| format!("Incorrect cipher: {mistake}.")))
+	}
+}
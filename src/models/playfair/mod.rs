@@ -0,0 +1,5 @@
+mod encrypt;
+pub use crate::models::playfair::encrypt::*;
+
+mod decrypt;
+pub use crate::models::playfair::decrypt::*;
@@ -0,0 +1,115 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		rail_fence::{
+			RailFenceDecryptChallenge,
+			RailFenceDecryptPublic,
+			RailFenceDecryptSubmission,
+		},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::rail_fence_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RailFenceDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	rails:  i32,
+	cipher: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::rail_fence_decrypts)]
+struct NewRailFenceDecrypt {
+	user_id: i32,
+
+	rails:  i32,
+	cipher: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      RailFenceDecrypt,
+	table:      rail_fence_decrypts,
+	pool:       rail_fence_decrypt,
+	name:       "rail fence decrypt",
+	cache_key:  "rail_fence:decrypt",
+	expiry:     "RAIL_FENCE_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  RailFenceDecryptChallenge,
+	public:     RailFenceDecryptPublic,
+	submission: RailFenceDecryptSubmission,
+}
+
+impl RailFenceDecrypt {
+	pub fn challenge(&self) -> Result<RailFenceDecryptChallenge, Error> {
+		let challenge = RailFenceDecryptChallenge {
+			rails:  self.rails,
+			cipher: self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<RailFenceDecryptChallenge> {
+		let cipher_size = env::var("RAIL_FENCE_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(RailFenceDecryptChallenge::generate(
+			rng,
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: RailFenceDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewRailFenceDecrypt {
+		NewRailFenceDecrypt {
+			user_id,
+
+			rails: challenge.rails,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &RailFenceDecryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
@@ -0,0 +1,115 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		rail_fence::{
+			RailFenceEncryptChallenge,
+			RailFenceEncryptPublic,
+			RailFenceEncryptSubmission,
+		},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::rail_fence_encrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RailFenceEncrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	rails:   i32,
+	message: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::rail_fence_encrypts)]
+struct NewRailFenceEncrypt {
+	user_id: i32,
+
+	rails:   i32,
+	message: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      RailFenceEncrypt,
+	table:      rail_fence_encrypts,
+	pool:       rail_fence_encrypt,
+	name:       "rail fence encrypt",
+	cache_key:  "rail_fence:encrypt",
+	expiry:     "RAIL_FENCE_ENCRYPTION_EXPIRY_SECONDS",
+	challenge:  RailFenceEncryptChallenge,
+	public:     RailFenceEncryptPublic,
+	submission: RailFenceEncryptSubmission,
+}
+
+impl RailFenceEncrypt {
+	pub fn challenge(&self) -> Result<RailFenceEncryptChallenge, Error> {
+		let challenge = RailFenceEncryptChallenge {
+			rails:   self.rails,
+			message: self.message.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<RailFenceEncryptChallenge> {
+		let message_size = env::var("RAIL_FENCE_ENCRYPTION_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(RailFenceEncryptChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: RailFenceEncryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewRailFenceEncrypt {
+		NewRailFenceEncrypt {
+			user_id,
+
+			rails: challenge.rails,
+			message: challenge.message,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &RailFenceEncryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.cipher) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect cipher.".to_string()))
+	}
+}
@@ -0,0 +1,5 @@
+mod encrypt;
+pub use crate::models::rail_fence::encrypt::*;
+
+mod decrypt;
+pub use crate::models::rail_fence::decrypt::*;
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+	challenge::ChallengeKind,
+	error::Error,
+	history::HistoryAttempt,
+	schema,
+	state::AppState,
+};
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = schema::submissions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct Submission {
+	pub id:      i32,
+	pub user_id: i32,
+
+	challenge:        String,
+	pub challenge_id: i32,
+	pub payload:      Value,
+	pub correct:      bool,
+
+	pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::submissions)]
+struct NewSubmission {
+	user_id: i32,
+
+	challenge:    String,
+	challenge_id: i32,
+	payload:      Value,
+	correct:      bool,
+}
+
+impl Submission {
+	pub fn challenge(&self) -> Option<ChallengeKind> {
+		self.challenge.parse().ok()
+	}
+
+	pub async fn create(
+		state: &AppState,
+		user_id: i32,
+		challenge: ChallengeKind,
+		challenge_id: i32,
+		payload: &impl Serialize,
+		correct: bool,
+	) -> Result<Self, Error> {
+		let new_submission = NewSubmission {
+			user_id,
+
+			challenge: challenge.to_string(),
+			challenge_id,
+			payload: serde_json::to_value(payload)?,
+			correct,
+		};
+
+		let mut db = state.db().await?;
+
+		let submission = diesel::insert_into(schema::submissions::table)
+			.values(&new_submission)
+			.returning(Submission::as_returning())
+			.get_result(&mut db)
+			.await?;
+
+		Ok(submission)
+	}
+
+	/// Returns a user's attempts at the given challenges, oldest first and
+	/// keyed by the challenge they were made against.
+	pub async fn find_attempts(
+		state: &AppState,
+		user_id: i32,
+		challenges: &[(ChallengeKind, i32)],
+	) -> Result<HashMap<(ChallengeKind, i32), Vec<HistoryAttempt>>, Error> {
+		let mut db = state.db().await?;
+
+		let submissions = schema::submissions::dsl::submissions
+			.filter(schema::submissions::user_id.eq(user_id))
+			.filter(schema::submissions::challenge_id.eq_any(challenges.iter().map(|(_, id)| *id)))
+			.order(schema::submissions::created_at.asc())
+			.select(Submission::as_select())
+			.load(&mut db)
+			.await?;
+
+		let mut attempts = HashMap::<_, Vec<_>>::new();
+
+		for submission in submissions {
+			let Some(challenge) = submission.challenge() else {
+				continue;
+			};
+
+			if !challenges.contains(&(challenge, submission.challenge_id)) {
+				continue;
+			}
+
+			attempts
+				.entry((challenge, submission.challenge_id))
+				.or_default()
+				.push(submission.into());
+		}
+
+		Ok(attempts)
+	}
+}
+
+impl From<Submission> for HistoryAttempt {
+	fn from(submission: Submission) -> Self {
+		HistoryAttempt {
+			payload:      submission.payload,
+			correct:      submission.correct,
+			submitted_at: submission.created_at,
+		}
+	}
+}
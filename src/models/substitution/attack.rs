@@ -0,0 +1,143 @@
+use std::env;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		substitution::{
+			SubstitutionAttackChallenge,
+			SubstitutionAttackPublic,
+			SubstitutionAttackSubmission,
+		},
+	},
+	error::Error,
+	models::challenge_model::impl_challenge_model,
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::substitution_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SubstitutionAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::substitution_attacks)]
+struct NewSubstitutionAttack {
+	user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      SubstitutionAttack,
+	table:      substitution_attacks,
+	pool:       substitution_attack,
+	name:       "substitution attack",
+	cache_key:  "substitution:attack",
+	expiry:     "SUBSTITUTION_ATTACK_EXPIRY_SECONDS",
+	challenge:  SubstitutionAttackChallenge,
+	public:     SubstitutionAttackPublic,
+	submission: SubstitutionAttackSubmission,
+}
+
+impl SubstitutionAttack {
+	pub fn challenge(&self) -> Result<SubstitutionAttackChallenge, Error> {
+		let challenge = SubstitutionAttackChallenge {
+			key:     self.key.clone(),
+			message: self.message.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	/// Fraction of the cipher's letters a submission has to decrypt correctly.
+	pub fn threshold() -> f64 {
+		env::var("SUBSTITUTION_ATTACK_THRESHOLD")
+			.ok()
+			.and_then(|value| value.parse::<f64>().ok())
+			.unwrap_or(0.9)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<SubstitutionAttackChallenge> {
+		let message_size = env::var("SUBSTITUTION_ATTACK_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(300);
+
+		Some(SubstitutionAttackChallenge::generate(
+			rng,
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: SubstitutionAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewSubstitutionAttack {
+		NewSubstitutionAttack {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &SubstitutionAttackSubmission) -> Result<Option<String>, Error> {
+		let Some(score) = self.challenge()?.score(submission) else {
+			let error = Error::default()
+				.with_code(StatusCode::BAD_REQUEST)
+				.with_message("Submit either a key or a message.");
+
+			return Err(error);
+		};
+
+		let threshold = SubstitutionAttack::threshold();
+
+		if score >= threshold {
+			return Ok(None);
+		}
+
+		let mistake = format!(
+			"Too few of the letters are right, {:.0}% are needed.",
+			threshold * 100.0
+		);
+
+		Ok(Some(mistake))
+	}
+}
@@ -0,0 +1,2 @@
+mod attack;
+pub use crate::models::substitution::attack::*;
@@ -1,3 +1,9 @@
+use argon2::{
+	Argon2,
+	password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
+};
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use postcard::{from_bytes, to_allocvec};
@@ -5,9 +11,12 @@ use rand::distr::{Alphanumeric, SampleString};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+	challenge::ChallengeKind,
 	error::Error,
+	middleware::rate::AccessPeriod,
 	schema,
 	state::{AppState, Cacheable, DEFAULT_TTL},
+	with_challenge_model,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
@@ -18,6 +27,9 @@ pub struct User {
 
 	pub username:      String,
 	pub password_hash: String,
+
+	pub is_admin:  bool,
+	pub banned_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Insertable)]
@@ -28,6 +40,33 @@ pub struct NewUser {
 }
 
 impl User {
+	pub fn hash_password(password: &str) -> Result<String, Error> {
+		let salt = SaltString::generate(&mut OsRng);
+		let argon2 = Argon2::default();
+
+		let password_hash = argon2
+			.hash_password(password.as_bytes(), &salt)
+			.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+			.to_string();
+
+		Ok(password_hash)
+	}
+
+	pub fn verify_password(&self, password: &str) -> Result<bool, Error> {
+		let argon2 = Argon2::default();
+
+		let parsed_hash = PasswordHash::new(&self.password_hash)
+			.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+		Ok(argon2
+			.verify_password(password.as_bytes(), &parsed_hash)
+			.is_ok())
+	}
+
+	pub fn is_banned(&self) -> bool {
+		self.banned_at.is_some()
+	}
+
 	pub fn init_bearer_token(&self, state: &AppState) -> Result<String, Error> {
 		let user_key = format!("user:{}:bearer", self.id);
 		let mut cache = state.cache();
@@ -98,7 +137,9 @@ impl User {
 			return Ok(None);
 		};
 
-		let user: User = from_bytes((&cache_bytes).into())?;
+		let Ok(user) = from_bytes::<User>((&cache_bytes).into()) else {
+			return Ok(None);
+		};
 
 		cache.ttl(format!("user:{}:bearer", user.id), DEFAULT_TTL)?;
 		cache.ttl(bearer_key, DEFAULT_TTL)?;
@@ -119,6 +160,86 @@ impl User {
 
 		Ok(user)
 	}
+
+	pub async fn set_admin(self, state: &AppState, is_admin: bool) -> Result<Self, Error> {
+		let mut db = state.db().await?;
+
+		let user = diesel::update(schema::users::dsl::users.find(self.id))
+			.set(schema::users::dsl::is_admin.eq(is_admin))
+			.returning(User::as_returning())
+			.get_result(&mut db)
+			.await?;
+
+		user.purge_cached(state)?;
+
+		Ok(user)
+	}
+
+	pub async fn set_banned(self, state: &AppState, is_banned: bool) -> Result<Self, Error> {
+		let mut db = state.db().await?;
+
+		let query = diesel::update(schema::users::dsl::users.find(self.id));
+
+		let user = if is_banned {
+			query
+				.set(schema::users::dsl::banned_at.eq(diesel::dsl::now))
+				.returning(User::as_returning())
+				.get_result(&mut db)
+				.await?
+		} else {
+			query
+				.set(schema::users::dsl::banned_at.eq(None::<DateTime<Utc>>))
+				.returning(User::as_returning())
+				.get_result(&mut db)
+				.await?
+		};
+
+		user.purge_cached(state)?;
+
+		Ok(user)
+	}
+
+	pub async fn set_password(self, state: &AppState, password: &str) -> Result<Self, Error> {
+		let password_hash = User::hash_password(password)?;
+		let mut db = state.db().await?;
+
+		let user = diesel::update(schema::users::dsl::users.find(self.id))
+			.set(schema::users::dsl::password_hash.eq(password_hash))
+			.returning(User::as_returning())
+			.get_result(&mut db)
+			.await?;
+
+		user.purge_cached(state)?;
+
+		Ok(user)
+	}
+
+	/// Removes every cache entry belonging to the user, which also ends their
+	/// current session.
+	pub fn purge_cached(&self, state: &AppState) -> Result<(), Error> {
+		let user_key = format!("user:{}:bearer", self.id);
+
+		{
+			let mut cache = state.cache();
+
+			if let Ok(bearer_token_bytes) = cache.get(&user_key)
+				&& let Ok(bearer_token) = TryInto::<String>::try_into(bearer_token_bytes)
+			{
+				let _ = cache.del(format!("bearer:{bearer_token}"));
+			}
+
+			let _ = cache.del(user_key);
+		}
+
+		User::purge_cache(state.cache(), self.id)?;
+		AccessPeriod::purge_cache(state.cache(), self.id)?;
+
+		for kind in ChallengeKind::ALL {
+			with_challenge_model!(kind, M => M::purge_cache(state.cache(), self.id)?);
+		}
+
+		Ok(())
+	}
 }
 
 impl Cacheable for User {
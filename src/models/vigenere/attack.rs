@@ -0,0 +1,116 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		vigenere::{VigenereAttackChallenge, VigenereAttackPublic, VigenereAttackSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, vigenere},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::vigenere_attacks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct VigenereAttack {
+	id:          i32,
+	pub user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::vigenere_attacks)]
+struct NewVigenereAttack {
+	user_id: i32,
+
+	key:     String,
+	message: String,
+	cipher:  String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      VigenereAttack,
+	table:      vigenere_attacks,
+	pool:       vigenere_attack,
+	name:       "vigenere attack",
+	cache_key:  "vigenere:attack",
+	expiry:     "VIGENERE_ATTACK_EXPIRY_SECONDS",
+	challenge:  VigenereAttackChallenge,
+	public:     VigenereAttackPublic,
+	submission: VigenereAttackSubmission,
+}
+
+impl VigenereAttack {
+	pub fn challenge(&self) -> Result<VigenereAttackChallenge, Error> {
+		let challenge = VigenereAttackChallenge {
+			key:     self.key.clone(),
+			message: self.message.clone(),
+			cipher:  self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<VigenereAttackChallenge> {
+		let message_size = env::var("VIGENERE_ATTACK_MESSAGE_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(300);
+
+		Some(VigenereAttackChallenge::generate(
+			rng,
+			vigenere::key_size(difficulty),
+			difficulty.scale_size(message_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: VigenereAttackChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewVigenereAttack {
+		NewVigenereAttack {
+			user_id,
+
+			key: challenge.key,
+			message: challenge.message,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &VigenereAttackSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.key) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect key.".to_string()))
+	}
+}
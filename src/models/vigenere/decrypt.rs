@@ -0,0 +1,112 @@
+use std::env;
+
+use chrono::{DateTime, Utc};
+use diesel::{Insertable, Queryable, Selectable};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	challenge::{
+		Difficulty,
+		vigenere::{VigenereDecryptChallenge, VigenereDecryptPublic, VigenereDecryptSubmission},
+	},
+	error::Error,
+	models::{challenge_model::impl_challenge_model, vigenere},
+	schema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = schema::vigenere_decrypts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct VigenereDecrypt {
+	id:          i32,
+	pub user_id: i32,
+
+	key:    String,
+	cipher: String,
+
+	created_at:   DateTime<Utc>,
+	completed_at: Option<DateTime<Utc>>,
+	abandoned_at: Option<DateTime<Utc>>,
+	expired_at:   Option<DateTime<Utc>>,
+	delivered_at: Option<DateTime<Utc>>,
+	hints_used:   i32,
+	difficulty:   String,
+	seed:         Option<i64>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::vigenere_decrypts)]
+struct NewVigenereDecrypt {
+	user_id: i32,
+
+	key:    String,
+	cipher: String,
+
+	difficulty: String,
+	seed:       i64,
+}
+
+impl_challenge_model! {
+	model:      VigenereDecrypt,
+	table:      vigenere_decrypts,
+	pool:       vigenere_decrypt,
+	name:       "vigenere decrypt",
+	cache_key:  "vigenere:decrypt",
+	expiry:     "VIGENERE_DECRYPTION_EXPIRY_SECONDS",
+	challenge:  VigenereDecryptChallenge,
+	public:     VigenereDecryptPublic,
+	submission: VigenereDecryptSubmission,
+}
+
+impl VigenereDecrypt {
+	pub fn challenge(&self) -> Result<VigenereDecryptChallenge, Error> {
+		let challenge = VigenereDecryptChallenge {
+			key:    self.key.clone(),
+			cipher: self.cipher.clone(),
+		};
+
+		Ok(challenge)
+	}
+
+	pub(crate) fn generate<R: Rng + ?Sized>(
+		rng: &mut R,
+		difficulty: Difficulty,
+	) -> Option<VigenereDecryptChallenge> {
+		let cipher_size = env::var("VIGENERE_DECRYPTION_CIPHER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(12);
+
+		Some(VigenereDecryptChallenge::generate(
+			rng,
+			vigenere::key_size(difficulty),
+			difficulty.scale_size(cipher_size),
+		))
+	}
+
+	fn new_row(
+		user_id: i32,
+		challenge: VigenereDecryptChallenge,
+		difficulty: Difficulty,
+		seed: u64,
+	) -> NewVigenereDecrypt {
+		NewVigenereDecrypt {
+			user_id,
+
+			key: challenge.key,
+			cipher: challenge.cipher,
+
+			difficulty: difficulty.to_string(),
+			seed: seed as i64,
+		}
+	}
+
+	fn mistake(&self, submission: &VigenereDecryptSubmission) -> Result<Option<String>, Error> {
+		if self.challenge()?.verify(&submission.message) {
+			return Ok(None);
+		}
+
+		Ok(Some("Incorrect message.".to_string()))
+	}
+}
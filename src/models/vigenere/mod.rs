@@ -0,0 +1,22 @@
+mod encrypt;
+pub use crate::models::vigenere::encrypt::*;
+
+mod decrypt;
+pub use crate::models::vigenere::decrypt::*;
+
+mod attack;
+pub use crate::models::vigenere::attack::*;
+
+use std::env;
+
+use crate::challenge::Difficulty;
+
+/// Length of the generated keys, configured for medium difficulty.
+pub(crate) fn key_size(difficulty: Difficulty) -> usize {
+	let key_size = env::var("VIGENERE_KEY_SIZE")
+		.ok()
+		.and_then(|value| value.parse::<usize>().ok())
+		.unwrap_or(5);
+
+	difficulty.scale_size(key_size)
+}
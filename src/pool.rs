@@ -0,0 +1,420 @@
+use std::{
+	collections::{HashMap, HashSet, VecDeque},
+	env,
+	sync::{Arc, Mutex},
+};
+
+use axum::http::StatusCode;
+use rand::rngs::StdRng;
+use tokio::{sync::Notify, task};
+
+use crate::{
+	challenge::{
+		self,
+		Difficulty,
+		affine::{AffineAttackChallenge, AffineDecryptChallenge, AffineEncryptChallenge},
+		caesar::{
+			CaesarAttackChallenge,
+			CaesarCiphertextAttackChallenge,
+			CaesarDecryptChallenge,
+			CaesarEncryptChallenge,
+		},
+		columnar::{
+			ColumnarDecryptChallenge,
+			ColumnarDoubleAttackChallenge,
+			ColumnarEncryptChallenge,
+		},
+		diffie_hellman_exchange::DiffieHellmanExchangeChallenge,
+		dss::{DssSignChallenge, DssVerifyChallenge},
+		enigma::{EnigmaAttackChallenge, EnigmaDecryptChallenge, EnigmaEncryptChallenge},
+		hill::{HillAttackChallenge, HillDecryptChallenge, HillEncryptChallenge},
+		playfair::{PlayfairDecryptChallenge, PlayfairEncryptChallenge},
+		rail_fence::{RailFenceDecryptChallenge, RailFenceEncryptChallenge},
+		rsa::{RsaDecryptChallenge, RsaEncryptChallenge},
+		substitution::SubstitutionAttackChallenge,
+		vigenere::{VigenereAttackChallenge, VigenereDecryptChallenge, VigenereEncryptChallenge},
+	},
+	error::Error,
+	models::{
+		affine::{AffineAttack, AffineDecrypt, AffineEncrypt},
+		caesar::{CaesarAttack, CaesarCiphertextAttack, CaesarDecrypt, CaesarEncrypt},
+		columnar::{ColumnarDecrypt, ColumnarDoubleAttack, ColumnarEncrypt},
+		diffie_hellman_exchange::DiffieHellmanExchange,
+		dss::{DssSign, DssVerify},
+		enigma::{EnigmaAttack, EnigmaDecrypt, EnigmaEncrypt},
+		hill::{HillAttack, HillDecrypt, HillEncrypt},
+		playfair::{PlayfairDecrypt, PlayfairEncrypt},
+		rail_fence::{RailFenceDecrypt, RailFenceEncrypt},
+		rsa::{RsaDecrypt, RsaEncrypt},
+		substitution::SubstitutionAttack,
+		vigenere::{VigenereAttack, VigenereDecrypt, VigenereEncrypt},
+	},
+	state::AppState,
+};
+
+type Generate<T> = fn(&mut StdRng, Difficulty) -> Option<T>;
+
+/// How long a client should wait for a drained key tier to be refilled.
+const RETRY_AFTER_SECONDS: u64 = 5;
+
+/// A generated challenge along with the seed it was generated from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pooled<T> {
+	pub seed:      u64,
+	pub challenge: T,
+}
+
+/// Pre-generated challenges of a single type, kept per difficulty so that
+/// the expensive prime and generator searches happen outside of requests.
+pub struct Pool<T> {
+	size:      usize,
+	key_size:  Option<usize>,
+	generate:  Generate<T>,
+	entries:   Mutex<HashMap<Difficulty, VecDeque<Pooled<T>>>>,
+	requested: Mutex<HashSet<Difficulty>>,
+	refill:    Notify,
+}
+
+pub struct Pools {
+	pub caesar_encrypt:           Arc<Pool<CaesarEncryptChallenge>>,
+	pub caesar_decrypt:           Arc<Pool<CaesarDecryptChallenge>>,
+	pub caesar_attack:            Arc<Pool<CaesarAttackChallenge>>,
+	pub diffie_hellman_exchange:  Arc<Pool<DiffieHellmanExchangeChallenge>>,
+	pub rsa_encrypt:              Arc<Pool<RsaEncryptChallenge>>,
+	pub rsa_decrypt:              Arc<Pool<RsaDecryptChallenge>>,
+	pub dss_sign:                 Arc<Pool<DssSignChallenge>>,
+	pub dss_verify:               Arc<Pool<DssVerifyChallenge>>,
+	pub vigenere_encrypt:         Arc<Pool<VigenereEncryptChallenge>>,
+	pub vigenere_decrypt:         Arc<Pool<VigenereDecryptChallenge>>,
+	pub vigenere_attack:          Arc<Pool<VigenereAttackChallenge>>,
+	pub affine_encrypt:           Arc<Pool<AffineEncryptChallenge>>,
+	pub affine_decrypt:           Arc<Pool<AffineDecryptChallenge>>,
+	pub affine_attack:            Arc<Pool<AffineAttackChallenge>>,
+	pub substitution_attack:      Arc<Pool<SubstitutionAttackChallenge>>,
+	pub caesar_ciphertext_attack: Arc<Pool<CaesarCiphertextAttackChallenge>>,
+	pub playfair_encrypt:         Arc<Pool<PlayfairEncryptChallenge>>,
+	pub playfair_decrypt:         Arc<Pool<PlayfairDecryptChallenge>>,
+	pub hill_encrypt:             Arc<Pool<HillEncryptChallenge>>,
+	pub hill_decrypt:             Arc<Pool<HillDecryptChallenge>>,
+	pub hill_attack:              Arc<Pool<HillAttackChallenge>>,
+	pub rail_fence_encrypt:       Arc<Pool<RailFenceEncryptChallenge>>,
+	pub rail_fence_decrypt:       Arc<Pool<RailFenceDecryptChallenge>>,
+	pub columnar_encrypt:         Arc<Pool<ColumnarEncryptChallenge>>,
+	pub columnar_decrypt:         Arc<Pool<ColumnarDecryptChallenge>>,
+	pub columnar_double_attack:   Arc<Pool<ColumnarDoubleAttackChallenge>>,
+	pub enigma_encrypt:           Arc<Pool<EnigmaEncryptChallenge>>,
+	pub enigma_decrypt:           Arc<Pool<EnigmaDecryptChallenge>>,
+	pub enigma_attack:            Arc<Pool<EnigmaAttackChallenge>>,
+}
+
+impl<T> Pool<T>
+where
+	T: Send + 'static,
+{
+	pub fn new(size: usize, generate: Generate<T>) -> Self {
+		Pool {
+			size,
+			key_size: None,
+			generate,
+			entries: Mutex::new(HashMap::new()),
+			requested: Mutex::new(HashSet::new()),
+			refill: Notify::new(),
+		}
+	}
+
+	/// Keeps at most `size` challenges for difficulties with realistically
+	/// sized keys, and only once one has been requested, since each can take
+	/// seconds to generate. A drained key tier is never generated on the
+	/// request path unless pooling is disabled.
+	pub fn with_key_tiers(mut self, size: usize) -> Self {
+		self.key_size = Some(size);
+		self
+	}
+
+	pub fn len(&self, difficulty: Difficulty) -> usize {
+		self.entries
+			.lock()
+			.unwrap()
+			.get(&difficulty)
+			.map_or(0, VecDeque::len)
+	}
+
+	pub fn is_empty(&self, difficulty: Difficulty) -> bool {
+		self.len(difficulty) == 0
+	}
+
+	/// Hands out a pooled challenge and schedules a refill. Falls back to
+	/// generating one on the blocking thread pool when the pool is empty,
+	/// except for a pooled key tier, which asks the client to retry instead.
+	pub async fn take(&self, difficulty: Difficulty) -> Result<Pooled<T>, Error> {
+		let pooled = self
+			.entries
+			.lock()
+			.unwrap()
+			.get_mut(&difficulty)
+			.and_then(VecDeque::pop_front);
+
+		if pooled.is_none() && self.is_key_tier(difficulty) && self.target(difficulty) > 0 {
+			self.requested.lock().unwrap().insert(difficulty);
+			self.refill.notify_one();
+
+			let error = Error::default()
+				.with_code(StatusCode::SERVICE_UNAVAILABLE)
+				.with_message("This challenge is still being generated. Try again shortly.")
+				.with_retry_after(RETRY_AFTER_SECONDS);
+
+			return Err(error);
+		}
+
+		self.refill.notify_one();
+
+		match pooled {
+			Some(pooled) => Ok(pooled),
+			None => self.generate(difficulty).await,
+		}
+	}
+
+	/// Tops up every difficulty to its target size, skipping key tiers that
+	/// have never been requested, then waits until a challenge is taken before
+	/// doing so again. Runs until the process exits.
+	pub async fn fill(&self) {
+		loop {
+			for difficulty in Difficulty::ALL {
+				if self.is_key_tier(difficulty)
+					&& !self
+						.requested
+						.lock()
+						.unwrap()
+						.contains(&difficulty)
+				{
+					continue;
+				}
+
+				while self.len(difficulty) < self.target(difficulty) {
+					match self.generate(difficulty).await {
+						Ok(pooled) => self.push(difficulty, pooled),
+
+						Err(err) => {
+							tracing::error!("could not fill challenge pool: {err}");
+							break;
+						},
+					}
+				}
+			}
+
+			self.refill.notified().await;
+		}
+	}
+
+	async fn generate(&self, difficulty: Difficulty) -> Result<Pooled<T>, Error> {
+		let generate = self.generate;
+
+		let pooled = task::spawn_blocking(move || {
+			let seed = rand::random();
+			let challenge = generate(&mut challenge::seeded_rng(seed), difficulty)?;
+
+			Some(Pooled {
+				seed,
+				challenge,
+			})
+		})
+		.await?
+		.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+		Ok(pooled)
+	}
+
+	fn is_key_tier(&self, difficulty: Difficulty) -> bool {
+		self.key_size.is_some() && difficulty.key_bits().is_some()
+	}
+
+	fn target(&self, difficulty: Difficulty) -> usize {
+		match self.key_size {
+			Some(key_size) if difficulty.key_bits().is_some() => key_size.min(self.size),
+			_ => self.size,
+		}
+	}
+
+	fn push(&self, difficulty: Difficulty, pooled: Pooled<T>) {
+		self.entries
+			.lock()
+			.unwrap()
+			.entry(difficulty)
+			.or_default()
+			.push_back(pooled);
+	}
+}
+
+impl Pools {
+	pub fn new() -> Self {
+		let size = env::var("CHALLENGE_POOL_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(8);
+
+		let key_size = env::var("CHALLENGE_POOL_KEY_TIER_SIZE")
+			.ok()
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or(2);
+
+		Pools {
+			caesar_encrypt:           Arc::new(Pool::new(size, CaesarEncrypt::generate)),
+			caesar_decrypt:           Arc::new(Pool::new(size, CaesarDecrypt::generate)),
+			caesar_attack:            Arc::new(Pool::new(size, CaesarAttack::generate)),
+			diffie_hellman_exchange:  Arc::new(
+				Pool::new(size, DiffieHellmanExchange::generate).with_key_tiers(key_size),
+			),
+			rsa_encrypt:              Arc::new(
+				Pool::new(size, RsaEncrypt::generate).with_key_tiers(key_size),
+			),
+			rsa_decrypt:              Arc::new(
+				Pool::new(size, RsaDecrypt::generate).with_key_tiers(key_size),
+			),
+			dss_sign:                 Arc::new(
+				Pool::new(size, DssSign::generate).with_key_tiers(key_size),
+			),
+			dss_verify:               Arc::new(
+				Pool::new(size, DssVerify::generate).with_key_tiers(key_size),
+			),
+			vigenere_encrypt:         Arc::new(Pool::new(size, VigenereEncrypt::generate)),
+			vigenere_decrypt:         Arc::new(Pool::new(size, VigenereDecrypt::generate)),
+			vigenere_attack:          Arc::new(Pool::new(size, VigenereAttack::generate)),
+			affine_encrypt:           Arc::new(Pool::new(size, AffineEncrypt::generate)),
+			affine_decrypt:           Arc::new(Pool::new(size, AffineDecrypt::generate)),
+			affine_attack:            Arc::new(Pool::new(size, AffineAttack::generate)),
+			substitution_attack:      Arc::new(Pool::new(size, SubstitutionAttack::generate)),
+			caesar_ciphertext_attack: Arc::new(Pool::new(size, CaesarCiphertextAttack::generate)),
+			playfair_encrypt:         Arc::new(Pool::new(size, PlayfairEncrypt::generate)),
+			playfair_decrypt:         Arc::new(Pool::new(size, PlayfairDecrypt::generate)),
+			hill_encrypt:             Arc::new(Pool::new(size, HillEncrypt::generate)),
+			hill_decrypt:             Arc::new(Pool::new(size, HillDecrypt::generate)),
+			hill_attack:              Arc::new(Pool::new(size, HillAttack::generate)),
+			rail_fence_encrypt:       Arc::new(Pool::new(size, RailFenceEncrypt::generate)),
+			rail_fence_decrypt:       Arc::new(Pool::new(size, RailFenceDecrypt::generate)),
+			columnar_encrypt:         Arc::new(Pool::new(size, ColumnarEncrypt::generate)),
+			columnar_decrypt:         Arc::new(Pool::new(size, ColumnarDecrypt::generate)),
+			columnar_double_attack:   Arc::new(Pool::new(size, ColumnarDoubleAttack::generate)),
+			enigma_encrypt:           Arc::new(Pool::new(size, EnigmaEncrypt::generate)),
+			enigma_decrypt:           Arc::new(Pool::new(size, EnigmaDecrypt::generate)),
+			enigma_attack:            Arc::new(Pool::new(size, EnigmaAttack::generate)),
+		}
+	}
+}
+
+impl Default for Pools {
+	fn default() -> Self {
+		Pools::new()
+	}
+}
+
+/// Keeps every challenge pool topped up in the background.
+pub fn run(state: &AppState) {
+	let pools = state.pools();
+
+	spawn_fill(pools.caesar_encrypt.clone());
+	spawn_fill(pools.caesar_decrypt.clone());
+	spawn_fill(pools.caesar_attack.clone());
+	spawn_fill(pools.diffie_hellman_exchange.clone());
+	spawn_fill(pools.rsa_encrypt.clone());
+	spawn_fill(pools.rsa_decrypt.clone());
+	spawn_fill(pools.dss_sign.clone());
+	spawn_fill(pools.dss_verify.clone());
+	spawn_fill(pools.vigenere_encrypt.clone());
+	spawn_fill(pools.vigenere_decrypt.clone());
+	spawn_fill(pools.vigenere_attack.clone());
+	spawn_fill(pools.affine_encrypt.clone());
+	spawn_fill(pools.affine_decrypt.clone());
+	spawn_fill(pools.affine_attack.clone());
+	spawn_fill(pools.substitution_attack.clone());
+	spawn_fill(pools.caesar_ciphertext_attack.clone());
+	spawn_fill(pools.playfair_encrypt.clone());
+	spawn_fill(pools.playfair_decrypt.clone());
+	spawn_fill(pools.hill_encrypt.clone());
+	spawn_fill(pools.hill_decrypt.clone());
+	spawn_fill(pools.hill_attack.clone());
+	spawn_fill(pools.rail_fence_encrypt.clone());
+	spawn_fill(pools.rail_fence_decrypt.clone());
+	spawn_fill(pools.columnar_encrypt.clone());
+	spawn_fill(pools.columnar_decrypt.clone());
+	spawn_fill(pools.columnar_double_attack.clone());
+	spawn_fill(pools.enigma_encrypt.clone());
+	spawn_fill(pools.enigma_decrypt.clone());
+	spawn_fill(pools.enigma_attack.clone());
+}
+
+fn spawn_fill<T>(pool: Arc<Pool<T>>)
+where
+	T: Send + 'static,
+{
+	tokio::spawn(async move { pool.fill().await });
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{Rng, RngExt};
+
+	use crate::{challenge::Difficulty, pool::*};
+
+	fn generate<R: Rng + ?Sized>(rng: &mut R, difficulty: Difficulty) -> Option<usize> {
+		Some(rng.random_range(0..1_000) * difficulty.tier())
+	}
+
+	#[tokio::test]
+	async fn it_generates_when_empty() {
+		let pool = Pool::new(0, generate);
+		let pooled = pool.take(Difficulty::Easy).await.unwrap();
+
+		assert_eq!(
+			generate(&mut challenge::seeded_rng(pooled.seed), Difficulty::Easy),
+			Some(pooled.challenge)
+		);
+	}
+
+	#[tokio::test]
+	async fn it_asks_for_a_retry_when_a_key_tier_is_drained() {
+		let pool = Pool::new(2, generate).with_key_tiers(1);
+
+		let error = pool.take(Difficulty::Expert).await.unwrap_err();
+		assert_eq!(error, StatusCode::SERVICE_UNAVAILABLE);
+		assert!(pool.take(Difficulty::Hard).await.is_ok());
+
+		let pool = Pool::new(0, generate).with_key_tiers(1);
+		assert!(pool.take(Difficulty::Expert).await.is_ok());
+	}
+
+	#[tokio::test]
+	async fn it_fills_key_tiers_once_requested() {
+		let pool = Arc::new(Pool::new(3, generate).with_key_tiers(1));
+		tokio::spawn({
+			let pool = pool.clone();
+			async move { pool.fill().await }
+		});
+
+		while pool.len(Difficulty::Easy) < 3 {
+			task::yield_now().await;
+		}
+
+		assert!(pool.is_empty(Difficulty::Expert));
+		assert!(pool.take(Difficulty::Expert).await.is_err());
+
+		while pool.is_empty(Difficulty::Expert) {
+			task::yield_now().await;
+		}
+
+		assert!(pool.is_empty(Difficulty::Advanced));
+		assert!(pool.take(Difficulty::Expert).await.is_ok());
+	}
+
+	#[tokio::test]
+	async fn it_hands_out_pooled_challenges_first() {
+		let pool = Pool::new(2, generate);
+		let pooled = pool.generate(Difficulty::Hard).await.unwrap();
+
+		pool.push(Difficulty::Hard, pooled.clone());
+		assert_eq!(1, pool.len(Difficulty::Hard));
+		assert!(pool.is_empty(Difficulty::Easy));
+
+		assert_eq!(pooled, pool.take(Difficulty::Hard).await.unwrap());
+		assert!(pool.is_empty(Difficulty::Hard));
+	}
+}
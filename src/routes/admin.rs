@@ -0,0 +1,31 @@
+use axum::{
+	Router,
+	extract::{Json, Path, State},
+	http::StatusCode,
+	routing::post,
+};
+
+use crate::{
+	admin::RegeneratedChallenge,
+	challenge::ChallengeKind,
+	error::Error,
+	models::challenge_model::ChallengeModel,
+	state::AppState,
+	with_challenge_model,
+};
+
+async fn regenerate(
+	State(state): State<AppState>,
+	Path((challenge, id)): Path<(ChallengeKind, i32)>,
+) -> Result<Json<RegeneratedChallenge>, Error> {
+	let regenerated = with_challenge_model!(challenge, M => M::find_by_id(&state, id)
+		.await?
+		.ok_or(StatusCode::NOT_FOUND)?
+		.try_into()?);
+
+	Ok(Json(regenerated))
+}
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new().route("/{challenge}/{id}/regenerate", post(regenerate))
+}
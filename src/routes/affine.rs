@@ -0,0 +1,14 @@
+use axum::Router;
+
+use crate::{
+	models::affine::{AffineAttack, AffineDecrypt, AffineEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<AffineEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<AffineDecrypt>())
+		.nest("/attack", challenge::guarded_router::<AffineAttack>())
+}
@@ -0,0 +1,18 @@
+use axum::Router;
+
+use crate::{
+	models::caesar::{CaesarAttack, CaesarCiphertextAttack, CaesarDecrypt, CaesarEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<CaesarEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<CaesarDecrypt>())
+		.nest("/attack", challenge::guarded_router::<CaesarAttack>())
+		.nest(
+			"/ciphertext-attack",
+			challenge::guarded_router::<CaesarCiphertextAttack>(),
+		)
+}
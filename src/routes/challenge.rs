@@ -0,0 +1,113 @@
+use axum::{
+	Router,
+	extract::{Extension, Json, Query, State},
+	http::StatusCode,
+	response::Response,
+	routing::{delete, get, post},
+};
+use axum_valid::Valid;
+
+use crate::{
+	challenge::{DifficultyQuery, Hint},
+	delivery,
+	error::Error,
+	leaderboard::LeaderboardResult,
+	models::{challenge_model::ChallengeModel, user::User},
+	state::AppState,
+};
+
+async fn create_challenge<M: ChallengeModel>(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+	Query(query): Query<DifficultyQuery>,
+) -> Result<Response, Error> {
+	if let Some(existing) = M::find_user_incomplete(&state, user.id).await? {
+		if query
+			.difficulty
+			.is_some_and(|difficulty| difficulty != existing.difficulty())
+		{
+			let error = Error::default()
+				.with_code(StatusCode::CONFLICT)
+				.with_message(format!(
+					"An active {} {} session already exists.",
+					existing.difficulty(),
+					M::NAME
+				));
+
+			return Err(error);
+		}
+
+		let public = existing.public()?;
+		return delivery::deliver(&state, existing, (StatusCode::OK, Json(public))).await;
+	}
+
+	let created = M::create(&state, user.id, query.difficulty.unwrap_or_default()).await?;
+	let public = created.public()?;
+
+	delivery::deliver(&state, created, (StatusCode::CREATED, Json(public))).await
+}
+
+async fn submit_challenge<M: ChallengeModel>(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+	Valid(Json(body)): Valid<Json<M::Submission>>,
+) -> Result<(StatusCode, String), Error> {
+	let incomplete = find_incomplete::<M>(&state, &user).await?;
+
+	let duration = incomplete
+		.try_into_completed(&state, body)
+		.await?;
+	let message = format!("Correct! This attempt took {duration:?}.");
+
+	Ok((StatusCode::OK, message))
+}
+
+async fn abandon_challenge<M: ChallengeModel>(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+) -> Result<StatusCode, Error> {
+	find_incomplete::<M>(&state, &user)
+		.await?
+		.abandon(&state)
+		.await?;
+
+	Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_hint<M: ChallengeModel>(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+) -> Result<Json<Hint>, Error> {
+	let incomplete = find_incomplete::<M>(&state, &user).await?;
+
+	Ok(Json(incomplete.take_hint(&state).await?))
+}
+
+async fn get_leaderboard<M: ChallengeModel>(
+	State(state): State<AppState>,
+	Query(query): Query<DifficultyQuery>,
+) -> Result<Json<Vec<LeaderboardResult>>, Error> {
+	Ok(Json(
+		M::leaderboard(&state, query.difficulty.unwrap_or_default()).await?,
+	))
+}
+
+async fn find_incomplete<M: ChallengeModel>(state: &AppState, user: &User) -> Result<M, Error> {
+	M::find_user_incomplete(state, user.id)
+		.await?
+		.ok_or_else(|| {
+			Error::default()
+				.with_code(StatusCode::BAD_REQUEST)
+				.with_message(format!("No active {} session found.", M::NAME))
+		})
+}
+
+/// The routes every challenge type shares, to be nested under its path.
+pub fn guarded_router<M: ChallengeModel>() -> Router<AppState> {
+	Router::new()
+		.route("/", get(create_challenge::<M>))
+		.route("/", post(submit_challenge::<M>))
+		.route("/", delete(abandon_challenge::<M>))
+		.route("/hint", get(get_hint::<M>))
+		.route("/leaderboard", get(get_leaderboard::<M>))
+}
@@ -0,0 +1,17 @@
+use axum::Router;
+
+use crate::{
+	models::columnar::{ColumnarDecrypt, ColumnarDoubleAttack, ColumnarEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<ColumnarEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<ColumnarDecrypt>())
+		.nest(
+			"/double-attack",
+			challenge::guarded_router::<ColumnarDoubleAttack>(),
+		)
+}
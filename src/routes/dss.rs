@@ -0,0 +1,13 @@
+use axum::Router;
+
+use crate::{
+	models::dss::{DssSign, DssVerify},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/sign", challenge::guarded_router::<DssSign>())
+		.nest("/verify", challenge::guarded_router::<DssVerify>())
+}
@@ -0,0 +1,14 @@
+use axum::Router;
+
+use crate::{
+	models::enigma::{EnigmaAttack, EnigmaDecrypt, EnigmaEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<EnigmaEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<EnigmaDecrypt>())
+		.nest("/attack", challenge::guarded_router::<EnigmaAttack>())
+}
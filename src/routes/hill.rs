@@ -0,0 +1,14 @@
+use axum::Router;
+
+use crate::{
+	models::hill::{HillAttack, HillDecrypt, HillEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<HillEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<HillDecrypt>())
+		.nest("/attack", challenge::guarded_router::<HillAttack>())
+}
@@ -1,22 +1,45 @@
+mod admin;
+mod affine;
 mod caesar;
+mod challenge;
+mod columnar;
 mod diffie_hellman_exchange;
 mod dss;
+mod enigma;
 mod health;
+mod hill;
+mod playfair;
+mod rail_fence;
 mod rsa;
+mod substitution;
 mod user;
+mod vigenere;
 
 use axum::Router;
 
-use crate::state::AppState;
+use crate::{middleware, state::AppState};
 
 pub fn guarded_router() -> Router<AppState> {
 	Router::new()
 		.nest("/health", health::guarded_router())
 		.nest("/user", user::guarded_router())
 		.nest("/caesar", caesar::guarded_router())
+		.nest("/playfair", playfair::guarded_router())
+		.nest("/hill", hill::guarded_router())
+		.nest("/rail-fence", rail_fence::guarded_router())
+		.nest("/columnar", columnar::guarded_router())
+		.nest("/enigma", enigma::guarded_router())
 		.nest("/diffie-hellman", diffie_hellman_exchange::guarded_router())
 		.nest("/rsa", rsa::guarded_router())
 		.nest("/dss", dss::guarded_router())
+		.nest("/vigenere", vigenere::guarded_router())
+		.nest("/affine", affine::guarded_router())
+		.nest("/substitution", substitution::guarded_router())
+		.nest(
+			"/admin",
+			admin::guarded_router()
+				.route_layer(axum::middleware::from_fn(middleware::admin::admin)),
+		)
 }
 
 pub fn unguarded_router() -> Router<AppState> {
@@ -0,0 +1,13 @@
+use axum::Router;
+
+use crate::{
+	models::playfair::{PlayfairDecrypt, PlayfairEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<PlayfairEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<PlayfairDecrypt>())
+}
@@ -0,0 +1,13 @@
+use axum::Router;
+
+use crate::{
+	models::rail_fence::{RailFenceDecrypt, RailFenceEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<RailFenceEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<RailFenceDecrypt>())
+}
@@ -0,0 +1,13 @@
+use axum::Router;
+
+use crate::{
+	models::rsa::{RsaDecrypt, RsaEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<RsaEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<RsaDecrypt>())
+}
@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::{models::substitution::SubstitutionAttack, routes::challenge, state::AppState};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new().nest("/attack", challenge::guarded_router::<SubstitutionAttack>())
+}
@@ -1,59 +1,44 @@
-use argon2::{
-	Argon2,
-	password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
-};
 use axum::{
 	Router,
-	extract::{Json, State},
+	extract::{Extension, Json, Query, State},
 	http::StatusCode,
 	response::IntoResponse,
-	routing::post,
+	routing::{get, post},
 };
 use axum_valid::Valid;
-use once_cell::sync::Lazy;
-use regex::Regex;
-use serde::Deserialize;
-use validator::Validate;
 
 use crate::{
+	challenge::{ChallengeKind, Difficulty, DifficultyQuery},
 	error::Error,
-	models::user::{NewUser, User},
+	history::{HistoryPage, HistoryQuery},
+	models::{
+		challenge_model::ChallengeModel,
+		submission::Submission,
+		user::{NewUser, User},
+	},
 	state::AppState,
+	stats::ChallengeStats,
+	user::UserCredentials,
+	with_challenge_model,
 };
 
-static USERNAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z0-9]*$").unwrap());
-
-#[derive(Deserialize, Validate)]
-struct CreateUserBody {
-	#[validate(regex(path = *USERNAME_REGEX, message = "Username can only contain alphanumeric characters."))]
-	#[validate(length(
-		min = 2,
-		max = 16,
-		message = "Username must be between 2 and 16 characters."
-	))]
-	username: String,
-
-	#[validate(length(min = 6, message = "Password must be at least 6 characters."))]
-	password: String,
-}
-
 async fn create_user(
 	State(state): State<AppState>,
-	Valid(Json(body)): Valid<Json<CreateUserBody>>,
+	Valid(Json(body)): Valid<Json<UserCredentials>>,
 ) -> Result<impl IntoResponse, Error> {
 	if let Some(user) = User::find_by_username(&state, &body.username).await? {
-		let argon2 = Argon2::default();
+		if !user.verify_password(&body.password)? {
+			let error = Error::default()
+				.with_code(StatusCode::FORBIDDEN)
+				.with_message("Invalid password.");
 
-		let parsed_hash = PasswordHash::new(&user.password_hash)
-			.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+			return Err(error);
+		}
 
-		if argon2
-			.verify_password(body.password.as_bytes(), &parsed_hash)
-			.is_err()
-		{
+		if user.is_banned() {
 			let error = Error::default()
 				.with_code(StatusCode::FORBIDDEN)
-				.with_message("Invalid password.");
+				.with_message("This account has been banned.");
 
 			return Err(error);
 		}
@@ -61,17 +46,9 @@ async fn create_user(
 		return Ok((StatusCode::OK, user.init_bearer_token(&state)?));
 	}
 
-	let salt = SaltString::generate(&mut OsRng);
-	let argon2 = Argon2::default();
-
-	let password_hash = argon2
-		.hash_password(body.password.as_bytes(), &salt)
-		.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-		.to_string();
-
 	let new_user = NewUser {
-		username: body.username,
-		password_hash,
+		username:      body.username,
+		password_hash: User::hash_password(&body.password)?,
 	};
 
 	let user = User::create(&state, new_user).await?;
@@ -80,8 +57,78 @@ async fn create_user(
 	Ok((StatusCode::CREATED, bearer_token))
 }
 
+/// Reports every challenge at the requested difficulty, or at each
+/// difficulty in turn when none is given.
+async fn get_stats(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+	Query(query): Query<DifficultyQuery>,
+) -> Result<Json<Vec<ChallengeStats>>, Error> {
+	let difficulties = match query.difficulty {
+		Some(difficulty) => vec![difficulty],
+		None => Difficulty::ALL.to_vec(),
+	};
+
+	let mut stats = Vec::new();
+
+	for kind in ChallengeKind::ALL {
+		stats.extend(
+			with_challenge_model!(kind, M => M::stats(&state, &user, &difficulties).await?),
+		);
+	}
+
+	Ok(Json(stats))
+}
+
+async fn get_history(
+	State(state): State<AppState>,
+	Extension(user): Extension<User>,
+	Valid(Query(query)): Valid<Query<HistoryQuery>>,
+) -> Result<Json<HistoryPage>, Error> {
+	let mut total = 0;
+
+	for kind in ChallengeKind::ALL {
+		total += with_challenge_model!(kind, M => M::count_by_user(&state, user.id).await?);
+	}
+
+	// every table's newest `offset + per_page` rows hold the whole page, and
+	// none are needed once the page starts past the end
+	let offset = query.offset();
+	let limit = if offset < total {
+		offset.saturating_add(query.per_page())
+	} else {
+		0
+	};
+
+	let mut entries = Vec::new();
+
+	for kind in ChallengeKind::ALL {
+		entries.extend(with_challenge_model!(kind, M => M::history(&state, user.id, limit).await?));
+	}
+
+	let mut page = HistoryPage::new(entries, total, &query);
+
+	let challenges = page
+		.entries
+		.iter()
+		.map(|entry| (entry.challenge, entry.id))
+		.collect::<Vec<_>>();
+
+	let mut attempts = Submission::find_attempts(&state, user.id, &challenges).await?;
+
+	for entry in &mut page.entries {
+		if let Some(entry_attempts) = attempts.remove(&(entry.challenge, entry.id)) {
+			entry.attempts = entry_attempts;
+		}
+	}
+
+	Ok(Json(page))
+}
+
 pub fn guarded_router() -> Router<AppState> {
 	Router::new()
+		.route("/me/stats", get(get_stats))
+		.route("/me/history", get(get_history))
 }
 
 pub fn unguarded_router() -> Router<AppState> {
@@ -0,0 +1,14 @@
+use axum::Router;
+
+use crate::{
+	models::vigenere::{VigenereAttack, VigenereDecrypt, VigenereEncrypt},
+	routes::challenge,
+	state::AppState,
+};
+
+pub fn guarded_router() -> Router<AppState> {
+	Router::new()
+		.nest("/encrypt", challenge::guarded_router::<VigenereEncrypt>())
+		.nest("/decrypt", challenge::guarded_router::<VigenereDecrypt>())
+		.nest("/attack", challenge::guarded_router::<VigenereAttack>())
+}
@@ -1,5 +1,60 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+	affine_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		a -> Int4,
+		b -> Int4,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	affine_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		a -> Int4,
+		b -> Int4,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	affine_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		a -> Int4,
+		b -> Int4,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
 diesel::table! {
 	caesar_attacks (id) {
 		id -> Int4,
@@ -8,6 +63,30 @@ diesel::table! {
 		cipher -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	caesar_ciphertext_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Int4,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -19,6 +98,12 @@ diesel::table! {
 		cipher -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -30,6 +115,65 @@ diesel::table! {
 		message -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	columnar_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	columnar_double_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		first_key -> Text,
+		second_key -> Text,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	columnar_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -37,11 +181,17 @@ diesel::table! {
 	diffie_hellman_exchanges (id) {
 		id -> Int4,
 		user_id -> Int4,
-		g -> Int8,
-		n -> Int8,
-		sk_server -> Int8,
+		g -> Text,
+		n -> Text,
+		sk_server -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -49,13 +199,19 @@ diesel::table! {
 	dss_signs (id) {
 		id -> Int4,
 		user_id -> Int4,
-		n_p -> Int8,
-		n_q -> Int8,
-		g -> Int8,
+		n_p -> Text,
+		n_q -> Text,
+		g -> Text,
 		h -> Text,
-		m -> Int8,
+		m -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -63,16 +219,203 @@ diesel::table! {
 	dss_verifies (id) {
 		id -> Int4,
 		user_id -> Int4,
-		n_p -> Int8,
-		n_q -> Int8,
-		g -> Int8,
+		n_p -> Text,
+		n_q -> Text,
+		g -> Text,
 		h -> Text,
-		pk -> Int8,
-		m -> Int8,
-		r -> Int8,
-		s -> Int8,
+		pk -> Text,
+		m -> Text,
+		r -> Text,
+		s -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	enigma_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		rotors -> Text,
+		rings -> Text,
+		positions -> Text,
+		plugboard -> Text,
+		crib -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	enigma_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		rotors -> Text,
+		rings -> Text,
+		positions -> Text,
+		plugboard -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	enigma_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		rotors -> Text,
+		rings -> Text,
+		positions -> Text,
+		plugboard -> Text,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	hill_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	hill_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	hill_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	playfair_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		keyword -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	playfair_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		keyword -> Text,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	rail_fence_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		rails -> Int4,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	rail_fence_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		rails -> Int4,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -80,13 +423,19 @@ diesel::table! {
 	rsa_decrypts (id) {
 		id -> Int4,
 		user_id -> Int4,
-		n_p -> Int8,
-		n_q -> Int8,
-		e -> Int8,
-		d -> Int8,
-		c -> Int8,
+		n_p -> Text,
+		n_q -> Text,
+		e -> Text,
+		d -> Text,
+		c -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -94,13 +443,49 @@ diesel::table! {
 	rsa_encrypts (id) {
 		id -> Int4,
 		user_id -> Int4,
-		n_p -> Int8,
-		n_q -> Int8,
-		e -> Int8,
-		d -> Int8,
-		m -> Int8,
+		n_p -> Text,
+		n_q -> Text,
+		e -> Text,
+		d -> Text,
+		m -> Text,
 		created_at -> Timestamptz,
 		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	submissions (id) {
+		id -> Int4,
+		user_id -> Int4,
+		challenge -> Text,
+		challenge_id -> Int4,
+		payload -> Jsonb,
+		correct -> Bool,
+		created_at -> Timestamptz,
+	}
+}
+
+diesel::table! {
+	substitution_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
@@ -109,26 +494,124 @@ diesel::table! {
 		id -> Int4,
 		username -> Text,
 		password_hash -> Text,
+		is_admin -> Bool,
+		banned_at -> Nullable<Timestamptz>,
+	}
+}
+
+diesel::table! {
+	vigenere_encrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	vigenere_decrypts (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
+	}
+}
+
+diesel::table! {
+	vigenere_attacks (id) {
+		id -> Int4,
+		user_id -> Int4,
+		key -> Text,
+		message -> Text,
+		cipher -> Text,
+		created_at -> Timestamptz,
+		completed_at -> Nullable<Timestamptz>,
+		abandoned_at -> Nullable<Timestamptz>,
+		expired_at -> Nullable<Timestamptz>,
+		delivered_at -> Nullable<Timestamptz>,
+		hints_used -> Int4,
+		difficulty -> Text,
+		seed -> Nullable<Int8>,
 	}
 }
 
+diesel::joinable!(affine_attacks -> users (user_id));
+diesel::joinable!(affine_decrypts -> users (user_id));
+diesel::joinable!(affine_encrypts -> users (user_id));
 diesel::joinable!(caesar_attacks -> users (user_id));
+diesel::joinable!(caesar_ciphertext_attacks -> users (user_id));
 diesel::joinable!(caesar_decrypts -> users (user_id));
 diesel::joinable!(caesar_encrypts -> users (user_id));
+diesel::joinable!(columnar_decrypts -> users (user_id));
+diesel::joinable!(columnar_double_attacks -> users (user_id));
+diesel::joinable!(columnar_encrypts -> users (user_id));
 diesel::joinable!(diffie_hellman_exchanges -> users (user_id));
 diesel::joinable!(dss_signs -> users (user_id));
 diesel::joinable!(dss_verifies -> users (user_id));
+diesel::joinable!(enigma_attacks -> users (user_id));
+diesel::joinable!(enigma_decrypts -> users (user_id));
+diesel::joinable!(enigma_encrypts -> users (user_id));
+diesel::joinable!(hill_attacks -> users (user_id));
+diesel::joinable!(hill_decrypts -> users (user_id));
+diesel::joinable!(hill_encrypts -> users (user_id));
+diesel::joinable!(playfair_decrypts -> users (user_id));
+diesel::joinable!(playfair_encrypts -> users (user_id));
+diesel::joinable!(rail_fence_decrypts -> users (user_id));
+diesel::joinable!(rail_fence_encrypts -> users (user_id));
 diesel::joinable!(rsa_decrypts -> users (user_id));
 diesel::joinable!(rsa_encrypts -> users (user_id));
+diesel::joinable!(submissions -> users (user_id));
+diesel::joinable!(substitution_attacks -> users (user_id));
+diesel::joinable!(vigenere_attacks -> users (user_id));
+diesel::joinable!(vigenere_decrypts -> users (user_id));
+diesel::joinable!(vigenere_encrypts -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+	affine_attacks,
+	affine_decrypts,
+	affine_encrypts,
 	caesar_attacks,
+	caesar_ciphertext_attacks,
 	caesar_decrypts,
 	caesar_encrypts,
+	columnar_decrypts,
+	columnar_double_attacks,
+	columnar_encrypts,
 	diffie_hellman_exchanges,
 	dss_signs,
 	dss_verifies,
+	enigma_attacks,
+	enigma_decrypts,
+	enigma_encrypts,
+	hill_attacks,
+	hill_decrypts,
+	hill_encrypts,
+	playfair_decrypts,
+	playfair_encrypts,
+	rail_fence_decrypts,
+	rail_fence_encrypts,
 	rsa_decrypts,
 	rsa_encrypts,
+	submissions,
+	substitution_attacks,
 	users,
+	vigenere_attacks,
+	vigenere_decrypts,
+	vigenere_encrypts,
 );
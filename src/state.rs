@@ -1,4 +1,7 @@
-use std::{env, sync::MutexGuard};
+use std::{
+	env,
+	sync::{Arc, MutexGuard},
+};
 
 use anyhow::anyhow;
 use diesel::{Connection, PgConnection};
@@ -18,7 +21,7 @@ use paper_client::{
 use postcard::{from_bytes, to_allocvec};
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::error::Error;
+use crate::{error::Error, pool::Pools};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 
@@ -30,6 +33,7 @@ pub const DEFAULT_TTL: Option<u32> = Some(3_600);
 pub struct AppState {
 	db:    Pool<AsyncPgConnection>,
 	cache: PaperPool,
+	pools: Arc<Pools>,
 }
 
 impl AppState {
@@ -37,6 +41,11 @@ impl AppState {
 		let db_addr = env::var("DATABASE_URL")?;
 		run_db_migrations(&db_addr)?;
 
+		AppState::connect().await
+	}
+
+	pub async fn connect() -> anyhow::Result<Self> {
+		let db_addr = env::var("DATABASE_URL")?;
 		let db_manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(db_addr);
 
 		let db = Pool::builder(db_manager)
@@ -49,6 +58,7 @@ impl AppState {
 		let state = AppState {
 			db,
 			cache,
+			pools: Arc::new(Pools::new()),
 		};
 
 		Ok(state)
@@ -61,6 +71,10 @@ impl AppState {
 	pub fn cache(&self) -> MutexGuard<'_, PaperClient> {
 		self.cache.client()
 	}
+
+	pub fn pools(&self) -> &Pools {
+		&self.pools
+	}
 }
 
 pub trait Cacheable {
@@ -91,17 +105,21 @@ pub trait Cacheable {
 			Err(err) => return Err(err.into()),
 		};
 
+		// an entry written before a schema change no longer decodes; treat it
+		// as a miss so it gets reloaded from the database
 		let bytes: Box<[u8]> = value.into();
-		Ok(Some(from_bytes::<Self>(&bytes)?))
+		Ok(from_bytes::<Self>(&bytes).ok())
 	}
 
 	fn purge_cache(mut cache: MutexGuard<'_, PaperClient>, id: Self::Id) -> Result<(), Error> {
-		cache.del(Self::cache_key(id))?;
-		Ok(())
+		match cache.del(Self::cache_key(id)) {
+			Ok(_) | Err(PaperClientError::CacheError(PaperCacheError::KeyNotFound)) => Ok(()),
+			Err(err) => Err(err.into()),
+		}
 	}
 }
 
-fn run_db_migrations(db_addr: &str) -> anyhow::Result<()> {
+pub fn run_db_migrations(db_addr: &str) -> anyhow::Result<()> {
 	let mut conn = PgConnection::establish(db_addr)?;
 
 	tracing::info!("running db migrations");
@@ -110,3 +128,17 @@ fn run_db_migrations(db_addr: &str) -> anyhow::Result<()> {
 
 	Ok(())
 }
+
+pub fn revert_db_migrations(db_addr: &str, steps: usize) -> anyhow::Result<()> {
+	let mut conn = PgConnection::establish(db_addr)?;
+
+	for _ in 0..steps {
+		let version = conn
+			.revert_last_migration(MIGRATIONS)
+			.map_err(|err| anyhow!("could not revert db migration: {err}"))?;
+
+		tracing::info!("reverted db migration {version}");
+	}
+
+	Ok(())
+}
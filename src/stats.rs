@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+
+use crate::{
+	challenge::{ChallengeKind, Difficulty},
+	leaderboard::parse_duration,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeStats {
+	pub challenge:   ChallengeKind,
+	pub difficulty:  Difficulty,
+	pub started:     usize,
+	pub completed:   usize,
+	pub in_progress: bool,
+	pub rank:        Option<usize>,
+
+	#[serde(
+		serialize_with = "serialize_duration",
+		deserialize_with = "deserialize_duration"
+	)]
+	pub best: Option<Duration>,
+
+	#[serde(
+		serialize_with = "serialize_duration",
+		deserialize_with = "deserialize_duration"
+	)]
+	pub median: Option<Duration>,
+
+	#[serde(
+		serialize_with = "serialize_duration",
+		deserialize_with = "deserialize_duration"
+	)]
+	pub latest: Option<Duration>,
+}
+
+impl ChallengeStats {
+	/// Aggregates a user's attempts of a challenge, oldest first. Each attempt
+	/// is its completed duration, or `None` if it was never completed.
+	pub fn new(
+		challenge: ChallengeKind,
+		difficulty: Difficulty,
+		attempts: impl IntoIterator<Item = Option<Duration>>,
+		in_progress: bool,
+		rank: Option<usize>,
+	) -> Self {
+		let mut started = 0;
+		let mut durations = Vec::new();
+
+		for attempt in attempts {
+			started += 1;
+
+			if let Some(duration) = attempt {
+				durations.push(duration);
+			}
+		}
+
+		let latest = durations.last().copied();
+		durations.sort();
+
+		ChallengeStats {
+			challenge,
+			difficulty,
+			started,
+			completed: durations.len(),
+			in_progress,
+			rank,
+
+			best: durations.first().copied(),
+			median: median(&durations),
+			latest,
+		}
+	}
+}
+
+fn median(sorted: &[Duration]) -> Option<Duration> {
+	if sorted.is_empty() {
+		return None;
+	}
+
+	let middle = sorted.len() / 2;
+
+	if sorted.len() % 2 == 1 {
+		return Some(sorted[middle]);
+	}
+
+	Some((sorted[middle - 1] + sorted[middle]) / 2)
+}
+
+fn serialize_duration<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match duration {
+		Some(duration) => serializer.serialize_some(&format!("{duration:?}")),
+		None => serializer.serialize_none(),
+	}
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let Some(value) = Option::<String>::deserialize(deserializer)? else {
+		return Ok(None);
+	};
+
+	parse_duration(&value)
+		.map(Some)
+		.ok_or_else(|| DeError::custom(format!("invalid duration: {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use crate::{
+		challenge::{ChallengeKind, Difficulty},
+		stats::*,
+	};
+
+	#[test]
+	fn it_aggregates_attempts() {
+		let attempts = [
+			Some(Duration::from_secs(5)),
+			None,
+			Some(Duration::from_secs(1)),
+			Some(Duration::from_secs(4)),
+			Some(Duration::from_secs(2)),
+		];
+
+		let stats = ChallengeStats::new(
+			ChallengeKind::RsaEncrypt,
+			Difficulty::Medium,
+			attempts,
+			false,
+			Some(3),
+		);
+
+		assert_eq!(5, stats.started);
+		assert_eq!(4, stats.completed);
+		assert_eq!(Some(Duration::from_secs(1)), stats.best);
+		assert_eq!(Some(Duration::from_secs(3)), stats.median);
+		assert_eq!(Some(Duration::from_secs(2)), stats.latest);
+		assert_eq!(Some(3), stats.rank);
+	}
+
+	#[test]
+	fn it_aggregates_no_completions() {
+		let stats =
+			ChallengeStats::new(ChallengeKind::DssSign, Difficulty::Hard, [None], true, None);
+
+		assert_eq!(1, stats.started);
+		assert_eq!(0, stats.completed);
+		assert!(stats.in_progress);
+		assert_eq!(None, stats.best);
+		assert_eq!(None, stats.median);
+		assert_eq!(None, stats.latest);
+	}
+}
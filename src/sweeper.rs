@@ -0,0 +1,42 @@
+use std::{env, time::Duration};
+
+use tokio::time;
+
+use crate::{
+	challenge::ChallengeKind,
+	error::Error,
+	models::challenge_model::ChallengeModel,
+	state::AppState,
+	with_challenge_model,
+};
+
+/// Periodically expires stale pending challenges of every type. Runs until
+/// the process exits; failed sweeps are logged and retried on the next tick.
+pub async fn run(state: AppState) {
+	let seconds = env::var("EXPIRY_SWEEP_INTERVAL_SECONDS")
+		.ok()
+		.and_then(|value| value.parse::<u64>().ok())
+		.unwrap_or(60);
+
+	let mut interval = time::interval(Duration::from_secs(seconds));
+
+	loop {
+		interval.tick().await;
+
+		match sweep(&state).await {
+			Ok(0) => {},
+			Ok(expired) => tracing::info!("expired {expired} stale challenges"),
+			Err(err) => tracing::error!("could not sweep stale challenges: {err}"),
+		}
+	}
+}
+
+pub async fn sweep(state: &AppState) -> Result<usize, Error> {
+	let mut expired = 0;
+
+	for kind in ChallengeKind::ALL {
+		expired += with_challenge_model!(kind, M => M::expire_stale(state).await?);
+	}
+
+	Ok(expired)
+}
@@ -0,0 +1,20 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+static USERNAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z0-9]*$").unwrap());
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct UserCredentials {
+	#[validate(regex(path = *USERNAME_REGEX, message = "Username can only contain alphanumeric characters."))]
+	#[validate(length(
+		min = 2,
+		max = 16,
+		message = "Username must be between 2 and 16 characters."
+	))]
+	pub username: String,
+
+	#[validate(length(min = 6, message = "Password must be at least 6 characters."))]
+	pub password: String,
+}